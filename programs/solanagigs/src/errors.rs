@@ -1,4 +1,3 @@
-```rust
 use anchor_lang::prelude::*;
 
 #[error_code]
@@ -347,5 +346,244 @@ pub enum SolanaGigsError {
     
     #[msg("Maximum discount exceeded")]
     MaximumDiscountExceeded,
+    
+    #[msg("Too many tags")]
+    TooManyTags,
+    
+    #[msg("Tag too long")]
+    TagTooLong,
+    
+    #[msg("Too many images")]
+    TooManyImages,
+    
+    #[msg("Subcategory too long")]
+    SubcategoryTooLong,
+    
+    #[msg("Requirements too long")]
+    RequirementsTooLong,
+    
+    #[msg("Invalid revision count")]
+    InvalidRevisionCount,
+    
+    #[msg("Delivery message too long")]
+    DeliveryMessageTooLong,
+    
+    #[msg("Too many delivery files")]
+    TooManyFiles,
+    
+    #[msg("Cannot cancel order")]
+    CannotCancelOrder,
+    
+    #[msg("Invalid dispute reason")]
+    InvalidDisputeReason,
+    
+    #[msg("Invalid evidence hash")]
+    InvalidEvidenceHash,
+    
+    #[msg("Dispute period expired")]
+    DisputePeriodExpired,
+    
+    #[msg("Refund must be between 0 and 10000 basis points")]
+    InvalidRefundPercentage,
+    
+    #[msg("Comment too long")]
+    CommentTooLong,
+    
+    #[msg("Username too long")]
+    UsernameTooLong,
+    
+    #[msg("Bio too long")]
+    BioTooLong,
+    
+    #[msg("URL too long")]
+    UrlTooLong,
+    
+    #[msg("Too many languages")]
+    TooManyLanguages,
+    
+    #[msg("Language too long")]
+    LanguageTooLong,
+    
+    #[msg("Skill too long")]
+    SkillTooLong,
+    
+    #[msg("Location too long")]
+    LocationTooLong,
+    
+    #[msg("Timezone too long")]
+    TimezoneTooLong,
+    
+    #[msg("Too many social links")]
+    TooManySocialLinks,
+    
+    #[msg("Too many preferred categories")]
+    TooManyCategories,
+    
+    #[msg("Category too long")]
+    CategoryTooLong,
+    
+    #[msg("Too many badges")]
+    TooManyBadges,
+    
+    #[msg("Too many favorites")]
+    TooManyFavorites,
+    
+    #[msg("Search term too long")]
+    SearchTermTooLong,
+    
+    #[msg("Insufficient loyalty points")]
+    InsufficientLoyaltyPoints,
+    
+    #[msg("Milestone amounts must add up to the order amount")]
+    MilestoneTotalMismatch,
+    
+    #[msg("Invalid milestone details")]
+    InvalidMilestoneDetails,
+    
+    #[msg("Escrow cannot be auto-released yet")]
+    AutoReleaseNotAvailable,
+    
+    #[msg("Too many packages")]
+    TooManyPackages,
+    
+    #[msg("Invalid package details")]
+    InvalidPackage,
+    
+    #[msg("Invalid package index")]
+    InvalidPackageIndex,
+    
+    #[msg("Too many extras")]
+    TooManyExtras,
+    
+    #[msg("Invalid extra details")]
+    InvalidExtra,
+    
+    #[msg("Invalid or duplicate extra index")]
+    InvalidExtraIndex,
+    
+    #[msg("Only the counterparty can accept a cancellation proposal")]
+    CannotAcceptOwnProposal,
+    
+    #[msg("An extension request is already pending")]
+    ExtensionAlreadyPending,
+    
+    #[msg("Extension request is not pending")]
+    ExtensionNotPending,
+    
+    #[msg("Arbiter is inactive or does not handle this category")]
+    ArbiterNotEligible,
+    
+    #[msg("Arbiter is not next in the assignment rotation")]
+    ArbiterOutOfRotation,
+    
+    #[msg("Dispute already has an arbiter")]
+    ArbiterAlreadyAssigned,
+    
+    #[msg("Dispute has no assigned arbiter")]
+    ArbiterNotAssigned,
+    
+    #[msg("Dispute is not in the required resolution mode")]
+    InvalidResolutionMode,
+    
+    #[msg("Juror stake is below the minimum")]
+    InsufficientJurorStake,
+    
+    #[msg("Juror still has open cases")]
+    JurorHasActiveCases,
+    
+    #[msg("Not enough eligible jurors to draw a panel")]
+    NotEnoughJurors,
+    
+    #[msg("Juror is not on this panel")]
+    JurorNotOnPanel,
+    
+    #[msg("Vote was already committed or revealed")]
+    VoteAlreadyCast,
+    
+    #[msg("Revealed vote does not match the commitment")]
+    InvalidVoteCommitment,
+    
+    #[msg("Voting phase is closed")]
+    VotingPhaseClosed,
+    
+    #[msg("Voting phase has not ended yet")]
+    VotingPhaseActive,
+    
+    #[msg("Juror reward is not available")]
+    JurorRewardUnavailable,
+    
+    #[msg("Evidence window has closed")]
+    EvidenceWindowClosed,
+    
+    #[msg("Evidence limit reached for this dispute")]
+    EvidenceLimitReached,
+    
+    #[msg("Invalid dispute response")]
+    InvalidDisputeResponse,
+    
+    #[msg("Dispute has already been answered")]
+    DisputeAlreadyAnswered,
+    
+    #[msg("Appeal window is still open")]
+    AppealWindowOpen,
+    
+    #[msg("Appeal window has closed")]
+    AppealWindowClosed,
+    
+    #[msg("Only the party the resolution went against can appeal")]
+    CannotAppealResolution,
+    
+    #[msg("Response deadline has passed")]
+    ResponseDeadlinePassed,
+    
+    #[msg("Response deadline has not passed yet")]
+    ResponseDeadlineNotReached,
+    
+    #[msg("Respondent has engaged with the dispute")]
+    RespondentEngaged,
+    
+    #[msg("Arbiter deadline has not passed yet")]
+    ArbiterDeadlineNotReached,
+    
+    #[msg("Order is not finalized")]
+    OrderNotFinalized,
+    
+    #[msg("Retention period has not passed yet")]
+    RetentionPeriodActive,
+    
+    #[msg("Withdrawal above the threshold needs a withdrawal request")]
+    WithdrawalApprovalRequired,
+    
+    #[msg("Invalid governance signer set, threshold or delay")]
+    InvalidGovernanceConfig,
+    
+    #[msg("Proposal is not in a valid state for this operation")]
+    InvalidProposalStatus,
+    
+    #[msg("Proposal was created under an older signer set")]
+    StaleProposal,
+    
+    #[msg("Signer has already approved this proposal")]
+    AlreadyApproved,
+    
+    #[msg("Proposal timelock has not elapsed")]
+    ProposalNotReady,
+    
+    #[msg("Proposal instruction is too large")]
+    ProposalTooLarge,
+    
+    #[msg("No recent slot hash to seed the jury draw")]
+    SlotHashUnavailable,
+    
+    #[msg("Juror reward claim window has closed")]
+    ClaimDeadlinePassed,
+    
+    #[msg("Juror reward claim window is still open")]
+    ClaimDeadlineNotReached,
+    
+    #[msg("Appeal not found")]
+    AppealNotFound,
+    
+    #[msg("Order has reached the extension limit")]
+    TooManyExtensions,
 }
-```
\ No newline at end of file
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"marketplace"],
+        bump = marketplace.bump,
+        constraint = marketplace.pending_authority == Some(new_authority.key()) @ SolanaGigsError::Unauthorized
+    )]
+    pub marketplace: Account<'info, Marketplace>,
+
+    pub new_authority: Signer<'info>,
+}
+
+/// Completes an authority transfer. Every delegated role moves to the new
+/// authority too, which can hand them out again with `assign_role`.
+pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let marketplace = &mut ctx.accounts.marketplace;
+    let previous_authority = marketplace.authority;
+    marketplace.set_authority(ctx.accounts.new_authority.key());
+
+    emit!(AuthorityTransferred {
+        marketplace: marketplace.key(),
+        previous_authority,
+        new_authority: marketplace.authority,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct AuthorityTransferred {
+    pub marketplace: Pubkey,
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
@@ -0,0 +1,262 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::state::*;
+use crate::errors::*;
+use crate::utils::{charge_fee, payout_account, treasury_account, EscrowPayer};
+
+#[derive(Accounts)]
+pub struct AcceptCancellation<'info> {
+    #[account(
+        seeds = [b"marketplace"],
+        bump = marketplace.bump
+    )]
+    pub marketplace: Box<Account<'info, Marketplace>>,
+
+    #[account(
+        seeds = [b"fee_schedule"],
+        bump = fee_schedule.bump
+    )]
+    pub fee_schedule: Box<Account<'info, FeeSchedule>>,
+
+    #[account(
+        mut,
+        seeds = [b"gig", gig.id.to_le_bytes().as_ref()],
+        bump = gig.bump
+    )]
+    pub gig: Box<Account<'info, Gig>>,
+
+    #[account(
+        mut,
+        seeds = [b"order", order.id.to_le_bytes().as_ref()],
+        bump = order.bump,
+        has_one = gig,
+        constraint = order.is_participant(&signer.key()) @ SolanaGigsError::Unauthorized
+    )]
+    pub order: Box<Account<'info, Order>>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", order.key().as_ref()],
+        bump = escrow.bump,
+        has_one = order
+    )]
+    pub escrow: Box<Account<'info, Escrow>>,
+
+    #[account(
+        mut,
+        seeds = [b"cancellation", order.key().as_ref()],
+        bump = proposal.bump,
+        has_one = order,
+        close = proposer
+    )]
+    pub proposal: Box<Account<'info, CancellationProposal>>,
+
+    #[account(
+        mut,
+        constraint = vault.key() == escrow.vault @ SolanaGigsError::InvalidEscrowState,
+        token::mint = escrow.mint
+    )]
+    pub vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        address = escrow.mint @ SolanaGigsError::InvalidTokenMint
+    )]
+    pub mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        mut,
+        seeds = [b"user", order.seller.as_ref()],
+        bump = seller_profile.bump
+    )]
+    pub seller_profile: Box<Account<'info, UserProfile>>,
+
+    #[account(
+        mut,
+        seeds = [b"volume", order.seller.as_ref(), escrow.mint.as_ref()],
+        bump = seller_volume.bump
+    )]
+    pub seller_volume: Box<Account<'info, SellerVolume>>,
+
+    #[account(
+        mut,
+        token::mint = escrow.mint,
+        token::authority = order.buyer
+    )]
+    pub buyer_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        token::mint = escrow.mint,
+        token::authority = order.seller
+    )]
+    pub seller_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// CHECK: The seller's earnings PDA, which is credited instead of the seller once initialized
+    #[account(
+        mut,
+        seeds = [b"earnings", order.seller.as_ref(), escrow.mint.as_ref()],
+        bump
+    )]
+    pub seller_earnings: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub earnings_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury", escrow.mint.as_ref()],
+        bump = treasury.bump
+    )]
+    pub treasury: Box<Account<'info, Treasury>>,
+
+    #[account(
+        mut,
+        address = treasury.vault @ SolanaGigsError::InvalidAccountData
+    )]
+    pub treasury_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        address = order.buyer @ SolanaGigsError::Unauthorized
+    )]
+    pub buyer_wallet: Option<SystemAccount<'info>>,
+
+    #[account(
+        mut,
+        address = order.seller @ SolanaGigsError::Unauthorized
+    )]
+    pub seller: Option<SystemAccount<'info>>,
+
+    /// CHECK: Receives the proposal rent back
+    #[account(
+        mut,
+        address = proposal.proposed_by @ SolanaGigsError::Unauthorized
+    )]
+    pub proposer: UncheckedAccount<'info>,
+
+    pub signer: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn accept_cancellation<'info>(ctx: Context<'_, '_, '_, 'info, AcceptCancellation<'info>>) -> Result<()> {
+    ctx.accounts.marketplace.require_funds_unfrozen()?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let order = &mut ctx.accounts.order;
+    let escrow = &mut ctx.accounts.escrow;
+    let proposal = &ctx.accounts.proposal;
+
+    require!(
+        proposal.proposed_by != ctx.accounts.signer.key(),
+        SolanaGigsError::CannotAcceptOwnProposal
+    );
+    require!(
+        matches!(order.status, OrderStatus::InProgress | OrderStatus::Delivered),
+        SolanaGigsError::CannotCancelOrder
+    );
+    require!(!escrow.is_settled(), SolanaGigsError::EscrowAlreadyReleased);
+
+    // Split what is left in escrow; the marketplace fee only applies to the seller share
+    let amount = escrow.remaining_amount();
+    let (refund_amount, seller_share) = proposal.split(amount);
+    let marketplace_fee = charge_fee(
+        &ctx.accounts.fee_schedule,
+        order.key(),
+        ctx.accounts.gig.category,
+        &ctx.accounts.seller_profile,
+        &ctx.accounts.seller_volume,
+        seller_share,
+    )?;
+    let seller_amount = seller_share
+        .checked_sub(marketplace_fee)
+        .ok_or(SolanaGigsError::ArithmeticUnderflow)?;
+
+    let payer = EscrowPayer {
+        escrow,
+        vault: &ctx.accounts.vault,
+        mint: &ctx.accounts.mint,
+        token_program: &ctx.accounts.token_program,
+        remaining_accounts: ctx.remaining_accounts,
+    };
+
+    // Refund buyer if applicable
+    if refund_amount > 0 {
+        let buyer_destination = payout_account(
+            escrow.is_native,
+            &ctx.accounts.buyer_token_account,
+            &ctx.accounts.buyer_wallet,
+        )?;
+        payer.transfer(buyer_destination, refund_amount)?;
+    }
+
+    // Pay seller if applicable
+    if seller_amount > 0 {
+        payer.pay_seller(
+            &ctx.accounts.seller_earnings,
+            &ctx.accounts.earnings_vault,
+            &ctx.accounts.seller_token_account,
+            &ctx.accounts.seller,
+            seller_amount,
+        )?;
+    }
+
+    // Transfer fee to marketplace
+    if marketplace_fee > 0 {
+        let fee_destination = treasury_account(&ctx.accounts.treasury, &ctx.accounts.treasury_vault)?;
+        payer.transfer(fee_destination, marketplace_fee)?;
+    }
+
+    // Settlement is final, so the vault is drained and closed
+    payer.close_vault(
+        &ctx.accounts.treasury_vault,
+        ctx.accounts.buyer_wallet.as_ref().map(|buyer| buyer.to_account_info()),
+    )?;
+
+    order.status = OrderStatus::Cancelled;
+    order.has_pending_extension = false;
+    order.cancelled_at = Some(current_time);
+
+    escrow.platform_fee = escrow.platform_fee.checked_add(marketplace_fee)
+        .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+    escrow.partial_release_amount = escrow.amount;
+    if refund_amount > 0 {
+        escrow.refunded_at = Some(current_time);
+    }
+    if seller_share > 0 {
+        escrow.released_at = Some(current_time);
+    }
+
+    let gig = &mut ctx.accounts.gig;
+    gig.active_orders = gig.active_orders.saturating_sub(1);
+
+    if seller_amount > 0 {
+        ctx.accounts.seller_profile.add_earnings(seller_amount)?;
+        ctx.accounts.seller_volume.add_volume(seller_amount)?;
+    }
+
+    emit!(CancellationAccepted {
+        order: order.key(),
+        proposed_by: proposal.proposed_by,
+        accepted_by: ctx.accounts.signer.key(),
+        refund_bps: proposal.refund_bps,
+        buyer_refund_amount: refund_amount,
+        seller_payout_amount: seller_amount,
+        marketplace_fee,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct CancellationAccepted {
+    pub order: Pubkey,
+    pub proposed_by: Pubkey,
+    pub accepted_by: Pubkey,
+    pub refund_bps: u16,
+    pub buyer_refund_amount: u64,
+    pub seller_payout_amount: u64,
+    pub marketplace_fee: u64,
+}
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::utils::{create_pda_account, store};
+
+#[derive(Accounts)]
+pub struct AcceptOrder<'info> {
+    #[account(
+        mut,
+        seeds = [b"order", order.id.to_le_bytes().as_ref()],
+        bump = order.bump,
+        has_one = seller
+    )]
+    pub order: Box<Account<'info, Order>>,
+
+    #[account(
+        seeds = [b"escrow", order.key().as_ref()],
+        bump = escrow.bump,
+        has_one = order
+    )]
+    pub escrow: Box<Account<'info, Escrow>>,
+
+    /// CHECK: The seller's volume PDA for the escrow mint, created on their first order in it
+    #[account(
+        mut,
+        seeds = [b"volume", seller.key().as_ref(), escrow.mint.as_ref()],
+        bump
+    )]
+    pub seller_volume: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn accept_order(ctx: Context<AcceptOrder>) -> Result<()> {
+    let order = &mut ctx.accounts.order;
+
+    require!(order.status == OrderStatus::Pending, SolanaGigsError::InvalidOrderStatus);
+    require!(
+        !order.has_milestones() || order.milestones_total == order.amount,
+        SolanaGigsError::MilestoneTotalMismatch
+    );
+
+    let seller_volume = &ctx.accounts.seller_volume;
+    if seller_volume.data_is_empty() {
+        let seller = ctx.accounts.seller.key();
+        let mint = ctx.accounts.escrow.mint;
+        let bump = ctx.bumps.seller_volume;
+        create_pda_account(
+            &ctx.accounts.seller.to_account_info(),
+            seller_volume,
+            &ctx.accounts.system_program.to_account_info(),
+            SellerVolume::LEN,
+            &[b"volume", seller.as_ref(), mint.as_ref(), &[bump]],
+        )?;
+        store(
+            seller_volume,
+            &SellerVolume {
+                seller,
+                mint,
+                total_volume: 0,
+                bump,
+            },
+        )?;
+    }
+
+    order.status = OrderStatus::InProgress;
+    order.accepted_at = Some(Clock::get()?.unix_timestamp);
+
+    emit!(OrderAccepted {
+        order: order.key(),
+        seller: order.seller,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct OrderAccepted {
+    pub order: Pubkey,
+    pub seller: Pubkey,
+}
@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::spl_token::native_mint;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct AddAcceptedMint<'info> {
+    #[account(
+        seeds = [b"marketplace"],
+        bump = marketplace.bump,
+        has_one = authority
+    )]
+    pub marketplace: Account<'info, Marketplace>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = AcceptedMint::LEN,
+        seeds = [b"accepted_mint", mint.key().as_ref()],
+        bump
+    )]
+    pub accepted_mint: Account<'info, AcceptedMint>,
+
+    /// Omitted for SOL, whose treasury comes with the marketplace
+    #[account(
+        init,
+        payer = authority,
+        space = Treasury::LEN,
+        seeds = [b"treasury", mint.key().as_ref()],
+        bump
+    )]
+    pub treasury: Option<Box<Account<'info, Treasury>>>,
+
+    #[account(
+        init,
+        payer = authority,
+        seeds = [b"treasury_vault", mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = treasury
+    )]
+    pub treasury_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn add_accepted_mint(
+    ctx: Context<AddAcceptedMint>,
+    min_price: u64,
+    max_price: u64,
+    withdrawal_threshold: u64,
+) -> Result<()> {
+    AcceptedMint::validate_bounds(min_price, max_price)?;
+
+    let accepted_mint = &mut ctx.accounts.accepted_mint;
+    accepted_mint.mint = ctx.accounts.mint.key();
+    accepted_mint.min_price = min_price;
+    accepted_mint.max_price = max_price;
+    accepted_mint.withdrawal_threshold = withdrawal_threshold;
+    accepted_mint.is_active = true;
+    accepted_mint.added_at = Clock::get()?.unix_timestamp;
+    accepted_mint.bump = ctx.bumps.accepted_mint;
+
+    // Every accepted mint gets a treasury up front so settlements always
+    // have somewhere to pay fees
+    if accepted_mint.mint == native_mint::ID {
+        require!(
+            ctx.accounts.treasury.is_none() && ctx.accounts.treasury_vault.is_none(),
+            SolanaGigsError::InvalidAccountData
+        );
+    } else {
+        let treasury_vault_key = ctx.accounts.treasury_vault.as_ref()
+            .ok_or(SolanaGigsError::TokenAccountNotFound)?
+            .key();
+        let treasury = ctx.accounts.treasury.as_mut()
+            .ok_or(SolanaGigsError::InvalidAccountData)?;
+        treasury.mint = accepted_mint.mint;
+        treasury.vault = treasury_vault_key;
+        treasury.is_native = false;
+        treasury.total_withdrawn = 0;
+        treasury.withdrawal_count = 0;
+        treasury.bump = ctx.bumps.treasury;
+    }
+
+    emit!(AcceptedMintUpdated {
+        mint: accepted_mint.mint,
+        min_price,
+        max_price,
+        withdrawal_threshold,
+        is_active: true,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct AcceptedMintUpdated {
+    pub mint: Pubkey,
+    pub min_price: u64,
+    pub max_price: u64,
+    pub withdrawal_threshold: u64,
+    pub is_active: bool,
+}
@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct AppealDispute<'info> {
+    #[account(
+        seeds = [b"marketplace"],
+        bump = marketplace.bump
+    )]
+    pub marketplace: Box<Account<'info, Marketplace>>,
+
+    #[account(
+        seeds = [b"order", order.id.to_le_bytes().as_ref()],
+        bump = order.bump,
+        constraint = order.is_participant(&appellant.key()) @ SolanaGigsError::Unauthorized
+    )]
+    pub order: Box<Account<'info, Order>>,
+
+    #[account(
+        mut,
+        seeds = [b"dispute", order.key().as_ref()],
+        bump = dispute.bump,
+        has_one = order
+    )]
+    pub dispute: Box<Account<'info, Dispute>>,
+
+    #[account(
+        init,
+        payer = appellant,
+        space = DisputeAppeal::LEN,
+        seeds = [b"appeal", dispute.key().as_ref()],
+        bump
+    )]
+    pub appeal: Box<Account<'info, DisputeAppeal>>,
+
+    #[account(mut)]
+    pub appellant: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Escalates a pending resolution to the appeals tier. Only the party the
+/// resolution went against may appeal, and it posts the marketplace bond.
+/// The appeals tier has the marketplace arbiter period to decide; after
+/// that the appeal lapses through `default_dispute`.
+pub fn appeal_dispute(ctx: Context<AppealDispute>, reason: String) -> Result<()> {
+    ctx.accounts.marketplace.require_funds_unfrozen()?;
+
+    require!(
+        reason.len() >= Dispute::MIN_REASON_LEN && reason.len() <= DisputeAppeal::MAX_REASON_LEN,
+        SolanaGigsError::InvalidDisputeReason
+    );
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let order = &ctx.accounts.order;
+    let dispute = &mut ctx.accounts.dispute;
+    let appellant = ctx.accounts.appellant.key();
+
+    require!(dispute.can_appeal(current_time), SolanaGigsError::AppealWindowClosed);
+
+    let lost_something = if appellant == order.buyer {
+        dispute.refund_bps < 10_000
+    } else {
+        dispute.refund_bps > 0
+    };
+    require!(lost_something, SolanaGigsError::CannotAppealResolution);
+
+    let bond = ctx.accounts.marketplace.appeal_bond;
+    if bond > 0 {
+        let cpi_accounts = system_program::Transfer {
+            from: ctx.accounts.appellant.to_account_info(),
+            to: ctx.accounts.appeal.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.system_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        system_program::transfer(cpi_ctx, bond)?;
+    }
+
+    let appeal = &mut ctx.accounts.appeal;
+    appeal.dispute = dispute.key();
+    appeal.order = order.key();
+    appeal.appellant = appellant;
+    appeal.bond = bond;
+    appeal.original_resolution = dispute.resolution.ok_or(SolanaGigsError::InvalidDisputeResolution)?;
+    appeal.original_refund_bps = dispute.refund_bps;
+    appeal.reason = reason;
+    appeal.created_at = current_time;
+    appeal.bump = ctx.bumps.appeal;
+
+    dispute.status = DisputeStatus::Appealed;
+    dispute.appealed = true;
+    dispute.arbiter_deadline = current_time.checked_add(ctx.accounts.marketplace.arbiter_period)
+        .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+    dispute.updated_at = current_time;
+
+    emit!(DisputeAppealed {
+        order: order.key(),
+        dispute: dispute.key(),
+        appellant,
+        bond,
+        original_refund_bps: appeal.original_refund_bps,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct DisputeAppealed {
+    pub order: Pubkey,
+    pub dispute: Pubkey,
+    pub appellant: Pubkey,
+    pub bond: u64,
+    pub original_refund_bps: u16,
+}
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::instructions::assign_arbiter::assign;
+
+#[derive(Accounts)]
+pub struct AppointArbiter<'info> {
+    #[account(
+        seeds = [b"marketplace"],
+        bump = marketplace.bump
+    )]
+    pub marketplace: Box<Account<'info, Marketplace>>,
+
+    #[account(
+        seeds = [b"gig", gig.id.to_le_bytes().as_ref()],
+        bump = gig.bump
+    )]
+    pub gig: Box<Account<'info, Gig>>,
+
+    #[account(
+        mut,
+        seeds = [b"order", order.id.to_le_bytes().as_ref()],
+        bump = order.bump,
+        has_one = gig
+    )]
+    pub order: Box<Account<'info, Order>>,
+
+    #[account(
+        mut,
+        seeds = [b"dispute", order.key().as_ref()],
+        bump = dispute.bump,
+        has_one = order
+    )]
+    pub dispute: Box<Account<'info, Dispute>>,
+
+    #[account(
+        mut,
+        seeds = [b"arbiter", arbiter.index.to_le_bytes().as_ref()],
+        bump = arbiter.bump
+    )]
+    pub arbiter: Box<Account<'info, Arbiter>>,
+
+    #[account(
+        mut,
+        seeds = [b"arbiter", previous_arbiter.index.to_le_bytes().as_ref()],
+        bump = previous_arbiter.bump,
+        constraint = dispute.arbiter == Some(previous_arbiter.key()) @ SolanaGigsError::ArbiterNotAssigned
+    )]
+    pub previous_arbiter: Option<Box<Account<'info, Arbiter>>>,
+
+    #[account(address = marketplace.dispute_admin @ SolanaGigsError::AdminPrivilegesRequired)]
+    pub dispute_admin: Signer<'info>,
+}
+
+/// Lets the dispute admin hand a dispute to a specific arbiter,
+/// replacing the current one if the dispute was already assigned.
+pub fn appoint_arbiter(ctx: Context<AppointArbiter>) -> Result<()> {
+    let dispute = &mut ctx.accounts.dispute;
+    let arbiter = &mut ctx.accounts.arbiter;
+
+    require!(dispute.is_active(), SolanaGigsError::NoDisputeFound);
+    require!(
+        dispute.resolution_mode == ResolutionMode::Arbiter,
+        SolanaGigsError::InvalidResolutionMode
+    );
+    require!(dispute.arbiter != Some(arbiter.key()), SolanaGigsError::ArbiterAlreadyAssigned);
+    require!(arbiter.is_eligible(ctx.accounts.gig.category), SolanaGigsError::ArbiterNotEligible);
+
+    if dispute.arbiter.is_some() {
+        let previous_arbiter = ctx.accounts.previous_arbiter.as_mut()
+            .ok_or(SolanaGigsError::ArbiterNotAssigned)?;
+        previous_arbiter.active_cases = previous_arbiter.active_cases.saturating_sub(1);
+    }
+
+    assign(&mut ctx.accounts.order, dispute, arbiter, ctx.accounts.marketplace.arbiter_period)
+}
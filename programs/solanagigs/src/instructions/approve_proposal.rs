@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct ApproveProposal<'info> {
+    #[account(
+        seeds = [b"governance"],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+
+    pub approver: Signer<'info>,
+}
+
+pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let governance = &ctx.accounts.governance;
+    let approver = ctx.accounts.approver.key();
+    governance.require_signer(&approver)?;
+
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.approve(governance, approver, current_time)?;
+
+    emit!(ProposalApproved {
+        proposal: proposal.key(),
+        approver,
+        approvals: proposal.approvals.len() as u8,
+        executable_at: proposal.executable_at,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ProposalApproved {
+    pub proposal: Pubkey,
+    pub approver: Pubkey,
+    pub approvals: u8,
+    pub executable_at: Option<i64>,
+}
@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct AssignArbiter<'info> {
+    #[account(
+        mut,
+        seeds = [b"marketplace"],
+        bump = marketplace.bump
+    )]
+    pub marketplace: Box<Account<'info, Marketplace>>,
+
+    #[account(
+        seeds = [b"gig", gig.id.to_le_bytes().as_ref()],
+        bump = gig.bump
+    )]
+    pub gig: Box<Account<'info, Gig>>,
+
+    #[account(
+        mut,
+        seeds = [b"order", order.id.to_le_bytes().as_ref()],
+        bump = order.bump,
+        has_one = gig
+    )]
+    pub order: Box<Account<'info, Order>>,
+
+    #[account(
+        mut,
+        seeds = [b"dispute", order.key().as_ref()],
+        bump = dispute.bump,
+        has_one = order
+    )]
+    pub dispute: Box<Account<'info, Dispute>>,
+
+    #[account(
+        mut,
+        seeds = [b"arbiter", arbiter.index.to_le_bytes().as_ref()],
+        bump = arbiter.bump
+    )]
+    pub arbiter: Box<Account<'info, Arbiter>>,
+}
+
+/// Permissionless round-robin assignment. The arbiter must be the first
+/// eligible one from the marketplace pointer; every arbiter skipped on the
+/// way has to be passed in `remaining_accounts` to prove it was ineligible.
+pub fn assign_arbiter<'info>(ctx: Context<'_, '_, 'info, 'info, AssignArbiter<'info>>) -> Result<()> {
+    let marketplace = &mut ctx.accounts.marketplace;
+    let category = ctx.accounts.gig.category;
+
+    require!(ctx.accounts.dispute.is_active(), SolanaGigsError::NoDisputeFound);
+    require!(
+        ctx.accounts.dispute.resolution_mode == ResolutionMode::Arbiter,
+        SolanaGigsError::InvalidResolutionMode
+    );
+    require!(ctx.accounts.dispute.arbiter.is_none(), SolanaGigsError::ArbiterAlreadyAssigned);
+    require!(marketplace.arbiter_count > 0, SolanaGigsError::ArbiterNotEligible);
+
+    let expected_index = walk_rotation(marketplace, category, ctx.remaining_accounts, None)?;
+
+    let arbiter = &mut ctx.accounts.arbiter;
+    require!(arbiter.index == expected_index, SolanaGigsError::ArbiterOutOfRotation);
+    require!(arbiter.is_eligible(category), SolanaGigsError::ArbiterNotEligible);
+
+    marketplace.next_arbiter_index = (expected_index + 1) % marketplace.arbiter_count;
+
+    let arbiter_period = marketplace.arbiter_period;
+    assign(&mut ctx.accounts.order, &mut ctx.accounts.dispute, arbiter, arbiter_period)
+}
+
+/// Checks the skipped arbiters against the rotation starting at the
+/// marketplace pointer and returns the index the assignee must have.
+/// `excluded` is an arbiter that may be skipped even though it is eligible.
+pub(crate) fn walk_rotation<'info>(
+    marketplace: &Marketplace,
+    category: ServiceCategory,
+    skipped: &'info [AccountInfo<'info>],
+    excluded: Option<Pubkey>,
+) -> Result<u32> {
+    let mut expected_index = marketplace.next_arbiter_index % marketplace.arbiter_count;
+    for account_info in skipped.iter() {
+        let skipped = Account::<Arbiter>::try_from(account_info)?;
+        require!(skipped.index == expected_index, SolanaGigsError::ArbiterOutOfRotation);
+        require!(
+            !skipped.is_eligible(category) || excluded == Some(skipped.key()),
+            SolanaGigsError::ArbiterOutOfRotation
+        );
+        expected_index = (expected_index + 1) % marketplace.arbiter_count;
+    }
+    Ok(expected_index)
+}
+
+pub(crate) fn assign(
+    order: &mut Order,
+    dispute: &mut Dispute,
+    arbiter: &mut Account<Arbiter>,
+    arbiter_period: i64,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let arbiter_key = arbiter.key();
+
+    order.arbiter = Some(arbiter_key);
+    dispute.arbiter = Some(arbiter_key);
+    dispute.arbiter_deadline = current_time.checked_add(arbiter_period)
+        .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+    dispute.status = DisputeStatus::UnderReview;
+    dispute.updated_at = current_time;
+
+    arbiter.active_cases = arbiter.active_cases.checked_add(1)
+        .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+    arbiter.total_cases = arbiter.total_cases.checked_add(1)
+        .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+
+    emit!(ArbiterAssigned {
+        order: dispute.order,
+        arbiter: arbiter_key,
+        arbiter_authority: arbiter.authority,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ArbiterAssigned {
+    pub order: Pubkey,
+    pub arbiter: Pubkey,
+    pub arbiter_authority: Pubkey,
+}
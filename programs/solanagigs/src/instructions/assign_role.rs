@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct AssignRole<'info> {
+    #[account(
+        mut,
+        seeds = [b"marketplace"],
+        bump = marketplace.bump,
+        has_one = authority
+    )]
+    pub marketplace: Account<'info, Marketplace>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn assign_role(ctx: Context<AssignRole>, role: AdminRole, holder: Pubkey) -> Result<()> {
+    let marketplace = &mut ctx.accounts.marketplace;
+    let previous_holder = marketplace.role_holder(role);
+
+    match role {
+        AdminRole::FeeManager => marketplace.fee_manager = holder,
+        AdminRole::Pauser => marketplace.pauser = holder,
+        AdminRole::DisputeAdmin => marketplace.dispute_admin = holder,
+        AdminRole::CategoryCurator => marketplace.category_curator = holder,
+        AdminRole::Verifier => marketplace.verifier = holder,
+    }
+
+    emit!(RoleAssigned {
+        marketplace: marketplace.key(),
+        role,
+        previous_holder,
+        holder,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct RoleAssigned {
+    pub marketplace: Pubkey,
+    pub role: AdminRole,
+    pub previous_holder: Pubkey,
+    pub holder: Pubkey,
+}
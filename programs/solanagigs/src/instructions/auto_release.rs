@@ -0,0 +1,303 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::state::*;
+use crate::errors::*;
+use crate::utils::{charge_fee, payout_account, treasury_account, EscrowPayer};
+
+#[derive(Accounts)]
+pub struct AutoRelease<'info> {
+    #[account(
+        seeds = [b"marketplace"],
+        bump = marketplace.bump
+    )]
+    pub marketplace: Box<Account<'info, Marketplace>>,
+
+    #[account(
+        seeds = [b"fee_schedule"],
+        bump = fee_schedule.bump
+    )]
+    pub fee_schedule: Box<Account<'info, FeeSchedule>>,
+
+    #[account(
+        mut,
+        seeds = [b"gig", gig.id.to_le_bytes().as_ref()],
+        bump = gig.bump
+    )]
+    pub gig: Box<Account<'info, Gig>>,
+
+    #[account(
+        mut,
+        seeds = [b"order", order.id.to_le_bytes().as_ref()],
+        bump = order.bump,
+        has_one = gig
+    )]
+    pub order: Box<Account<'info, Order>>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", order.key().as_ref()],
+        bump = escrow.bump,
+        has_one = order
+    )]
+    pub escrow: Box<Account<'info, Escrow>>,
+
+    #[account(
+        mut,
+        constraint = vault.key() == escrow.vault @ SolanaGigsError::InvalidEscrowState,
+        token::mint = escrow.mint
+    )]
+    pub vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        address = escrow.mint @ SolanaGigsError::InvalidTokenMint
+    )]
+    pub mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        mut,
+        seeds = [b"user", order.seller.as_ref()],
+        bump = seller_profile.bump
+    )]
+    pub seller_profile: Box<Account<'info, UserProfile>>,
+
+    #[account(
+        mut,
+        seeds = [b"volume", order.seller.as_ref(), escrow.mint.as_ref()],
+        bump = seller_volume.bump
+    )]
+    pub seller_volume: Box<Account<'info, SellerVolume>>,
+
+    #[account(
+        mut,
+        token::mint = escrow.mint,
+        token::authority = order.seller
+    )]
+    pub seller_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// CHECK: The seller's earnings PDA, which is credited instead of the seller once initialized
+    #[account(
+        mut,
+        seeds = [b"earnings", order.seller.as_ref(), escrow.mint.as_ref()],
+        bump
+    )]
+    pub seller_earnings: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub earnings_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// CHECK: The buyer's referral PDA, which pays a referrer once initialized
+    #[account(
+        mut,
+        seeds = [b"referral", order.buyer.as_ref()],
+        bump
+    )]
+    pub buyer_referral: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        token::mint = escrow.mint
+    )]
+    pub buyer_referrer_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(mut)]
+    pub buyer_referrer: Option<SystemAccount<'info>>,
+
+    /// CHECK: The seller's referral PDA, which pays a referrer once initialized
+    #[account(
+        mut,
+        seeds = [b"referral", order.seller.as_ref()],
+        bump
+    )]
+    pub seller_referral: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        token::mint = escrow.mint
+    )]
+    pub seller_referrer_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(mut)]
+    pub seller_referrer: Option<SystemAccount<'info>>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury", escrow.mint.as_ref()],
+        bump = treasury.bump
+    )]
+    pub treasury: Box<Account<'info, Treasury>>,
+
+    #[account(
+        mut,
+        address = treasury.vault @ SolanaGigsError::InvalidAccountData
+    )]
+    pub treasury_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        token::mint = escrow.mint
+    )]
+    pub caller_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        address = order.buyer @ SolanaGigsError::Unauthorized
+    )]
+    pub buyer_wallet: Option<SystemAccount<'info>>,
+
+    #[account(
+        mut,
+        address = order.seller @ SolanaGigsError::Unauthorized
+    )]
+    pub seller: Option<SystemAccount<'info>>,
+
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn auto_release<'info>(ctx: Context<'_, '_, '_, 'info, AutoRelease<'info>>) -> Result<()> {
+    ctx.accounts.marketplace.require_funds_unfrozen()?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let order = &mut ctx.accounts.order;
+    let escrow = &mut ctx.accounts.escrow;
+    let gig = &mut ctx.accounts.gig;
+
+    require!(order.status == OrderStatus::Delivered, SolanaGigsError::InvalidOrderStatus);
+    if !escrow.can_auto_release(current_time) {
+        msg!(
+            "Dispute window closes in {} seconds",
+            escrow.remaining_dispute_time(current_time)
+        );
+        return err!(SolanaGigsError::AutoReleaseNotAvailable);
+    }
+
+    // Calculate fees, carving the caller bounty out of the marketplace fee
+    let amount = escrow.remaining_amount();
+    let marketplace = &ctx.accounts.marketplace;
+    let marketplace_fee = charge_fee(
+        &ctx.accounts.fee_schedule,
+        order.key(),
+        gig.category,
+        &ctx.accounts.seller_profile,
+        &ctx.accounts.seller_volume,
+        amount,
+    )?;
+    let seller_amount = amount
+        .checked_sub(marketplace_fee)
+        .ok_or(SolanaGigsError::ArithmeticUnderflow)?;
+    // Lamport escrows can always pay the bounty straight to the caller's wallet
+    let bounty = if escrow.is_native || ctx.accounts.caller_token_account.is_some() {
+        marketplace.calculate_auto_release_bounty(marketplace_fee)
+    } else {
+        0
+    };
+
+    let payer = EscrowPayer {
+        escrow,
+        vault: &ctx.accounts.vault,
+        mint: &ctx.accounts.mint,
+        token_program: &ctx.accounts.token_program,
+        remaining_accounts: ctx.remaining_accounts,
+    };
+
+    // Transfer payment to seller
+    payer.pay_seller(
+        &ctx.accounts.seller_earnings,
+        &ctx.accounts.earnings_vault,
+        &ctx.accounts.seller_token_account,
+        &ctx.accounts.seller,
+        seller_amount,
+    )?;
+
+    // Referrers are paid out of the marketplace fee, never on top of it
+    let referral_reward = ctx.accounts.marketplace.calculate_referral_reward(marketplace_fee);
+    let buyer_referral = payer.pay_referrer(
+        &ctx.accounts.buyer_referral,
+        &ctx.accounts.buyer_referrer_token_account,
+        &ctx.accounts.buyer_referrer,
+        referral_reward,
+    )?;
+    let seller_referral = payer.pay_referrer(
+        &ctx.accounts.seller_referral,
+        &ctx.accounts.seller_referrer_token_account,
+        &ctx.accounts.seller_referrer,
+        referral_reward,
+    )?;
+    let referral_total = buyer_referral.map_or(0, |split| split.amount)
+        + seller_referral.map_or(0, |split| split.amount);
+
+    // The caller bounty comes out of whatever the referrers leave
+    let marketplace_share = marketplace_fee
+        .checked_sub(referral_total)
+        .ok_or(SolanaGigsError::ArithmeticUnderflow)?;
+    let bounty = bounty.min(marketplace_share);
+    let net_marketplace_fee = marketplace_share - bounty;
+
+    // Transfer the rest of the fee to marketplace
+    if net_marketplace_fee > 0 {
+        let fee_destination = treasury_account(&ctx.accounts.treasury, &ctx.accounts.treasury_vault)?;
+        payer.transfer(fee_destination, net_marketplace_fee)?;
+    }
+
+    // Reward the caller for cranking the release
+    if bounty > 0 {
+        let caller_destination = if escrow.is_native {
+            ctx.accounts.caller.to_account_info()
+        } else {
+            payout_account(escrow.is_native, &ctx.accounts.caller_token_account, &None)?
+        };
+        payer.transfer(caller_destination, bounty)?;
+    }
+
+    // Settlement is final, so the vault is drained and closed
+    payer.close_vault(
+        &ctx.accounts.treasury_vault,
+        ctx.accounts.buyer_wallet.as_ref().map(|buyer| buyer.to_account_info()),
+    )?;
+
+    order.status = OrderStatus::Completed;
+    order.completed_at = Some(current_time);
+
+    escrow.platform_fee = escrow.platform_fee.checked_add(net_marketplace_fee)
+        .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+    escrow.partial_release_amount = escrow.amount;
+    escrow.released_at = Some(current_time);
+
+    gig.active_orders = gig.active_orders.saturating_sub(1);
+    gig.completed_orders = gig.completed_orders.checked_add(1)
+        .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+
+    let seller_profile = &mut ctx.accounts.seller_profile;
+    seller_profile.add_earnings(seller_amount)?;
+    ctx.accounts.seller_volume.add_volume(seller_amount)?;
+    seller_profile.increment_gigs_completed()?;
+
+    emit!(EscrowAutoReleased {
+        order: order.key(),
+        seller: order.seller,
+        caller: ctx.accounts.caller.key(),
+        seller_amount,
+        marketplace_fee: net_marketplace_fee,
+        bounty,
+        buyer_referral,
+        seller_referral,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct EscrowAutoReleased {
+    pub order: Pubkey,
+    pub seller: Pubkey,
+    pub caller: Pubkey,
+    pub seller_amount: u64,
+    pub marketplace_fee: u64,
+    pub bounty: u64,
+    pub buyer_referral: Option<ReferralSplit>,
+    pub seller_referral: Option<ReferralSplit>,
+}
@@ -0,0 +1,148 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::state::*;
+use crate::errors::*;
+use crate::utils::{payout_account, EscrowPayer};
+
+#[derive(Accounts)]
+pub struct CancelOrder<'info> {
+    #[account(
+        seeds = [b"marketplace"],
+        bump = marketplace.bump
+    )]
+    pub marketplace: Box<Account<'info, Marketplace>>,
+
+    #[account(
+        mut,
+        seeds = [b"gig", gig.id.to_le_bytes().as_ref()],
+        bump = gig.bump
+    )]
+    pub gig: Box<Account<'info, Gig>>,
+
+    #[account(
+        mut,
+        seeds = [b"order", order.id.to_le_bytes().as_ref()],
+        bump = order.bump,
+        has_one = gig,
+        constraint = order.is_participant(&signer.key()) @ SolanaGigsError::Unauthorized
+    )]
+    pub order: Box<Account<'info, Order>>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", order.key().as_ref()],
+        bump = escrow.bump,
+        has_one = order
+    )]
+    pub escrow: Box<Account<'info, Escrow>>,
+
+    #[account(
+        mut,
+        constraint = vault.key() == escrow.vault @ SolanaGigsError::InvalidEscrowState,
+        token::mint = escrow.mint
+    )]
+    pub vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        address = escrow.mint @ SolanaGigsError::InvalidTokenMint
+    )]
+    pub mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        mut,
+        token::mint = escrow.mint,
+        token::authority = order.buyer
+    )]
+    pub buyer_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury", escrow.mint.as_ref()],
+        bump = treasury.bump
+    )]
+    pub treasury: Box<Account<'info, Treasury>>,
+
+    #[account(
+        mut,
+        address = treasury.vault @ SolanaGigsError::InvalidAccountData
+    )]
+    pub treasury_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        address = order.buyer @ SolanaGigsError::Unauthorized
+    )]
+    pub buyer_wallet: Option<SystemAccount<'info>>,
+
+    pub signer: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn cancel_order<'info>(ctx: Context<'_, '_, '_, 'info, CancelOrder<'info>>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let order = &mut ctx.accounts.order;
+    let escrow = &mut ctx.accounts.escrow;
+
+    // Refunds of orders that never started stay open under an emergency stop
+    if order.status != OrderStatus::Pending {
+        ctx.accounts.marketplace.require_funds_unfrozen()?;
+    }
+
+    // Only allow cancellation if order is pending or past deadline
+    require!(order.can_be_cancelled(current_time), SolanaGigsError::CannotCancelOrder);
+    require!(!escrow.is_settled(), SolanaGigsError::EscrowAlreadyReleased);
+
+    let refund_amount = escrow.remaining_amount();
+
+    let payer = EscrowPayer {
+        escrow,
+        vault: &ctx.accounts.vault,
+        mint: &ctx.accounts.mint,
+        token_program: &ctx.accounts.token_program,
+        remaining_accounts: ctx.remaining_accounts,
+    };
+
+    // Refund buyer
+    let buyer_destination = payout_account(
+        escrow.is_native,
+        &ctx.accounts.buyer_token_account,
+        &ctx.accounts.buyer_wallet,
+    )?;
+    payer.transfer(buyer_destination, refund_amount)?;
+
+    // Settlement is final, so the vault is drained and closed
+    payer.close_vault(
+        &ctx.accounts.treasury_vault,
+        ctx.accounts.buyer_wallet.as_ref().map(|buyer| buyer.to_account_info()),
+    )?;
+
+    order.status = OrderStatus::Cancelled;
+    order.has_pending_extension = false;
+    order.cancelled_at = Some(current_time);
+
+    escrow.partial_release_amount = escrow.amount;
+    escrow.refunded_at = Some(current_time);
+
+    let gig = &mut ctx.accounts.gig;
+    gig.active_orders = gig.active_orders.saturating_sub(1);
+
+    emit!(OrderCancelled {
+        order: order.key(),
+        buyer: order.buyer,
+        cancelled_by: ctx.accounts.signer.key(),
+        refund_amount,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct OrderCancelled {
+    pub order: Pubkey,
+    pub buyer: Pubkey,
+    pub cancelled_by: Pubkey,
+    pub refund_amount: u64,
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct CancelProposal<'info> {
+    #[account(
+        seeds = [b"governance"],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+
+    pub signer: Signer<'info>,
+}
+
+/// Any governance signer can veto a proposal that has not run yet, including
+/// one already sitting out its timelock.
+pub fn cancel_proposal(ctx: Context<CancelProposal>) -> Result<()> {
+    let signer = ctx.accounts.signer.key();
+    ctx.accounts.governance.require_signer(&signer)?;
+
+    let proposal = &mut ctx.accounts.proposal;
+    require!(
+        matches!(proposal.status, ProposalStatus::Pending | ProposalStatus::Approved),
+        SolanaGigsError::InvalidProposalStatus
+    );
+    proposal.status = ProposalStatus::Cancelled;
+
+    emit!(ProposalCancelled {
+        proposal: proposal.key(),
+        cancelled_by: signer,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ProposalCancelled {
+    pub proposal: Pubkey,
+    pub cancelled_by: Pubkey,
+}
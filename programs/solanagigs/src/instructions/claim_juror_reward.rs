@@ -0,0 +1,167 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::state::*;
+use crate::errors::*;
+use crate::utils::{debit_lamports, payout_account, EscrowPayer};
+
+#[derive(Accounts)]
+pub struct ClaimJurorReward<'info> {
+    #[account(
+        seeds = [b"marketplace"],
+        bump = marketplace.bump
+    )]
+    pub marketplace: Box<Account<'info, Marketplace>>,
+
+    #[account(
+        seeds = [b"order", order.id.to_le_bytes().as_ref()],
+        bump = order.bump
+    )]
+    pub order: Box<Account<'info, Order>>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", order.key().as_ref()],
+        bump = escrow.bump,
+        has_one = order
+    )]
+    pub escrow: Box<Account<'info, Escrow>>,
+
+    #[account(
+        mut,
+        seeds = [b"jury", jury_case.dispute.as_ref()],
+        bump = jury_case.bump,
+        has_one = order
+    )]
+    pub jury_case: Box<Account<'info, JuryCase>>,
+
+    #[account(
+        mut,
+        constraint = vault.key() == escrow.vault @ SolanaGigsError::InvalidEscrowState,
+        token::mint = escrow.mint
+    )]
+    pub vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        address = escrow.mint @ SolanaGigsError::InvalidTokenMint
+    )]
+    pub mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        mut,
+        token::mint = escrow.mint,
+        token::authority = juror
+    )]
+    pub juror_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury", escrow.mint.as_ref()],
+        bump = treasury.bump
+    )]
+    pub treasury: Box<Account<'info, Treasury>>,
+
+    #[account(
+        mut,
+        address = treasury.vault @ SolanaGigsError::InvalidAccountData
+    )]
+    pub treasury_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        address = order.buyer @ SolanaGigsError::Unauthorized
+    )]
+    pub buyer_wallet: Option<SystemAccount<'info>>,
+
+    #[account(mut)]
+    pub juror: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Pays a majority juror an equal share of the juror fee and of the stakes
+/// slashed from no-shows, once `finalize_dispute` has settled the verdict and
+/// until the claim deadline. The last claimer also collects rounding dust.
+pub fn claim_juror_reward<'info>(ctx: Context<'_, '_, '_, 'info, ClaimJurorReward<'info>>) -> Result<()> {
+    ctx.accounts.marketplace.require_funds_unfrozen()?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let escrow = &mut ctx.accounts.escrow;
+    let jury_case = &mut ctx.accounts.jury_case;
+    let juror = ctx.accounts.juror.key();
+
+    require!(jury_case.status == JuryStatus::Settled, SolanaGigsError::VotingPhaseActive);
+    require!(ctx.accounts.order.status == OrderStatus::Resolved, SolanaGigsError::InvalidOrderStatus);
+    require!(current_time <= jury_case.claim_deadline, SolanaGigsError::ClaimDeadlinePassed);
+
+    let position = jury_case.juror_position(&juror)
+        .ok_or(SolanaGigsError::JurorNotOnPanel)?;
+    let vote = &jury_case.jurors[position];
+    require!(!vote.reward_claimed, SolanaGigsError::JurorRewardUnavailable);
+    let refund_bps = vote.refund_bps.ok_or(SolanaGigsError::JurorRewardUnavailable)?;
+    require!(jury_case.is_majority_vote(refund_bps), SolanaGigsError::JurorRewardUnavailable);
+
+    let claimants_left = (jury_case.majority_count - jury_case.claims_made) as u64;
+    let fee_share = escrow.remaining_amount() / claimants_left;
+    let slashed_share = jury_case.slashed_pool / claimants_left;
+
+    let payer = EscrowPayer {
+        escrow,
+        vault: &ctx.accounts.vault,
+        mint: &ctx.accounts.mint,
+        token_program: &ctx.accounts.token_program,
+        remaining_accounts: ctx.remaining_accounts,
+    };
+
+    if fee_share > 0 {
+        let juror_destination = if escrow.is_native {
+            ctx.accounts.juror.to_account_info()
+        } else {
+            payout_account(escrow.is_native, &ctx.accounts.juror_token_account, &None)?
+        };
+        payer.transfer(juror_destination, fee_share)?;
+    }
+
+    // The last majority juror empties the escrow. Without a juror fee the
+    // vault was already closed by finalize_dispute.
+    if claimants_left == 1 && jury_case.juror_fee > 0 {
+        payer.close_vault(
+            &ctx.accounts.treasury_vault,
+            ctx.accounts.buyer_wallet.as_ref().map(|buyer| buyer.to_account_info()),
+        )?;
+    }
+
+    escrow.partial_release_amount = escrow.partial_release_amount
+        .checked_add(fee_share)
+        .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+
+    if slashed_share > 0 {
+        debit_lamports(
+            &jury_case.to_account_info(),
+            &ctx.accounts.juror.to_account_info(),
+            slashed_share,
+        )?;
+        jury_case.slashed_pool -= slashed_share;
+    }
+
+    jury_case.jurors[position].reward_claimed = true;
+    jury_case.claims_made += 1;
+
+    emit!(JurorRewardClaimed {
+        dispute: jury_case.dispute,
+        juror,
+        fee_share,
+        slashed_share,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct JurorRewardClaimed {
+    pub dispute: Pubkey,
+    pub juror: Pubkey,
+    pub fee_share: u64,
+    pub slashed_share: u64,
+}
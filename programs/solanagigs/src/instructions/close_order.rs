@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct CloseOrder<'info> {
+    #[account(
+        seeds = [b"marketplace"],
+        bump = marketplace.bump
+    )]
+    pub marketplace: Box<Account<'info, Marketplace>>,
+
+    #[account(
+        mut,
+        seeds = [b"order", order.id.to_le_bytes().as_ref()],
+        bump = order.bump,
+        close = buyer
+    )]
+    pub order: Box<Account<'info, Order>>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", order.key().as_ref()],
+        bump = escrow.bump,
+        has_one = order,
+        close = buyer
+    )]
+    pub escrow: Box<Account<'info, Escrow>>,
+
+    #[account(
+        mut,
+        seeds = [b"dispute", order.key().as_ref()],
+        bump = dispute.bump,
+        has_one = order
+    )]
+    pub dispute: Option<Box<Account<'info, Dispute>>>,
+
+    #[account(
+        mut,
+        seeds = [b"review", order.key().as_ref(), order.buyer.as_ref()],
+        bump = buyer_review.bump
+    )]
+    pub buyer_review: Option<Box<Account<'info, Review>>>,
+
+    #[account(
+        mut,
+        seeds = [b"review", order.key().as_ref(), order.seller.as_ref()],
+        bump = seller_review.bump
+    )]
+    pub seller_review: Option<Box<Account<'info, Review>>>,
+
+    /// CHECK: Paid for the order and escrow accounts and gets their rent back
+    #[account(
+        mut,
+        address = order.buyer @ SolanaGigsError::Unauthorized
+    )]
+    pub buyer: UncheckedAccount<'info>,
+
+    /// CHECK: Gets the rent of the seller's review back
+    #[account(
+        mut,
+        address = order.seller @ SolanaGigsError::Unauthorized
+    )]
+    pub seller: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Opened the dispute and gets its rent back
+    #[account(mut)]
+    pub dispute_initiator: Option<UncheckedAccount<'info>>,
+}
+
+/// Permissionless cleanup of a finalized order once the marketplace
+/// retention period has passed. Closes the order and escrow together with
+/// the order's dispute and reviews, if any; every closed account's rent goes
+/// back to whoever paid for it. Milestone, revision, extension and jury
+/// accounts are not closed here.
+pub fn close_order(ctx: Context<CloseOrder>) -> Result<()> {
+    ctx.accounts.marketplace.require_funds_unfrozen()?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let order = &ctx.accounts.order;
+
+    let finalized_at = order.finalized_at().ok_or(SolanaGigsError::OrderNotFinalized)?;
+    require!(ctx.accounts.escrow.remaining_amount() == 0, SolanaGigsError::OrderNotFinalized);
+    let retention_end = finalized_at.checked_add(ctx.accounts.marketplace.retention_period)
+        .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+    require!(current_time >= retention_end, SolanaGigsError::RetentionPeriodActive);
+
+    if order.disputed_at.is_some() {
+        let dispute = ctx.accounts.dispute.as_ref()
+            .ok_or(SolanaGigsError::NoDisputeFound)?;
+        let dispute_initiator = ctx.accounts.dispute_initiator.as_ref()
+            .ok_or(SolanaGigsError::InvalidAccountData)?;
+        require_keys_eq!(dispute_initiator.key(), dispute.initiator, SolanaGigsError::Unauthorized);
+        dispute.close(dispute_initiator.to_account_info())?;
+    }
+
+    // Buyer reviews are written by the buyer, seller reviews by the seller
+    if order.buyer_rating.is_some() {
+        let buyer_review = ctx.accounts.buyer_review.as_ref()
+            .ok_or(SolanaGigsError::InvalidAccountData)?;
+        buyer_review.close(ctx.accounts.buyer.to_account_info())?;
+    }
+
+    if order.seller_rating.is_some() {
+        let seller_review = ctx.accounts.seller_review.as_ref()
+            .ok_or(SolanaGigsError::InvalidAccountData)?;
+        let seller = ctx.accounts.seller.as_ref()
+            .ok_or(SolanaGigsError::InvalidAccountData)?;
+        seller_review.close(seller.to_account_info())?;
+    }
+
+    emit!(OrderClosed {
+        order: order.key(),
+        buyer: order.buyer,
+        seller: order.seller,
+        finalized_at,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct OrderClosed {
+    pub order: Pubkey,
+    pub buyer: Pubkey,
+    pub seller: Pubkey,
+    pub finalized_at: i64,
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct CommitVote<'info> {
+    #[account(
+        mut,
+        seeds = [b"jury", jury_case.dispute.as_ref()],
+        bump = jury_case.bump
+    )]
+    pub jury_case: Box<Account<'info, JuryCase>>,
+
+    pub juror: Signer<'info>,
+}
+
+/// `commitment` is `hash(refund_bps_le || salt || juror)`, see `JuryCase::commitment`.
+pub fn commit_vote(ctx: Context<CommitVote>, commitment: [u8; 32]) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let jury_case = &mut ctx.accounts.jury_case;
+    let juror = ctx.accounts.juror.key();
+
+    require!(jury_case.status == JuryStatus::Voting, SolanaGigsError::VotingPhaseClosed);
+    require!(current_time <= jury_case.commit_deadline, SolanaGigsError::VotingPhaseClosed);
+
+    let position = jury_case.juror_position(&juror)
+        .ok_or(SolanaGigsError::JurorNotOnPanel)?;
+    let vote = &mut jury_case.jurors[position];
+    require!(vote.commitment.is_none(), SolanaGigsError::VoteAlreadyCast);
+    vote.commitment = Some(commitment);
+
+    emit!(VoteCommitted {
+        dispute: jury_case.dispute,
+        juror,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct VoteCommitted {
+    pub dispute: Pubkey,
+    pub juror: Pubkey,
+}
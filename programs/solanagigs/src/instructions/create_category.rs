@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+#[instruction(category: ServiceCategory)]
+pub struct CreateCategory<'info> {
+    #[account(
+        seeds = [b"marketplace"],
+        bump = marketplace.bump
+    )]
+    pub marketplace: Account<'info, Marketplace>,
+
+    #[account(
+        init,
+        payer = category_curator,
+        space = Category::LEN,
+        seeds = [b"category".as_ref(), &[category as u8]],
+        bump
+    )]
+    pub category_account: Account<'info, Category>,
+
+    #[account(
+        mut,
+        address = marketplace.category_curator @ SolanaGigsError::AdminPrivilegesRequired
+    )]
+    pub category_curator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Publishes the listing metadata for one `ServiceCategory`.
+pub fn create_category(
+    ctx: Context<CreateCategory>,
+    category: ServiceCategory,
+    name: String,
+    description: String,
+    subcategories: Vec<String>,
+) -> Result<()> {
+    Category::validate(&name, &description, &subcategories)?;
+
+    let category_account = &mut ctx.accounts.category_account;
+    category_account.name = name;
+    category_account.description = description;
+    category_account.subcategories = subcategories;
+    category_account.is_active = true;
+    category_account.gig_count = 0;
+    category_account.created_at = Clock::get()?.unix_timestamp;
+    category_account.bump = ctx.bumps.category_account;
+
+    emit!(CategoryUpdated {
+        category,
+        name: category_account.name.clone(),
+        is_active: true,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct CategoryUpdated {
+    pub category: ServiceCategory,
+    pub name: String,
+    pub is_active: bool,
+}
@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct CreateGig<'info> {
+    #[account(
+        mut,
+        seeds = [b"marketplace"],
+        bump = marketplace.bump
+    )]
+    pub marketplace: Account<'info, Marketplace>,
+
+    #[account(
+        init,
+        payer = seller,
+        space = Gig::LEN,
+        seeds = [b"gig", marketplace.total_gigs.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub gig: Box<Account<'info, Gig>>,
+
+    #[account(
+        mut,
+        seeds = [b"user", seller.key().as_ref()],
+        bump = seller_profile.bump,
+        constraint = seller_profile.authority == seller.key() @ SolanaGigsError::Unauthorized
+    )]
+    pub seller_profile: Box<Account<'info, UserProfile>>,
+
+    pub payment_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        seeds = [b"accepted_mint", payment_mint.key().as_ref()],
+        bump = accepted_mint.bump,
+        constraint = accepted_mint.is_active @ SolanaGigsError::UnsupportedToken
+    )]
+    pub accepted_mint: Box<Account<'info, AcceptedMint>>,
+
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CreateGigParams {
+    pub title: String,
+    pub description: String,
+    pub category: ServiceCategory,
+    pub subcategory: String,
+    pub tags: Vec<String>,
+    pub images: Vec<String>,
+    pub requirements: String,
+    pub price: u64,
+    pub delivery_time: u32,
+    pub revisions: u8,
+    pub packages: Vec<GigPackage>,
+    pub extras: Vec<GigExtra>,
+}
+
+pub fn create_gig(ctx: Context<CreateGig>, params: CreateGigParams) -> Result<()> {
+    ctx.accounts.marketplace.require_not_paused()?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let marketplace = &mut ctx.accounts.marketplace;
+    let gig = &mut ctx.accounts.gig;
+
+    gig.id = marketplace.total_gigs;
+    gig.seller = ctx.accounts.seller.key();
+    gig.payment_mint = ctx.accounts.payment_mint.key();
+    gig.title = params.title;
+    gig.description = params.description;
+    gig.category = params.category;
+    gig.subcategory = params.subcategory;
+    gig.tags = params.tags;
+    gig.images = params.images;
+    gig.packages = params.packages;
+    gig.extras = params.extras;
+    gig.requirements = params.requirements;
+    gig.price = params.price;
+    gig.delivery_time = params.delivery_time;
+    gig.revisions = params.revisions;
+    gig.is_active = true;
+    gig.total_orders = 0;
+    gig.active_orders = 0;
+    gig.completed_orders = 0;
+    gig.rating = 0;
+    gig.total_reviews = 0;
+    gig.created_at = current_time;
+    gig.updated_at = current_time;
+    gig.bump = ctx.bumps.gig;
+
+    gig.sync_base_package();
+    gig.validate()?;
+    gig.validate_prices(&ctx.accounts.accepted_mint)?;
+
+    marketplace.total_gigs = marketplace.total_gigs.checked_add(1)
+        .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+
+    let seller_profile = &mut ctx.accounts.seller_profile;
+    seller_profile.increment_gigs_created()?;
+    seller_profile.last_active = current_time;
+
+    emit!(GigCreated {
+        gig: gig.key(),
+        seller: gig.seller,
+        title: gig.title.clone(),
+        category: gig.category,
+        payment_mint: gig.payment_mint,
+        price: gig.price,
+        package_count: gig.packages.len() as u8,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct GigCreated {
+    pub gig: Pubkey,
+    pub seller: Pubkey,
+    pub title: String,
+    pub category: ServiceCategory,
+    pub payment_mint: Pubkey,
+    pub price: u64,
+    pub package_count: u8,
+}
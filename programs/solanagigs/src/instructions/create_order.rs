@@ -0,0 +1,332 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::spl_token::native_mint;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::state::*;
+use crate::errors::*;
+use crate::utils::{create_pda_account, store, transfer_checked};
+
+#[derive(Accounts)]
+pub struct CreateOrder<'info> {
+    #[account(
+        mut,
+        seeds = [b"marketplace"],
+        bump = marketplace.bump
+    )]
+    pub marketplace: Box<Account<'info, Marketplace>>,
+
+    #[account(
+        mut,
+        seeds = [b"gig", gig.id.to_le_bytes().as_ref()],
+        bump = gig.bump,
+        constraint = gig.is_active @ SolanaGigsError::GigIsNotActive,
+        constraint = gig.seller != buyer.key() @ SolanaGigsError::SellerCannotBuyOwnGig
+    )]
+    pub gig: Box<Account<'info, Gig>>,
+
+    #[account(
+        init,
+        payer = buyer,
+        space = Order::LEN,
+        seeds = [b"order", marketplace.total_orders.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub order: Box<Account<'info, Order>>,
+
+    #[account(
+        init,
+        payer = buyer,
+        space = Escrow::LEN,
+        seeds = [b"escrow", order.key().as_ref()],
+        bump
+    )]
+    pub escrow: Box<Account<'info, Escrow>>,
+
+    #[account(
+        init,
+        payer = buyer,
+        seeds = [b"escrow_vault", order.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = escrow
+    )]
+    pub escrow_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(address = gig.payment_mint @ SolanaGigsError::InvalidTokenMint)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        seeds = [b"accepted_mint", mint.key().as_ref()],
+        bump = accepted_mint.bump,
+        constraint = accepted_mint.is_active @ SolanaGigsError::UnsupportedToken
+    )]
+    pub accepted_mint: Box<Account<'info, AcceptedMint>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = buyer
+    )]
+    pub buyer_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Terms of one milestone, fixed when the order is placed.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct MilestoneParams {
+    pub title: String,
+    pub description: String,
+    pub amount: u64,
+    pub due_date: i64,
+}
+
+pub fn create_order<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateOrder<'info>>,
+    package_index: u8,
+    extras: Vec<u8>,
+    requirements: String,
+    milestones: Vec<MilestoneParams>,
+) -> Result<()> {
+    ctx.accounts.marketplace.require_not_paused()?;
+
+    require!(
+        requirements.len() <= Order::MAX_REQUIREMENTS_LEN,
+        SolanaGigsError::RequirementsTooLong
+    );
+    require!(
+        milestones.len() <= Order::MAX_MILESTONES as usize,
+        SolanaGigsError::InvalidMilestoneCount
+    );
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let mut milestones_total: u64 = 0;
+    for milestone in &milestones {
+        require!(
+            !milestone.title.is_empty() && milestone.title.len() <= OrderMilestone::MAX_TITLE_LEN,
+            SolanaGigsError::InvalidMilestoneDetails
+        );
+        require!(
+            milestone.description.len() <= OrderMilestone::MAX_DESCRIPTION_LEN,
+            SolanaGigsError::InvalidMilestoneDetails
+        );
+        require!(milestone.amount > 0, SolanaGigsError::InvalidTokenAmount);
+        require!(milestone.due_date > current_time, SolanaGigsError::TimestampInThePast);
+        milestones_total = milestones_total
+            .checked_add(milestone.amount)
+            .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+    }
+
+    // Milestone PDAs lead the remaining accounts; the rest belong to the
+    // token program's transfer hook
+    require!(
+        ctx.remaining_accounts.len() >= milestones.len(),
+        SolanaGigsError::InvalidMilestoneCount
+    );
+    let (milestone_accounts, transfer_accounts) = ctx.remaining_accounts.split_at(milestones.len());
+
+    let marketplace = &mut ctx.accounts.marketplace;
+    let gig = &mut ctx.accounts.gig;
+    let order = &mut ctx.accounts.order;
+    let escrow = &mut ctx.accounts.escrow;
+
+    let (price, delivery_time, revisions) = gig.package_terms(package_index)?;
+    let (price, delivery_time, revisions) = gig.apply_extras(&extras, price, delivery_time, revisions)?;
+    // Mint bounds may have tightened since the gig was priced, and extras add up
+    ctx.accounts.accepted_mint.check_price(price)?;
+    require!(
+        milestones.is_empty() || milestones_total == price,
+        SolanaGigsError::MilestoneTotalMismatch
+    );
+
+    let delivery_deadline = current_time
+        .checked_add(delivery_time as i64 * 86400)
+        .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+
+    order.id = marketplace.total_orders;
+    order.gig = gig.key();
+    order.buyer = ctx.accounts.buyer.key();
+    order.seller = gig.seller;
+    order.package_index = package_index;
+    order.extras = extras;
+    order.amount = price;
+    order.requirements = requirements;
+    order.status = OrderStatus::Pending;
+    order.created_at = current_time;
+    order.delivery_deadline = delivery_deadline;
+    order.accepted_at = None;
+    order.delivered_at = None;
+    order.completed_at = None;
+    order.cancelled_at = None;
+    order.disputed_at = None;
+    order.resolved_at = None;
+    order.delivery_message = None;
+    order.delivery_files = Vec::new();
+    order.revision_count = 0;
+    order.max_revisions = revisions;
+    order.dispute_reason = None;
+    order.arbiter = None;
+    order.resolution = None;
+    order.buyer_rating = None;
+    order.seller_rating = None;
+    order.milestone_count = milestones.len() as u8;
+    order.completed_milestones = 0;
+    order.milestones_total = milestones_total;
+    order.extension_count = 0;
+    order.has_pending_extension = false;
+    order.bump = ctx.bumps.order;
+
+    escrow.order = order.key();
+    escrow.buyer = order.buyer;
+    escrow.seller = order.seller;
+    escrow.mint = ctx.accounts.mint.key();
+    escrow.is_native = escrow.mint == native_mint::ID;
+    escrow.amount = order.amount;
+    escrow.platform_fee = 0;
+    escrow.partial_release_amount = 0;
+    escrow.created_at = current_time;
+    escrow.released_at = None;
+    escrow.refunded_at = None;
+    escrow.dispute_deadline = delivery_deadline
+        .checked_add(marketplace.dispute_period)
+        .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+    escrow.auto_release_enabled = true;
+    escrow.bump = ctx.bumps.escrow;
+
+    // Transfer payment to escrow. SOL gigs keep lamports on the escrow PDA so
+    // buyers never need a wrapped SOL account.
+    if escrow.is_native {
+        require!(ctx.accounts.escrow_vault.is_none(), SolanaGigsError::InvalidEscrowState);
+
+        escrow.vault = Pubkey::default();
+        escrow.vault_bump = 0;
+
+        let cpi_accounts = system_program::Transfer {
+            from: ctx.accounts.buyer.to_account_info(),
+            to: escrow.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.system_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        system_program::transfer(cpi_ctx, order.amount)?;
+    } else {
+        let buyer_token_account = ctx.accounts.buyer_token_account.as_ref()
+            .ok_or(SolanaGigsError::TokenAccountNotFound)?;
+        let escrow_vault = ctx.accounts.escrow_vault.as_mut()
+            .ok_or(SolanaGigsError::TokenAccountNotFound)?;
+
+        escrow.vault = escrow_vault.key();
+        escrow.vault_bump = ctx.bumps.escrow_vault;
+
+        transfer_checked(
+            &ctx.accounts.token_program,
+            buyer_token_account.to_account_info(),
+            &ctx.accounts.mint,
+            escrow_vault.to_account_info(),
+            ctx.accounts.buyer.to_account_info(),
+            transfer_accounts,
+            order.amount,
+            &[],
+        )?;
+
+        // Transfer-fee mints withhold part of the payment, so every later
+        // payout and fee is computed from what actually reached the vault
+        escrow_vault.reload()?;
+        escrow.amount = escrow_vault.amount;
+    }
+
+    let order_key = order.key();
+    for (index, (params, info)) in milestones.into_iter().zip(milestone_accounts).enumerate() {
+        let milestone_id = index as u8;
+        let (address, bump) = Pubkey::find_program_address(
+            &[b"milestone", order_key.as_ref(), &[milestone_id]],
+            ctx.program_id,
+        );
+        require_keys_eq!(info.key(), address, SolanaGigsError::InvalidAccountData);
+
+        create_pda_account(
+            &ctx.accounts.buyer.to_account_info(),
+            info,
+            &ctx.accounts.system_program.to_account_info(),
+            OrderMilestone::LEN,
+            &[b"milestone", order_key.as_ref(), &[milestone_id], &[bump]],
+        )?;
+
+        let milestone = OrderMilestone {
+            order: order_key,
+            milestone_id,
+            title: params.title,
+            description: params.description,
+            amount: params.amount,
+            status: MilestoneStatus::Pending,
+            due_date: params.due_date,
+            completed_at: None,
+            approved_at: None,
+            rejected_at: None,
+            rejection_reason: None,
+            deliverable_url: None,
+            bump,
+        };
+        store(info, &milestone)?;
+
+        emit!(MilestoneCreated {
+            order: order_key,
+            milestone: address,
+            milestone_id,
+            amount: milestone.amount,
+            due_date: milestone.due_date,
+        });
+    }
+
+    gig.total_orders = gig.total_orders.checked_add(1)
+        .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+    gig.active_orders = gig.active_orders.checked_add(1)
+        .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+    marketplace.total_orders = marketplace.total_orders.checked_add(1)
+        .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+    marketplace.total_volume = marketplace.total_volume.checked_add(order.amount)
+        .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+
+    emit!(OrderCreated {
+        order: order.key(),
+        buyer: order.buyer,
+        seller: order.seller,
+        gig: order.gig,
+        package_index: order.package_index,
+        extras: order.extras.clone(),
+        mint: escrow.mint,
+        amount: order.amount,
+        escrowed_amount: escrow.amount,
+        delivery_deadline: order.delivery_deadline,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct OrderCreated {
+    pub order: Pubkey,
+    pub buyer: Pubkey,
+    pub seller: Pubkey,
+    pub gig: Pubkey,
+    pub package_index: u8,
+    pub extras: Vec<u8>,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub escrowed_amount: u64,
+    pub delivery_deadline: i64,
+}
+
+#[event]
+pub struct MilestoneCreated {
+    pub order: Pubkey,
+    pub milestone: Pubkey,
+    pub milestone_id: u8,
+    pub amount: u64,
+    pub due_date: i64,
+}
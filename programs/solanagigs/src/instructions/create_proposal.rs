@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    #[account(
+        mut,
+        seeds = [b"governance"],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = Proposal::LEN,
+        seeds = [b"proposal", governance.proposal_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Queues an instruction of this program to be signed by the governance
+/// signer PDA. The proposer's own approval is counted straight away.
+pub fn create_proposal(
+    ctx: Context<CreateProposal>,
+    accounts: Vec<ProposalAccount>,
+    data: Vec<u8>,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let governance = &mut ctx.accounts.governance;
+    let proposer = ctx.accounts.proposer.key();
+    governance.require_signer(&proposer)?;
+
+    require!(
+        accounts.len() <= Proposal::MAX_ACCOUNTS && data.len() <= Proposal::MAX_DATA_LEN,
+        SolanaGigsError::ProposalTooLarge
+    );
+
+    // The governance signer is the only signature execution can supply
+    let (governance_signer, _) = Pubkey::find_program_address(&[b"governance_signer"], &crate::ID);
+    require!(
+        accounts.iter().all(|account| !account.is_signer || account.pubkey == governance_signer),
+        SolanaGigsError::InvalidAccountData
+    );
+
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.id = governance.proposal_count;
+    proposal.proposer = proposer;
+    proposal.accounts = accounts;
+    proposal.data = data;
+    proposal.approvals = Vec::new();
+    proposal.status = ProposalStatus::Pending;
+    proposal.config_version = governance.config_version;
+    proposal.created_at = current_time;
+    proposal.approved_at = None;
+    proposal.executable_at = None;
+    proposal.executed_at = None;
+    proposal.bump = ctx.bumps.proposal;
+    proposal.approve(governance, proposer, current_time)?;
+
+    governance.proposal_count = governance.proposal_count.checked_add(1)
+        .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+
+    emit!(ProposalCreated {
+        proposal: proposal.key(),
+        id: proposal.id,
+        proposer,
+        data: proposal.data.clone(),
+        executable_at: proposal.executable_at,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ProposalCreated {
+    pub proposal: Pubkey,
+    pub id: u64,
+    pub proposer: Pubkey,
+    pub data: Vec<u8>,
+    pub executable_at: Option<i64>,
+}
@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct CreateReview<'info> {
+    #[account(
+        mut,
+        seeds = [b"gig", gig.id.to_le_bytes().as_ref()],
+        bump = gig.bump
+    )]
+    pub gig: Box<Account<'info, Gig>>,
+
+    #[account(
+        mut,
+        seeds = [b"order", order.id.to_le_bytes().as_ref()],
+        bump = order.bump,
+        has_one = gig,
+        constraint = order.status == OrderStatus::Completed @ SolanaGigsError::InvalidOrderStatus,
+        constraint = order.is_participant(&reviewer.key()) @ SolanaGigsError::Unauthorized
+    )]
+    pub order: Box<Account<'info, Order>>,
+
+    #[account(
+        init,
+        payer = reviewer,
+        space = Review::SPACE,
+        seeds = [b"review", order.key().as_ref(), reviewer.key().as_ref()],
+        bump
+    )]
+    pub review: Box<Account<'info, Review>>,
+
+    #[account(
+        mut,
+        seeds = [b"user", reviewee_profile.authority.as_ref()],
+        bump = reviewee_profile.bump,
+        constraint = order.is_participant(&reviewee_profile.authority) @ SolanaGigsError::ProfileNotFound,
+        constraint = reviewee_profile.authority != reviewer.key() @ SolanaGigsError::CannotRateOwnService
+    )]
+    pub reviewee_profile: Option<Box<Account<'info, UserProfile>>>,
+
+    #[account(mut)]
+    pub reviewer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_review(
+    ctx: Context<CreateReview>,
+    rating: u8,
+    comment: String,
+    is_public: bool,
+) -> Result<()> {
+    require!((1..=5).contains(&rating), SolanaGigsError::InvalidRatingValue);
+    require!(comment.len() <= Review::MAX_COMMENT_LENGTH, SolanaGigsError::CommentTooLong);
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let order = &mut ctx.accounts.order;
+    let review = &mut ctx.accounts.review;
+    let reviewer = ctx.accounts.reviewer.key();
+
+    let reviewee = if reviewer == order.buyer {
+        // Buyer reviews feed the gig rating as well as the seller's reputation
+        ctx.accounts.gig.record_rating(rating)?;
+        order.buyer_rating = Some(rating);
+        order.seller
+    } else {
+        order.seller_rating = Some(rating);
+        order.buyer
+    };
+
+    review.order = order.key();
+    review.gig = order.gig;
+    review.reviewer = reviewer;
+    review.reviewee = reviewee;
+    review.rating = rating;
+    review.comment = comment;
+    review.is_public = is_public;
+    review.created_at = current_time;
+    review.bump = ctx.bumps.review;
+
+    // Update reviewee reputation when they have a profile
+    if let Some(reviewee_profile) = ctx.accounts.reviewee_profile.as_mut() {
+        reviewee_profile.update_reputation(rating as u32 * 100)?;
+    }
+
+    emit!(ReviewCreated {
+        review: review.key(),
+        order: review.order,
+        reviewer,
+        reviewee,
+        rating,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ReviewCreated {
+    pub review: Pubkey,
+    pub order: Pubkey,
+    pub reviewer: Pubkey,
+    pub reviewee: Pubkey,
+    pub rating: u8,
+    pub timestamp: i64,
+}
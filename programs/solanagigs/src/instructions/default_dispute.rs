@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct DefaultDispute<'info> {
+    #[account(
+        seeds = [b"order", order.id.to_le_bytes().as_ref()],
+        bump = order.bump
+    )]
+    pub order: Box<Account<'info, Order>>,
+
+    #[account(
+        mut,
+        seeds = [b"dispute", order.key().as_ref()],
+        bump = dispute.bump,
+        has_one = order
+    )]
+    pub dispute: Box<Account<'info, Dispute>>,
+
+    #[account(
+        mut,
+        seeds = [b"arbiter", arbiter.index.to_le_bytes().as_ref()],
+        bump = arbiter.bump,
+        constraint = dispute.arbiter == Some(arbiter.key()) @ SolanaGigsError::ArbiterNotAssigned
+    )]
+    pub arbiter: Option<Box<Account<'info, Arbiter>>>,
+
+    #[account(
+        mut,
+        seeds = [b"appeal", dispute.key().as_ref()],
+        bump = appeal.bump,
+        has_one = dispute
+    )]
+    pub appeal: Option<Box<Account<'info, DisputeAppeal>>>,
+
+    /// CHECK: Gets the bond and rent of a lapsed appeal back
+    #[account(mut)]
+    pub appellant: Option<UncheckedAccount<'info>>,
+}
+
+/// Permissionless once the response deadline passes without the respondent
+/// answering or filing evidence. The dispute resolves fully in favour of the
+/// initiator and can be settled with `finalize_dispute` straight away.
+///
+/// An appeal the appeals tier leaves undecided past its deadline lapses the
+/// same way: the appellant gets the bond back and the appealed resolution
+/// can be settled straight away.
+pub fn default_dispute(ctx: Context<DefaultDispute>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let dispute = &mut ctx.accounts.dispute;
+
+    if dispute.status == DisputeStatus::Appealed {
+        require!(
+            current_time > dispute.arbiter_deadline,
+            SolanaGigsError::ArbiterDeadlineNotReached
+        );
+        let appeal = ctx.accounts.appeal.as_ref()
+            .ok_or(SolanaGigsError::AppealNotFound)?;
+        let appellant = ctx.accounts.appellant.as_ref()
+            .ok_or(SolanaGigsError::Unauthorized)?;
+        require_keys_eq!(appellant.key(), appeal.appellant, SolanaGigsError::Unauthorized);
+
+        let bond = appeal.bond;
+        appeal.close(appellant.to_account_info())?;
+
+        dispute.status = DisputeStatus::PendingSettlement;
+        dispute.appeal_deadline = current_time;
+        dispute.updated_at = current_time;
+
+        emit!(AppealLapsed {
+            order: ctx.accounts.order.key(),
+            dispute: dispute.key(),
+            appellant: appellant.key(),
+            bond,
+        });
+
+        return Ok(());
+    }
+
+    require!(dispute.is_active(), SolanaGigsError::NoDisputeFound);
+    require!(
+        dispute.resolution_mode == ResolutionMode::Arbiter,
+        SolanaGigsError::InvalidResolutionMode
+    );
+    require!(
+        current_time > dispute.response_deadline,
+        SolanaGigsError::ResponseDeadlineNotReached
+    );
+    require!(!dispute.respondent_engaged(), SolanaGigsError::RespondentEngaged);
+
+    // Release the assigned arbiter from the case
+    if dispute.arbiter.is_some() {
+        let arbiter = ctx.accounts.arbiter.as_mut()
+            .ok_or(SolanaGigsError::ArbiterNotAssigned)?;
+        arbiter.active_cases = arbiter.active_cases.saturating_sub(1);
+    }
+
+    let (resolution, refund_bps) = match dispute.initiator_type {
+        DisputeInitiator::Buyer => (DisputeResolution::RefundBuyer, 10_000),
+        DisputeInitiator::Seller => (DisputeResolution::ReleaseToSeller, 0),
+    };
+
+    dispute.status = DisputeStatus::PendingSettlement;
+    dispute.resolution = Some(resolution);
+    dispute.refund_bps = refund_bps;
+    dispute.resolution_reason = None;
+    dispute.resolved_by = None;
+    dispute.appeal_deadline = current_time;
+    dispute.updated_at = current_time;
+
+    emit!(DisputeDefaulted {
+        order: ctx.accounts.order.key(),
+        dispute: dispute.key(),
+        winner: dispute.initiator,
+        respondent: dispute.respondent,
+        resolution,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct AppealLapsed {
+    pub order: Pubkey,
+    pub dispute: Pubkey,
+    pub appellant: Pubkey,
+    pub bond: u64,
+}
+
+#[event]
+pub struct DisputeDefaulted {
+    pub order: Pubkey,
+    pub dispute: Pubkey,
+    pub winner: Pubkey,
+    pub respondent: Pubkey,
+    pub resolution: DisputeResolution,
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct DeleteGig<'info> {
+    #[account(
+        mut,
+        seeds = [b"gig", gig.id.to_le_bytes().as_ref()],
+        bump = gig.bump,
+        has_one = seller,
+        close = seller
+    )]
+    pub gig: Box<Account<'info, Gig>>,
+
+    #[account(mut)]
+    pub seller: Signer<'info>,
+}
+
+pub fn delete_gig(ctx: Context<DeleteGig>) -> Result<()> {
+    let gig = &ctx.accounts.gig;
+
+    require!(gig.active_orders == 0, SolanaGigsError::CannotDeleteGigWithActiveOrders);
+
+    emit!(GigDeleted {
+        gig: gig.key(),
+        seller: gig.seller,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct GigDeleted {
+    pub gig: Pubkey,
+    pub seller: Pubkey,
+}
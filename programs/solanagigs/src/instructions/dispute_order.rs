@@ -1,6 +1,4 @@
-```rust
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 
 use crate::state::*;
 use crate::errors::*;
@@ -9,60 +7,39 @@ use crate::errors::*;
 pub struct DisputeOrder<'info> {
     #[account(
         mut,
-        seeds = [b"order", order.client.as_ref(), order.freelancer.as_ref(), &order.order_id.to_le_bytes()],
-        bump = order.bump,
-        constraint = order.status == OrderStatus::InProgress @ SolanaGigsError::InvalidOrderStatus,
-        constraint = order.client == client.key() || order.freelancer == freelancer.key() @ SolanaGigsError::UnauthorizedDispute
+        seeds = [b"marketplace"],
+        bump = marketplace.bump
     )]
-    pub order: Account<'info, Order>,
+    pub marketplace: Box<Account<'info, Marketplace>>,
 
     #[account(
-        init,
-        payer = dispute_initiator,
-        space = 8 + Dispute::INIT_SPACE,
-        seeds = [b"dispute", order.key().as_ref()],
-        bump
+        mut,
+        seeds = [b"order", order.id.to_le_bytes().as_ref()],
+        bump = order.bump,
+        constraint = order.is_participant(&dispute_initiator.key()) @ SolanaGigsError::Unauthorized
     )]
-    pub dispute: Account<'info, Dispute>,
+    pub order: Box<Account<'info, Order>>,
 
     #[account(
-        mut,
         seeds = [b"escrow", order.key().as_ref()],
-        bump = order.escrow_bump,
-        token::mint = order.payment_mint,
-        token::authority = escrow_authority
+        bump = escrow.bump,
+        has_one = order
     )]
-    pub escrow_account: Account<'info, TokenAccount>,
+    pub escrow: Box<Account<'info, Escrow>>,
 
-    /// CHECK: This is the escrow authority PDA
     #[account(
-        seeds = [b"escrow_authority"],
+        init,
+        payer = dispute_initiator,
+        space = Dispute::LEN,
+        seeds = [b"dispute", order.key().as_ref()],
         bump
     )]
-    pub escrow_authority: UncheckedAccount<'info>,
+    pub dispute: Box<Account<'info, Dispute>>,
 
     #[account(mut)]
-    pub client: Signer<'info>,
-
-    #[account(mut)]
-    pub freelancer: Signer<'info>,
-
-    #[account(
-        mut,
-        constraint = dispute_initiator.key() == client.key() || dispute_initiator.key() == freelancer.key() @ SolanaGigsError::UnauthorizedDispute
-    )]
     pub dispute_initiator: Signer<'info>,
 
-    #[account(
-        mut,
-        seeds = [b"platform_config"],
-        bump = platform_config.bump
-    )]
-    pub platform_config: Account<'info, PlatformConfig>,
-
-    pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
-    pub rent: Sysvar<'info, Rent>,
 }
 
 pub fn dispute_order(
@@ -71,98 +48,105 @@ pub fn dispute_order(
     evidence_hash: Option<String>,
     requested_resolution: DisputeResolution,
 ) -> Result<()> {
-    let order = &mut ctx.accounts.order;
-    let dispute = &mut ctx.accounts.dispute;
-    let platform_config = &ctx.accounts.platform_config;
-    let dispute_initiator = &ctx.accounts.dispute_initiator;
-    let client = &ctx.accounts.client;
-    let freelancer = &ctx.accounts.freelancer;
-
     // Validate dispute reason length
     require!(
-        reason.len() >= 10 && reason.len() <= 500,
+        reason.len() >= Dispute::MIN_REASON_LEN && reason.len() <= Dispute::MAX_REASON_LEN,
         SolanaGigsError::InvalidDisputeReason
     );
 
     // Validate evidence hash if provided
     if let Some(ref hash) = evidence_hash {
         require!(
-            hash.len() == 64, // SHA-256 hash length
+            hash.len() == Dispute::EVIDENCE_HASH_LEN && hash.chars().all(|c| c.is_ascii_hexdigit()),
             SolanaGigsError::InvalidEvidenceHash
         );
     }
 
+    let order = &mut ctx.accounts.order;
+    let dispute = &mut ctx.accounts.dispute;
+    let marketplace = &mut ctx.accounts.marketplace;
+    let dispute_initiator = &ctx.accounts.dispute_initiator;
+
+    require!(order.can_raise_dispute(), SolanaGigsError::InvalidOrderStatus);
+
     // Check if dispute period is still valid
     let current_time = Clock::get()?.unix_timestamp;
-    let dispute_deadline = order.delivery_date + platform_config.dispute_period;
     require!(
-        current_time <= dispute_deadline,
+        current_time <= ctx.accounts.escrow.dispute_deadline,
         SolanaGigsError::DisputePeriodExpired
     );
 
     // Determine dispute initiator type
-    let initiator_type = if dispute_initiator.key() == client.key() {
-        DisputeInitiator::Client
+    let (initiator_type, respondent) = if dispute_initiator.key() == order.buyer {
+        (DisputeInitiator::Buyer, order.seller)
     } else {
-        DisputeInitiator::Freelancer
+        (DisputeInitiator::Seller, order.buyer)
     };
 
-    // Initialize dispute
     dispute.order = order.key();
     dispute.initiator = dispute_initiator.key();
     dispute.initiator_type = initiator_type;
-    dispute.reason = reason;
+    dispute.respondent = respondent;
+    dispute.reason = reason.clone();
     dispute.evidence_hash = evidence_hash;
+    dispute.evidence = Vec::new();
+    dispute.evidence_deadline = current_time.checked_add(Dispute::EVIDENCE_PERIOD)
+        .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+    dispute.response_deadline = current_time.checked_add(marketplace.response_period)
+        .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+    dispute.arbiter_deadline = 0;
     dispute.requested_resolution = requested_resolution;
     dispute.status = DisputeStatus::Open;
-    dispute.created_at = current_time;
-    dispute.updated_at = current_time;
-    dispute.arbitrator = None;
+    dispute.resolution_mode = ResolutionMode::Arbiter;
+    dispute.juror_pool = marketplace.juror_count;
+    dispute.arbiter = None;
     dispute.resolution = None;
+    dispute.refund_bps = 0;
     dispute.resolution_reason = None;
-    dispute.client_response = None;
-    dispute.freelancer_response = None;
+    dispute.buyer_response = None;
+    dispute.seller_response = None;
+    dispute.buyer_refund_amount = 0;
+    dispute.seller_payout_amount = 0;
+    dispute.platform_fee = 0;
+    dispute.resolved_by = None;
+    dispute.appeal_deadline = 0;
+    dispute.appealed = false;
+    dispute.created_at = current_time;
+    dispute.updated_at = current_time;
+    dispute.resolved_at = None;
     dispute.bump = ctx.bumps.dispute;
 
-    // Update order status
+    // Escrow stays locked while the order is disputed
     order.status = OrderStatus::Disputed;
-    order.updated_at = current_time;
+    order.has_pending_extension = false;
+    order.dispute_reason = Some(reason);
+    order.disputed_at = Some(current_time);
 
-    // Lock escrow funds during dispute
-    order.escrow_locked = true;
+    marketplace.total_disputes = marketplace.total_disputes.checked_add(1)
+        .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+    marketplace.active_disputes = marketplace.active_disputes.checked_add(1)
+        .ok_or(SolanaGigsError::ArithmeticOverflow)?;
 
-    // Emit dispute created event
-    emit!(DisputeCreatedEvent {
-        dispute: dispute.key(),
+    emit!(OrderDisputed {
         order: order.key(),
-        initiator: dispute_initiator.key(),
+        dispute: dispute.key(),
+        buyer: order.buyer,
+        seller: order.seller,
+        initiator: dispute.initiator,
         initiator_type,
-        reason: dispute.reason.clone(),
         requested_resolution,
-        timestamp: current_time,
     });
 
-    // Update platform statistics
-    platform_config.total_disputes += 1;
-    platform_config.active_disputes += 1;
-
-    msg!(
-        "Dispute created for order {} by {}",
-        order.key(),
-        dispute_initiator.key()
-    );
-
     Ok(())
 }
 
 #[event]
-pub struct DisputeCreatedEvent {
-    pub dispute: Pubkey,
+pub struct OrderDisputed {
     pub order: Pubkey,
+    pub dispute: Pubkey,
+    pub buyer: Pubkey,
+    pub seller: Pubkey,
     pub initiator: Pubkey,
     pub initiator_type: DisputeInitiator,
-    pub reason: String,
     pub requested_resolution: DisputeResolution,
-    pub timestamp: i64,
 }
-```
\ No newline at end of file
@@ -0,0 +1,148 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::slot_hashes;
+
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct DrawJury<'info> {
+    #[account(
+        seeds = [b"marketplace"],
+        bump = marketplace.bump
+    )]
+    pub marketplace: Box<Account<'info, Marketplace>>,
+
+    #[account(
+        mut,
+        seeds = [b"order", order.id.to_le_bytes().as_ref()],
+        bump = order.bump
+    )]
+    pub order: Box<Account<'info, Order>>,
+
+    #[account(
+        mut,
+        seeds = [b"dispute", order.key().as_ref()],
+        bump = dispute.bump,
+        has_one = order
+    )]
+    pub dispute: Box<Account<'info, Dispute>>,
+
+    #[account(
+        init,
+        payer = caller,
+        space = JuryCase::LEN,
+        seeds = [b"jury", dispute.key().as_ref()],
+        bump
+    )]
+    pub jury_case: Box<Account<'info, JuryCase>>,
+
+    #[account(
+        mut,
+        constraint = order.is_participant(&caller.key()) ||
+            caller.key() == marketplace.dispute_admin @ SolanaGigsError::Unauthorized
+    )]
+    pub caller: Signer<'info>,
+
+    /// CHECK: Read by hand, the sysvar is too large to deserialize on-chain
+    #[account(address = slot_hashes::ID)]
+    pub slot_hashes: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Moves an unassigned dispute to a staked jury. The registry is walked
+/// from a start index derived from the dispute address and the most recent
+/// slot hash; every juror stake on that walk is passed in
+/// `remaining_accounts`, and the first eligible ones fill the panel. Only
+/// jurors registered before the dispute was opened are part of the walk.
+pub fn draw_jury<'info>(ctx: Context<'_, '_, 'info, 'info, DrawJury<'info>>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let slot_hash = most_recent_slot_hash(&ctx.accounts.slot_hashes)?;
+    let order = &mut ctx.accounts.order;
+    let dispute = &mut ctx.accounts.dispute;
+    let jury_case = &mut ctx.accounts.jury_case;
+
+    require!(dispute.is_active(), SolanaGigsError::NoDisputeFound);
+    require!(
+        dispute.resolution_mode == ResolutionMode::Arbiter && dispute.arbiter.is_none(),
+        SolanaGigsError::ArbiterAlreadyAssigned
+    );
+    let juror_pool = dispute.juror_pool;
+    require!(
+        juror_pool as usize >= JuryCase::PANEL_SIZE,
+        SolanaGigsError::NotEnoughJurors
+    );
+    require!(
+        ctx.remaining_accounts.len() <= juror_pool as usize,
+        SolanaGigsError::NotEnoughJurors
+    );
+
+    let mut expected_index = JuryCase::draw_start(&dispute.key(), &slot_hash, juror_pool);
+    let mut jurors = Vec::with_capacity(JuryCase::PANEL_SIZE);
+    for account_info in ctx.remaining_accounts.iter() {
+        let mut juror_stake = Account::<JurorStake>::try_from(account_info)?;
+        require!(juror_stake.index == expected_index, SolanaGigsError::JurorNotOnPanel);
+        expected_index = (expected_index + 1) % juror_pool;
+
+        if jurors.len() < JuryCase::PANEL_SIZE && juror_stake.can_serve(&order.buyer, &order.seller) {
+            juror_stake.active_cases = juror_stake.active_cases.checked_add(1)
+                .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+            juror_stake.exit(&crate::ID)?;
+
+            jurors.push(JurorVote {
+                juror: juror_stake.juror,
+                commitment: None,
+                refund_bps: None,
+                reward_claimed: false,
+            });
+        }
+    }
+    require!(jurors.len() == JuryCase::PANEL_SIZE, SolanaGigsError::NotEnoughJurors);
+
+    jury_case.dispute = dispute.key();
+    jury_case.order = order.key();
+    jury_case.jurors = jurors;
+    jury_case.status = JuryStatus::Voting;
+    jury_case.commit_deadline = current_time + JuryCase::COMMIT_PERIOD;
+    jury_case.reveal_deadline = jury_case.commit_deadline + JuryCase::REVEAL_PERIOD;
+    jury_case.median_refund_bps = 0;
+    jury_case.juror_fee = 0;
+    jury_case.slashed_pool = 0;
+    jury_case.majority_count = 0;
+    jury_case.claims_made = 0;
+    jury_case.claim_deadline = 0;
+    jury_case.created_at = current_time;
+    jury_case.bump = ctx.bumps.jury_case;
+
+    dispute.resolution_mode = ResolutionMode::Jury;
+    dispute.status = DisputeStatus::UnderReview;
+    dispute.updated_at = current_time;
+
+    emit!(JuryDrawn {
+        order: order.key(),
+        dispute: dispute.key(),
+        jurors: jury_case.jurors.iter().map(|vote| vote.juror).collect(),
+        commit_deadline: jury_case.commit_deadline,
+        reveal_deadline: jury_case.reveal_deadline,
+    });
+
+    Ok(())
+}
+
+/// The sysvar is a length-prefixed list of (slot, hash) pairs, newest first.
+fn most_recent_slot_hash(slot_hashes: &AccountInfo) -> Result<[u8; 32]> {
+    let data = slot_hashes.try_borrow_data()?;
+    require!(data.len() >= 48, SolanaGigsError::SlotHashUnavailable);
+    let entries = u64::from_le_bytes(data[..8].try_into().unwrap());
+    require!(entries > 0, SolanaGigsError::SlotHashUnavailable);
+    Ok(data[16..48].try_into().unwrap())
+}
+
+#[event]
+pub struct JuryDrawn {
+    pub order: Pubkey,
+    pub dispute: Pubkey,
+    pub jurors: Vec<Pubkey>,
+    pub commit_deadline: i64,
+    pub reveal_deadline: i64,
+}
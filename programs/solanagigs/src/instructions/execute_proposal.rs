@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    #[account(
+        seeds = [b"governance"],
+        bump = governance.bump
+    )]
+    pub governance: Box<Account<'info, Governance>>,
+
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+
+    #[account(
+        mut,
+        seeds = [b"governance_signer"],
+        bump = governance.signer_bump
+    )]
+    pub governance_signer: SystemAccount<'info>,
+
+    pub executor: Signer<'info>,
+}
+
+/// Permissionless once a proposal is approved and its timelock has passed.
+/// Remaining accounts must cover every account the proposal names, plus this
+/// program; they are passed through to the proposed instruction.
+pub fn execute_proposal<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteProposal<'info>>,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let governance = &ctx.accounts.governance;
+    let proposal = &mut ctx.accounts.proposal;
+
+    require!(
+        proposal.config_version == governance.config_version,
+        SolanaGigsError::StaleProposal
+    );
+    require!(
+        proposal.status == ProposalStatus::Approved,
+        SolanaGigsError::InvalidProposalStatus
+    );
+    require!(proposal.can_execute(current_time), SolanaGigsError::ProposalNotReady);
+
+    // Persist the status before the call so the proposal cannot re-enter itself
+    proposal.status = ProposalStatus::Executed;
+    proposal.executed_at = Some(current_time);
+    proposal.exit(&crate::ID)?;
+
+    let instruction = Instruction {
+        program_id: crate::ID,
+        accounts: proposal
+            .accounts
+            .iter()
+            .map(|account| AccountMeta {
+                pubkey: account.pubkey,
+                is_signer: account.is_signer,
+                is_writable: account.is_writable,
+            })
+            .collect(),
+        data: proposal.data.clone(),
+    };
+
+    let mut account_infos = ctx.remaining_accounts.to_vec();
+    account_infos.push(ctx.accounts.governance_signer.to_account_info());
+
+    let seeds = &[b"governance_signer".as_ref(), &[governance.signer_bump]];
+    invoke_signed(&instruction, &account_infos, &[&seeds[..]])?;
+
+    emit!(ProposalExecuted {
+        proposal: proposal.key(),
+        executor: ctx.accounts.executor.key(),
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ProposalExecuted {
+    pub proposal: Pubkey,
+    pub executor: Pubkey,
+}
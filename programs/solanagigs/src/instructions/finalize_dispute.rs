@@ -0,0 +1,351 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::state::*;
+use crate::errors::*;
+use crate::utils::{charge_fee, payout_account, treasury_account, EscrowPayer};
+
+#[derive(Accounts)]
+pub struct FinalizeDispute<'info> {
+    #[account(
+        mut,
+        seeds = [b"marketplace"],
+        bump = marketplace.bump
+    )]
+    pub marketplace: Box<Account<'info, Marketplace>>,
+
+    #[account(
+        seeds = [b"fee_schedule"],
+        bump = fee_schedule.bump
+    )]
+    pub fee_schedule: Box<Account<'info, FeeSchedule>>,
+
+    #[account(
+        mut,
+        seeds = [b"gig", gig.id.to_le_bytes().as_ref()],
+        bump = gig.bump
+    )]
+    pub gig: Box<Account<'info, Gig>>,
+
+    #[account(
+        mut,
+        seeds = [b"order", order.id.to_le_bytes().as_ref()],
+        bump = order.bump,
+        has_one = gig
+    )]
+    pub order: Box<Account<'info, Order>>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", order.key().as_ref()],
+        bump = escrow.bump,
+        has_one = order
+    )]
+    pub escrow: Box<Account<'info, Escrow>>,
+
+    #[account(
+        mut,
+        seeds = [b"dispute", order.key().as_ref()],
+        bump = dispute.bump,
+        has_one = order
+    )]
+    pub dispute: Box<Account<'info, Dispute>>,
+
+    /// Required when a jury heard the dispute
+    #[account(
+        mut,
+        seeds = [b"jury", dispute.key().as_ref()],
+        bump = jury_case.bump,
+        has_one = dispute
+    )]
+    pub jury_case: Option<Box<Account<'info, JuryCase>>>,
+
+    #[account(
+        mut,
+        constraint = vault.key() == escrow.vault @ SolanaGigsError::InvalidEscrowState,
+        token::mint = escrow.mint
+    )]
+    pub vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        address = escrow.mint @ SolanaGigsError::InvalidTokenMint
+    )]
+    pub mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        mut,
+        seeds = [b"user", order.seller.as_ref()],
+        bump = seller_profile.bump
+    )]
+    pub seller_profile: Box<Account<'info, UserProfile>>,
+
+    #[account(
+        mut,
+        seeds = [b"volume", order.seller.as_ref(), escrow.mint.as_ref()],
+        bump = seller_volume.bump
+    )]
+    pub seller_volume: Box<Account<'info, SellerVolume>>,
+
+    #[account(
+        mut,
+        token::mint = escrow.mint,
+        token::authority = order.buyer
+    )]
+    pub buyer_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        token::mint = escrow.mint,
+        token::authority = order.seller
+    )]
+    pub seller_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// CHECK: The seller's earnings PDA, which is credited instead of the seller once initialized
+    #[account(
+        mut,
+        seeds = [b"earnings", order.seller.as_ref(), escrow.mint.as_ref()],
+        bump
+    )]
+    pub seller_earnings: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub earnings_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// CHECK: The buyer's referral PDA, which pays a referrer once initialized
+    #[account(
+        mut,
+        seeds = [b"referral", order.buyer.as_ref()],
+        bump
+    )]
+    pub buyer_referral: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        token::mint = escrow.mint
+    )]
+    pub buyer_referrer_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(mut)]
+    pub buyer_referrer: Option<SystemAccount<'info>>,
+
+    /// CHECK: The seller's referral PDA, which pays a referrer once initialized
+    #[account(
+        mut,
+        seeds = [b"referral", order.seller.as_ref()],
+        bump
+    )]
+    pub seller_referral: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        token::mint = escrow.mint
+    )]
+    pub seller_referrer_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(mut)]
+    pub seller_referrer: Option<SystemAccount<'info>>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury", escrow.mint.as_ref()],
+        bump = treasury.bump
+    )]
+    pub treasury: Box<Account<'info, Treasury>>,
+
+    #[account(
+        mut,
+        address = treasury.vault @ SolanaGigsError::InvalidAccountData
+    )]
+    pub treasury_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        address = order.buyer @ SolanaGigsError::Unauthorized
+    )]
+    pub buyer_wallet: Option<SystemAccount<'info>>,
+
+    #[account(
+        mut,
+        address = order.seller @ SolanaGigsError::Unauthorized
+    )]
+    pub seller: Option<SystemAccount<'info>>,
+
+    pub caller: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Pays out a pending resolution once its appeal window has passed, or
+/// straight after an appeal decision. Anyone may crank it. When a jury heard
+/// the dispute, the fee on the seller share stays in the vault for the
+/// majority jurors instead of going to the marketplace.
+pub fn finalize_dispute<'info>(ctx: Context<'_, '_, '_, 'info, FinalizeDispute<'info>>) -> Result<()> {
+    ctx.accounts.marketplace.require_funds_unfrozen()?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let order = &mut ctx.accounts.order;
+    let escrow = &mut ctx.accounts.escrow;
+    let dispute = &mut ctx.accounts.dispute;
+
+    require!(order.status == OrderStatus::Disputed, SolanaGigsError::InvalidOrderStatus);
+    require!(dispute.can_settle(current_time), SolanaGigsError::AppealWindowOpen);
+    require!(!escrow.is_settled(), SolanaGigsError::EscrowAlreadyReleased);
+
+    let resolution = dispute.resolution.ok_or(SolanaGigsError::InvalidDisputeResolution)?;
+    let jury_case = if dispute.resolution_mode == ResolutionMode::Jury {
+        let jury_case = ctx.accounts.jury_case.as_deref_mut()
+            .ok_or(SolanaGigsError::InvalidResolutionMode)?;
+        require!(jury_case.status == JuryStatus::Settled, SolanaGigsError::InvalidResolutionMode);
+        Some(jury_case)
+    } else {
+        None
+    };
+    let refund_bps = dispute.refund_bps;
+    let amount = escrow.remaining_amount();
+    let (refund_amount, seller_share) = Escrow::split_refund(amount, refund_bps);
+    let marketplace_fee = charge_fee(
+        &ctx.accounts.fee_schedule,
+        order.key(),
+        ctx.accounts.gig.category,
+        &ctx.accounts.seller_profile,
+        &ctx.accounts.seller_volume,
+        seller_share,
+    )?;
+    let seller_amount = seller_share
+        .checked_sub(marketplace_fee)
+        .ok_or(SolanaGigsError::ArithmeticUnderflow)?;
+    let (marketplace_fee, juror_fee) = match jury_case {
+        Some(_) => (0, marketplace_fee),
+        None => (marketplace_fee, 0),
+    };
+
+    let payer = EscrowPayer {
+        escrow,
+        vault: &ctx.accounts.vault,
+        mint: &ctx.accounts.mint,
+        token_program: &ctx.accounts.token_program,
+        remaining_accounts: ctx.remaining_accounts,
+    };
+
+    // Refund buyer if applicable
+    if refund_amount > 0 {
+        let buyer_destination = payout_account(
+            escrow.is_native,
+            &ctx.accounts.buyer_token_account,
+            &ctx.accounts.buyer_wallet,
+        )?;
+        payer.transfer(buyer_destination, refund_amount)?;
+    }
+
+    // Pay seller if applicable
+    if seller_amount > 0 {
+        payer.pay_seller(
+            &ctx.accounts.seller_earnings,
+            &ctx.accounts.earnings_vault,
+            &ctx.accounts.seller_token_account,
+            &ctx.accounts.seller,
+            seller_amount,
+        )?;
+    }
+
+    // Referrers are paid out of the marketplace fee, never on top of it
+    let referral_reward = ctx.accounts.marketplace.calculate_referral_reward(marketplace_fee);
+    let buyer_referral = payer.pay_referrer(
+        &ctx.accounts.buyer_referral,
+        &ctx.accounts.buyer_referrer_token_account,
+        &ctx.accounts.buyer_referrer,
+        referral_reward,
+    )?;
+    let seller_referral = payer.pay_referrer(
+        &ctx.accounts.seller_referral,
+        &ctx.accounts.seller_referrer_token_account,
+        &ctx.accounts.seller_referrer,
+        referral_reward,
+    )?;
+    let referral_total = buyer_referral.map_or(0, |split| split.amount)
+        + seller_referral.map_or(0, |split| split.amount);
+
+    // Transfer the rest of the fee to marketplace
+    let marketplace_share = marketplace_fee
+        .checked_sub(referral_total)
+        .ok_or(SolanaGigsError::ArithmeticUnderflow)?;
+    if marketplace_share > 0 {
+        let fee_destination = treasury_account(&ctx.accounts.treasury, &ctx.accounts.treasury_vault)?;
+        payer.transfer(fee_destination, marketplace_share)?;
+    }
+
+    // The vault closes now unless it still holds a juror fee to claim
+    if juror_fee == 0 {
+        payer.close_vault(
+            &ctx.accounts.treasury_vault,
+            ctx.accounts.buyer_wallet.as_ref().map(|buyer| buyer.to_account_info()),
+        )?;
+    }
+    if let Some(jury_case) = jury_case {
+        jury_case.juror_fee = juror_fee;
+        jury_case.claim_deadline = current_time.checked_add(JuryCase::CLAIM_PERIOD)
+            .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+    }
+
+    order.status = OrderStatus::Resolved;
+    order.resolution = Some(resolution);
+    order.resolved_at = Some(current_time);
+
+    dispute.status = DisputeStatus::Resolved;
+    dispute.buyer_refund_amount = refund_amount;
+    dispute.seller_payout_amount = seller_amount;
+    dispute.platform_fee = marketplace_fee;
+    dispute.updated_at = current_time;
+    dispute.resolved_at = Some(current_time);
+
+    escrow.platform_fee = escrow.platform_fee.checked_add(marketplace_fee)
+        .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+    escrow.partial_release_amount = escrow.amount - juror_fee;
+    if refund_amount > 0 {
+        escrow.refunded_at = Some(current_time);
+    }
+    if seller_share > 0 {
+        escrow.released_at = Some(current_time);
+    }
+
+    let marketplace = &mut ctx.accounts.marketplace;
+    marketplace.active_disputes = marketplace.active_disputes.saturating_sub(1);
+
+    let gig = &mut ctx.accounts.gig;
+    gig.active_orders = gig.active_orders.saturating_sub(1);
+
+    if seller_amount > 0 {
+        ctx.accounts.seller_profile.add_earnings(seller_amount)?;
+        ctx.accounts.seller_volume.add_volume(seller_amount)?;
+    }
+
+    emit!(DisputeResolved {
+        order: order.key(),
+        dispute: dispute.key(),
+        resolution,
+        refund_bps,
+        buyer_refund_amount: refund_amount,
+        seller_payout_amount: seller_amount,
+        marketplace_fee,
+        juror_fee,
+        buyer_referral,
+        seller_referral,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct DisputeResolved {
+    pub order: Pubkey,
+    pub dispute: Pubkey,
+    pub resolution: DisputeResolution,
+    pub refund_bps: u16,
+    pub buyer_refund_amount: u64,
+    pub seller_payout_amount: u64,
+    pub marketplace_fee: u64,
+    pub juror_fee: u64,
+    pub buyer_referral: Option<ReferralSplit>,
+    pub seller_referral: Option<ReferralSplit>,
+}
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct InitializeGovernance<'info> {
+    #[account(
+        mut,
+        seeds = [b"marketplace"],
+        bump = marketplace.bump,
+        has_one = authority
+    )]
+    pub marketplace: Account<'info, Marketplace>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Governance::LEN,
+        seeds = [b"governance"],
+        bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    /// Holds no data; it only signs (and pays) for executed proposals
+    #[account(
+        seeds = [b"governance_signer"],
+        bump
+    )]
+    pub governance_signer: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Hands the marketplace over to an M-of-N signer set. From here on every
+/// `has_one = authority` instruction and every delegated role needs an
+/// approved, timelocked proposal until governance assigns the roles again.
+pub fn initialize_governance(
+    ctx: Context<InitializeGovernance>,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+    min_delay: i64,
+) -> Result<()> {
+    Governance::validate_config(&signers, threshold, min_delay)?;
+
+    let governance = &mut ctx.accounts.governance;
+    governance.signers = signers;
+    governance.threshold = threshold;
+    governance.min_delay = min_delay;
+    governance.proposal_count = 0;
+    governance.config_version = 0;
+    governance.signer_bump = ctx.bumps.governance_signer;
+    governance.bump = ctx.bumps.governance;
+
+    let marketplace = &mut ctx.accounts.marketplace;
+    marketplace.set_authority(ctx.accounts.governance_signer.key());
+
+    emit!(GovernanceUpdated {
+        governance: governance.key(),
+        signers: governance.signers.clone(),
+        threshold,
+        min_delay,
+        config_version: governance.config_version,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct GovernanceUpdated {
+    pub governance: Pubkey,
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+    pub min_delay: i64,
+    pub config_version: u32,
+}
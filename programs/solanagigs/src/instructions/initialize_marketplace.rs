@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::spl_token::native_mint;
+
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct InitializeMarketplace<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = Marketplace::LEN,
+        seeds = [b"marketplace"],
+        bump
+    )]
+    pub marketplace: Account<'info, Marketplace>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = FeeSchedule::LEN,
+        seeds = [b"fee_schedule"],
+        bump
+    )]
+    pub fee_schedule: Account<'info, FeeSchedule>,
+
+    /// SOL treasury, which also collects forfeited bonds and slashed stakes
+    #[account(
+        init,
+        payer = authority,
+        space = Treasury::LEN,
+        seeds = [b"treasury", native_mint::ID.as_ref()],
+        bump
+    )]
+    pub treasury: Box<Account<'info, Treasury>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_marketplace(
+    ctx: Context<InitializeMarketplace>,
+    default_fee_bps: u16,
+    dispute_period: Option<i64>,
+) -> Result<()> {
+    require!(
+        default_fee_bps <= FeeSchedule::MAX_FEE_BPS,
+        SolanaGigsError::InvalidPlatformFee
+    );
+
+    let dispute_period = dispute_period.unwrap_or(Marketplace::DEFAULT_DISPUTE_PERIOD);
+    require!(dispute_period > 0, SolanaGigsError::InvalidTimestamp);
+
+    let marketplace = &mut ctx.accounts.marketplace;
+    marketplace.authority = ctx.accounts.authority.key();
+    marketplace.pending_authority = None;
+    marketplace.fee_manager = marketplace.authority;
+    marketplace.pauser = marketplace.authority;
+    marketplace.dispute_admin = marketplace.authority;
+    marketplace.category_curator = marketplace.authority;
+    marketplace.verifier = marketplace.authority;
+    marketplace.dispute_period = dispute_period;
+    marketplace.auto_release_bounty = 0;
+    marketplace.referral_share = 0;
+    marketplace.total_gigs = 0;
+    marketplace.total_orders = 0;
+    marketplace.total_volume = 0;
+    marketplace.total_disputes = 0;
+    marketplace.active_disputes = 0;
+    marketplace.arbiter_count = 0;
+    marketplace.next_arbiter_index = 0;
+    marketplace.juror_count = 0;
+    marketplace.appeal_period = Marketplace::DEFAULT_APPEAL_PERIOD;
+    marketplace.appeal_bond = Marketplace::DEFAULT_APPEAL_BOND;
+    marketplace.response_period = Marketplace::DEFAULT_RESPONSE_PERIOD;
+    marketplace.arbiter_period = Marketplace::DEFAULT_ARBITER_PERIOD;
+    marketplace.retention_period = Marketplace::DEFAULT_RETENTION_PERIOD;
+    marketplace.is_paused = false;
+    marketplace.is_emergency_stopped = false;
+    marketplace.bump = ctx.bumps.marketplace;
+
+    let fee_schedule = &mut ctx.accounts.fee_schedule;
+    fee_schedule.default_fee_bps = default_fee_bps;
+    fee_schedule.category_fees = Vec::new();
+    fee_schedule.level_fees = Vec::new();
+    fee_schedule.volume_tiers = Vec::new();
+    fee_schedule.updated_at = Clock::get()?.unix_timestamp;
+    fee_schedule.bump = ctx.bumps.fee_schedule;
+
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.mint = native_mint::ID;
+    treasury.vault = Pubkey::default();
+    treasury.is_native = true;
+    treasury.total_withdrawn = 0;
+    treasury.withdrawal_count = 0;
+    treasury.bump = ctx.bumps.treasury;
+
+    emit!(MarketplaceInitialized {
+        marketplace: marketplace.key(),
+        authority: marketplace.authority,
+        default_fee_bps,
+        dispute_period,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct MarketplaceInitialized {
+    pub marketplace: Pubkey,
+    pub authority: Pubkey,
+    pub default_fee_bps: u16,
+    pub dispute_period: i64,
+}
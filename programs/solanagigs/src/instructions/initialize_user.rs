@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct InitializeUser<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = UserProfile::SPACE,
+        seeds = [b"user", authority.key().as_ref()],
+        bump
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_user(
+    ctx: Context<InitializeUser>,
+    username: String,
+    bio: String,
+    profile_image_url: String,
+    skills: Vec<String>,
+    hourly_rate: u64,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let user_profile = &mut ctx.accounts.user_profile;
+
+    user_profile.authority = ctx.accounts.authority.key();
+    user_profile.username = username;
+    user_profile.bio = bio;
+    user_profile.profile_image_url = profile_image_url;
+    user_profile.skills = skills;
+    user_profile.hourly_rate = hourly_rate;
+    user_profile.total_earnings = 0;
+    user_profile.total_gigs_completed = 0;
+    user_profile.total_gigs_created = 0;
+    user_profile.reputation_score = 0;
+    user_profile.reputation_level = ReputationLevel::Newcomer;
+    user_profile.is_verified = false;
+    user_profile.verification_level = VerificationLevel::None;
+    user_profile.languages = Vec::new();
+    user_profile.location = String::new();
+    user_profile.joined_at = current_time;
+    user_profile.last_active = current_time;
+    user_profile.updated_at = current_time;
+    user_profile.portfolio_items = Vec::new();
+    user_profile.average_rating = 0;
+    user_profile.total_reviews = 0;
+    user_profile.response_time_hours = 0;
+    user_profile.completion_rate = 0;
+    user_profile.badges = Vec::new();
+    user_profile.social_links = Vec::new();
+    user_profile.preferred_categories = Vec::new();
+    user_profile.availability_status = AvailabilityStatus::Available;
+    user_profile.timezone = String::new();
+    user_profile.bump = ctx.bumps.user_profile;
+
+    user_profile.validate()?;
+
+    emit!(UserInitialized {
+        user_profile: user_profile.key(),
+        authority: user_profile.authority,
+        username: user_profile.username.clone(),
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct UserInitialized {
+    pub user_profile: Pubkey,
+    pub authority: Pubkey,
+    pub username: String,
+}
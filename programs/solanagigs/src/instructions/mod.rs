@@ -1,27 +1,135 @@
+pub mod initialize_marketplace;
+pub mod update_marketplace;
+pub mod update_fee_schedule;
+pub mod nominate_authority;
+pub mod accept_authority;
+pub mod assign_role;
+pub mod pause_marketplace;
+pub mod verify_user;
+pub mod create_category;
+pub mod update_category;
+pub mod withdraw_treasury;
+pub mod initialize_governance;
+pub mod update_governance;
+pub mod create_proposal;
+pub mod approve_proposal;
+pub mod cancel_proposal;
+pub mod execute_proposal;
+pub mod add_accepted_mint;
+pub mod update_accepted_mint;
+pub mod initialize_user;
+pub mod update_user_profile;
 pub mod create_gig;
 pub mod update_gig;
 pub mod delete_gig;
 pub mod create_order;
 pub mod accept_order;
+pub mod submit_delivery;
+pub mod request_revision;
+pub mod submit_revision;
+pub mod request_extension;
+pub mod respond_extension;
 pub mod complete_order;
+pub mod auto_release;
 pub mod cancel_order;
+pub mod propose_cancellation;
+pub mod accept_cancellation;
+pub mod reject_cancellation;
+pub mod register_arbiter;
+pub mod update_arbiter;
 pub mod dispute_order;
+pub mod submit_evidence;
+pub mod respond_to_dispute;
+pub mod assign_arbiter;
+pub mod appoint_arbiter;
+pub mod reassign_dispute;
+pub mod default_dispute;
+pub mod register_juror;
+pub mod unstake_juror;
+pub mod draw_jury;
+pub mod commit_vote;
+pub mod reveal_vote;
+pub mod settle_jury;
+pub mod claim_juror_reward;
+pub mod sweep_juror_rewards;
 pub mod resolve_dispute;
+pub mod appeal_dispute;
+pub mod resolve_appeal;
+pub mod finalize_dispute;
 pub mod create_review;
+pub mod close_order;
+pub mod open_earnings;
 pub mod withdraw_earnings;
-pub mod initialize_user;
-pub mod update_user_profile;
+pub mod process_withdrawal;
+pub mod register_referral;
+pub mod submit_milestone;
+pub mod approve_milestone;
+pub mod reject_milestone;
 
+pub use initialize_marketplace::*;
+pub use update_marketplace::*;
+pub use update_fee_schedule::*;
+pub use nominate_authority::*;
+pub use accept_authority::*;
+pub use assign_role::*;
+pub use pause_marketplace::*;
+pub use verify_user::*;
+pub use create_category::*;
+pub use update_category::*;
+pub use withdraw_treasury::*;
+pub use initialize_governance::*;
+pub use update_governance::*;
+pub use create_proposal::*;
+pub use approve_proposal::*;
+pub use cancel_proposal::*;
+pub use execute_proposal::*;
+pub use add_accepted_mint::*;
+pub use update_accepted_mint::*;
+pub use initialize_user::*;
+pub use update_user_profile::*;
 pub use create_gig::*;
 pub use update_gig::*;
 pub use delete_gig::*;
 pub use create_order::*;
 pub use accept_order::*;
+pub use submit_delivery::*;
+pub use request_revision::*;
+pub use submit_revision::*;
+pub use request_extension::*;
+pub use respond_extension::*;
 pub use complete_order::*;
+pub use auto_release::*;
 pub use cancel_order::*;
+pub use propose_cancellation::*;
+pub use accept_cancellation::*;
+pub use reject_cancellation::*;
+pub use register_arbiter::*;
+pub use update_arbiter::*;
 pub use dispute_order::*;
+pub use submit_evidence::*;
+pub use respond_to_dispute::*;
+pub use assign_arbiter::*;
+pub use appoint_arbiter::*;
+pub use reassign_dispute::*;
+pub use default_dispute::*;
+pub use register_juror::*;
+pub use unstake_juror::*;
+pub use draw_jury::*;
+pub use commit_vote::*;
+pub use reveal_vote::*;
+pub use settle_jury::*;
+pub use claim_juror_reward::*;
+pub use sweep_juror_rewards::*;
 pub use resolve_dispute::*;
+pub use appeal_dispute::*;
+pub use resolve_appeal::*;
+pub use finalize_dispute::*;
 pub use create_review::*;
+pub use close_order::*;
+pub use open_earnings::*;
 pub use withdraw_earnings::*;
-pub use initialize_user::*;
-pub use update_user_profile::*;
\ No newline at end of file
+pub use process_withdrawal::*;
+pub use register_referral::*;
+pub use submit_milestone::*;
+pub use approve_milestone::*;
+pub use reject_milestone::*;
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct NominateAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"marketplace"],
+        bump = marketplace.bump,
+        has_one = authority
+    )]
+    pub marketplace: Account<'info, Marketplace>,
+
+    pub authority: Signer<'info>,
+}
+
+/// First half of an authority transfer. Nothing changes until the nominee
+/// accepts, so a mistyped key cannot lock the marketplace; `None` withdraws
+/// a pending nomination.
+pub fn nominate_authority(ctx: Context<NominateAuthority>, new_authority: Option<Pubkey>) -> Result<()> {
+    let marketplace = &mut ctx.accounts.marketplace;
+    marketplace.pending_authority = new_authority;
+
+    emit!(AuthorityNominated {
+        marketplace: marketplace.key(),
+        authority: marketplace.authority,
+        pending_authority: new_authority,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct AuthorityNominated {
+    pub marketplace: Pubkey,
+    pub authority: Pubkey,
+    pub pending_authority: Option<Pubkey>,
+}
@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::spl_token::native_mint;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct OpenEarnings<'info> {
+    #[account(
+        init,
+        payer = seller,
+        space = UserEarnings::LEN,
+        seeds = [b"earnings", seller.key().as_ref(), mint.key().as_ref()],
+        bump
+    )]
+    pub earnings: Box<Account<'info, UserEarnings>>,
+
+    #[account(
+        init,
+        payer = seller,
+        seeds = [b"earnings_vault", earnings.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = earnings
+    )]
+    pub earnings_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        seeds = [b"user", seller.key().as_ref()],
+        bump = seller_profile.bump
+    )]
+    pub seller_profile: Box<Account<'info, UserProfile>>,
+
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Opts a seller into pull payments for one mint. Once the earnings account
+/// exists, every settlement credits it instead of paying the seller
+/// directly. SOL earnings are held as lamports on the account itself.
+pub fn open_earnings(ctx: Context<OpenEarnings>) -> Result<()> {
+    let is_native = ctx.accounts.mint.key() == native_mint::ID;
+    let earnings = &mut ctx.accounts.earnings;
+
+    if is_native {
+        require!(ctx.accounts.earnings_vault.is_none(), SolanaGigsError::InvalidAccountData);
+        earnings.vault = Pubkey::default();
+    } else {
+        let earnings_vault = ctx.accounts.earnings_vault.as_ref()
+            .ok_or(SolanaGigsError::TokenAccountNotFound)?;
+        earnings.vault = earnings_vault.key();
+    }
+
+    earnings.user = ctx.accounts.seller.key();
+    earnings.mint = ctx.accounts.mint.key();
+    earnings.is_native = is_native;
+    earnings.available_balance = 0;
+    earnings.pending_balance = 0;
+    earnings.total_earned = 0;
+    earnings.total_withdrawn = 0;
+    earnings.withdrawal_count = 0;
+    earnings.period_start = 0;
+    earnings.period_withdrawn = 0;
+    earnings.last_updated = Clock::get()?.unix_timestamp;
+    earnings.bump = ctx.bumps.earnings;
+
+    emit!(EarningsOpened {
+        earnings: earnings.key(),
+        user: earnings.user,
+        mint: earnings.mint,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct EarningsOpened {
+    pub earnings: Pubkey,
+    pub user: Pubkey,
+    pub mint: Pubkey,
+}
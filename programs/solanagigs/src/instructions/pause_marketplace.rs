@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct PauseMarketplace<'info> {
+    #[account(
+        mut,
+        seeds = [b"marketplace"],
+        bump = marketplace.bump
+    )]
+    pub marketplace: Account<'info, Marketplace>,
+
+    #[account(address = marketplace.pauser @ SolanaGigsError::AdminPrivilegesRequired)]
+    pub pauser: Signer<'info>,
+}
+
+/// A normal pause only stops new gigs and orders. An emergency stop also
+/// freezes every fund movement apart from buyer refunds of pending orders.
+/// Pausing again can raise a normal pause to an emergency stop, but not lower it.
+pub fn pause(ctx: Context<PauseMarketplace>, emergency: bool) -> Result<()> {
+    let marketplace = &mut ctx.accounts.marketplace;
+    marketplace.is_paused = true;
+    marketplace.is_emergency_stopped |= emergency;
+
+    emit!(MarketplacePauseUpdated {
+        marketplace: marketplace.key(),
+        is_paused: marketplace.is_paused,
+        is_emergency_stopped: marketplace.is_emergency_stopped,
+        pauser: ctx.accounts.pauser.key(),
+    });
+
+    Ok(())
+}
+
+/// Lifts both pause levels at once.
+pub fn unpause(ctx: Context<PauseMarketplace>) -> Result<()> {
+    let marketplace = &mut ctx.accounts.marketplace;
+    marketplace.is_paused = false;
+    marketplace.is_emergency_stopped = false;
+
+    emit!(MarketplacePauseUpdated {
+        marketplace: marketplace.key(),
+        is_paused: false,
+        is_emergency_stopped: false,
+        pauser: ctx.accounts.pauser.key(),
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct MarketplacePauseUpdated {
+    pub marketplace: Pubkey,
+    pub is_paused: bool,
+    pub is_emergency_stopped: bool,
+    pub pauser: Pubkey,
+}
@@ -0,0 +1,125 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::state::*;
+use crate::errors::*;
+use crate::utils::EarningsPayer;
+use crate::instructions::withdraw_earnings::EarningsWithdrawn;
+
+#[derive(Accounts)]
+pub struct ProcessWithdrawal<'info> {
+    #[account(
+        seeds = [b"marketplace"],
+        bump = marketplace.bump,
+        has_one = authority
+    )]
+    pub marketplace: Box<Account<'info, Marketplace>>,
+
+    #[account(
+        mut,
+        seeds = [b"earnings", earnings.user.as_ref(), earnings.mint.as_ref()],
+        bump = earnings.bump
+    )]
+    pub earnings: Box<Account<'info, UserEarnings>>,
+
+    #[account(
+        mut,
+        seeds = [b"withdrawal", earnings.key().as_ref(), withdrawal_request.id.to_le_bytes().as_ref()],
+        bump = withdrawal_request.bump,
+        has_one = earnings
+    )]
+    pub withdrawal_request: Box<Account<'info, WithdrawalRequest>>,
+
+    #[account(
+        mut,
+        constraint = earnings_vault.key() == earnings.vault @ SolanaGigsError::InvalidAccountData
+    )]
+    pub earnings_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(address = earnings.mint @ SolanaGigsError::InvalidTokenMint)]
+    pub mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    /// CHECK: Validated against the earnings mint when the request was made
+    #[account(
+        mut,
+        address = withdrawal_request.destination @ SolanaGigsError::InvalidAccountData
+    )]
+    pub destination: UncheckedAccount<'info>,
+
+    pub authority: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Approves or rejects a withdrawal parked above the threshold. Approval pays
+/// the recorded destination; rejection returns the amount to the available
+/// balance. Neither counts towards the seller's unapproved period total.
+pub fn process_withdrawal<'info>(
+    ctx: Context<'_, '_, '_, 'info, ProcessWithdrawal<'info>>,
+    approve: bool,
+) -> Result<()> {
+    ctx.accounts.marketplace.require_funds_unfrozen()?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let earnings = &mut ctx.accounts.earnings;
+    let withdrawal_request = &mut ctx.accounts.withdrawal_request;
+    let amount = withdrawal_request.amount;
+
+    require!(
+        withdrawal_request.status == WithdrawalStatus::Pending,
+        SolanaGigsError::WithdrawalNotAllowed
+    );
+
+    earnings.pending_balance = earnings.pending_balance.checked_sub(amount)
+        .ok_or(SolanaGigsError::ArithmeticUnderflow)?;
+    earnings.last_updated = current_time;
+
+    if approve {
+        let payer = EarningsPayer {
+            earnings,
+            vault: &ctx.accounts.earnings_vault,
+            mint: &ctx.accounts.mint,
+            token_program: &ctx.accounts.token_program,
+            remaining_accounts: ctx.remaining_accounts,
+        };
+        payer.transfer(ctx.accounts.destination.to_account_info(), amount)?;
+
+        earnings.total_withdrawn = earnings.total_withdrawn.checked_add(amount)
+            .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+        withdrawal_request.status = WithdrawalStatus::Processed;
+
+        emit!(EarningsWithdrawn {
+            earnings: earnings.key(),
+            user: earnings.user,
+            amount,
+            destination: withdrawal_request.destination,
+            approved_by: Some(ctx.accounts.authority.key()),
+        });
+    } else {
+        earnings.available_balance = earnings.available_balance.checked_add(amount)
+            .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+        withdrawal_request.status = WithdrawalStatus::Rejected;
+
+        emit!(WithdrawalRejected {
+            earnings: earnings.key(),
+            withdrawal_request: withdrawal_request.key(),
+            user: earnings.user,
+            amount,
+            rejected_by: ctx.accounts.authority.key(),
+        });
+    }
+
+    withdrawal_request.processed_by = Some(ctx.accounts.authority.key());
+    withdrawal_request.processed_at = Some(current_time);
+
+    Ok(())
+}
+
+#[event]
+pub struct WithdrawalRejected {
+    pub earnings: Pubkey,
+    pub withdrawal_request: Pubkey,
+    pub user: Pubkey,
+    pub amount: u64,
+    pub rejected_by: Pubkey,
+}
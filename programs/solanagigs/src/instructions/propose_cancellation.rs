@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct ProposeCancellation<'info> {
+    #[account(
+        seeds = [b"order", order.id.to_le_bytes().as_ref()],
+        bump = order.bump,
+        constraint = order.is_participant(&proposer.key()) @ SolanaGigsError::Unauthorized
+    )]
+    pub order: Box<Account<'info, Order>>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = CancellationProposal::LEN,
+        seeds = [b"cancellation", order.key().as_ref()],
+        bump
+    )]
+    pub proposal: Box<Account<'info, CancellationProposal>>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn propose_cancellation(
+    ctx: Context<ProposeCancellation>,
+    refund_bps: u16,
+    reason: String,
+) -> Result<()> {
+    require!(
+        refund_bps <= CancellationProposal::MAX_REFUND_BPS,
+        SolanaGigsError::InvalidRefundPercentage
+    );
+    require!(
+        reason.len() <= CancellationProposal::MAX_REASON_LEN,
+        SolanaGigsError::InvalidOrderModification
+    );
+
+    let order = &ctx.accounts.order;
+    require!(
+        matches!(order.status, OrderStatus::InProgress | OrderStatus::Delivered),
+        SolanaGigsError::CannotCancelOrder
+    );
+
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.order = order.key();
+    proposal.proposed_by = ctx.accounts.proposer.key();
+    proposal.refund_bps = refund_bps;
+    proposal.reason = reason;
+    proposal.created_at = Clock::get()?.unix_timestamp;
+    proposal.bump = ctx.bumps.proposal;
+
+    emit!(CancellationProposed {
+        order: order.key(),
+        proposed_by: proposal.proposed_by,
+        refund_bps,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct CancellationProposed {
+    pub order: Pubkey,
+    pub proposed_by: Pubkey,
+    pub refund_bps: u16,
+}
@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::instructions::assign_arbiter::{assign, walk_rotation};
+
+#[derive(Accounts)]
+pub struct ReassignDispute<'info> {
+    #[account(
+        mut,
+        seeds = [b"marketplace"],
+        bump = marketplace.bump
+    )]
+    pub marketplace: Box<Account<'info, Marketplace>>,
+
+    #[account(
+        seeds = [b"gig", gig.id.to_le_bytes().as_ref()],
+        bump = gig.bump
+    )]
+    pub gig: Box<Account<'info, Gig>>,
+
+    #[account(
+        mut,
+        seeds = [b"order", order.id.to_le_bytes().as_ref()],
+        bump = order.bump,
+        has_one = gig
+    )]
+    pub order: Box<Account<'info, Order>>,
+
+    #[account(
+        mut,
+        seeds = [b"dispute", order.key().as_ref()],
+        bump = dispute.bump,
+        has_one = order
+    )]
+    pub dispute: Box<Account<'info, Dispute>>,
+
+    #[account(
+        mut,
+        seeds = [b"arbiter", previous_arbiter.index.to_le_bytes().as_ref()],
+        bump = previous_arbiter.bump,
+        constraint = dispute.arbiter == Some(previous_arbiter.key()) @ SolanaGigsError::ArbiterNotAssigned
+    )]
+    pub previous_arbiter: Box<Account<'info, Arbiter>>,
+
+    #[account(
+        mut,
+        seeds = [b"arbiter", arbiter.index.to_le_bytes().as_ref()],
+        bump = arbiter.bump,
+        constraint = arbiter.key() != previous_arbiter.key() @ SolanaGigsError::ArbiterAlreadyAssigned
+    )]
+    pub arbiter: Box<Account<'info, Arbiter>>,
+}
+
+/// Permissionless once the assigned arbiter misses its deadline. The case
+/// moves to the next arbiter in rotation, skipping the one that timed out.
+/// If `remaining_accounts` walks the full rotation without finding another
+/// first-tier arbiter, the case escalates to an appeals-tier arbiter instead.
+/// Appeals are open to the whole appeals tier rather than one arbiter, so an
+/// appeal past its deadline is not reassigned; `default_dispute` lapses it.
+pub fn reassign_dispute<'info>(ctx: Context<'_, '_, 'info, 'info, ReassignDispute<'info>>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let marketplace = &mut ctx.accounts.marketplace;
+    let dispute = &ctx.accounts.dispute;
+    let category = ctx.accounts.gig.category;
+
+    require!(dispute.status == DisputeStatus::UnderReview, SolanaGigsError::NoDisputeFound);
+    require!(
+        dispute.resolution_mode == ResolutionMode::Arbiter,
+        SolanaGigsError::InvalidResolutionMode
+    );
+    require!(
+        current_time > dispute.arbiter_deadline,
+        SolanaGigsError::ArbiterDeadlineNotReached
+    );
+
+    let previous_key = ctx.accounts.previous_arbiter.key();
+    let expected_index = walk_rotation(marketplace, category, ctx.remaining_accounts, Some(previous_key))?;
+
+    let arbiter = &mut ctx.accounts.arbiter;
+    let escalated = ctx.remaining_accounts.len() >= marketplace.arbiter_count as usize;
+    if escalated {
+        require!(arbiter.can_hear_appeal(category), SolanaGigsError::ArbiterNotEligible);
+    } else {
+        require!(arbiter.index == expected_index, SolanaGigsError::ArbiterOutOfRotation);
+        require!(arbiter.is_eligible(category), SolanaGigsError::ArbiterNotEligible);
+        marketplace.next_arbiter_index = (expected_index + 1) % marketplace.arbiter_count;
+    }
+
+    let previous_arbiter = &mut ctx.accounts.previous_arbiter;
+    previous_arbiter.active_cases = previous_arbiter.active_cases.saturating_sub(1);
+
+    let arbiter_period = marketplace.arbiter_period;
+    assign(&mut ctx.accounts.order, &mut ctx.accounts.dispute, arbiter, arbiter_period)?;
+
+    emit!(DisputeReassigned {
+        order: ctx.accounts.order.key(),
+        dispute: ctx.accounts.dispute.key(),
+        previous_arbiter: previous_key,
+        arbiter: arbiter.key(),
+        escalated,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct DisputeReassigned {
+    pub order: Pubkey,
+    pub dispute: Pubkey,
+    pub previous_arbiter: Pubkey,
+    pub arbiter: Pubkey,
+    pub escalated: bool,
+}
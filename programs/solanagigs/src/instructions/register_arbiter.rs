@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct RegisterArbiter<'info> {
+    #[account(
+        mut,
+        seeds = [b"marketplace"],
+        bump = marketplace.bump
+    )]
+    pub marketplace: Account<'info, Marketplace>,
+
+    #[account(
+        init,
+        payer = dispute_admin,
+        space = Arbiter::LEN,
+        seeds = [b"arbiter", marketplace.arbiter_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub arbiter: Account<'info, Arbiter>,
+
+    /// CHECK: Wallet that will sign resolutions for this arbiter
+    pub arbiter_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = marketplace.dispute_admin @ SolanaGigsError::AdminPrivilegesRequired
+    )]
+    pub dispute_admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn register_arbiter(
+    ctx: Context<RegisterArbiter>,
+    categories: Vec<ServiceCategory>,
+    tier: ArbiterTier,
+) -> Result<()> {
+    require!(
+        categories.len() <= Arbiter::MAX_CATEGORIES,
+        SolanaGigsError::TooManyCategories
+    );
+
+    let marketplace = &mut ctx.accounts.marketplace;
+    let arbiter = &mut ctx.accounts.arbiter;
+
+    arbiter.index = marketplace.arbiter_count;
+    arbiter.authority = ctx.accounts.arbiter_authority.key();
+    arbiter.categories = categories;
+    arbiter.tier = tier;
+    arbiter.is_active = true;
+    arbiter.active_cases = 0;
+    arbiter.resolved_cases = 0;
+    arbiter.total_cases = 0;
+    arbiter.registered_at = Clock::get()?.unix_timestamp;
+    arbiter.bump = ctx.bumps.arbiter;
+
+    marketplace.arbiter_count = marketplace.arbiter_count.checked_add(1)
+        .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+
+    emit!(ArbiterUpdated {
+        arbiter: arbiter.key(),
+        authority: arbiter.authority,
+        categories: arbiter.categories.clone(),
+        tier,
+        is_active: true,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ArbiterUpdated {
+    pub arbiter: Pubkey,
+    pub authority: Pubkey,
+    pub categories: Vec<ServiceCategory>,
+    pub tier: ArbiterTier,
+    pub is_active: bool,
+}
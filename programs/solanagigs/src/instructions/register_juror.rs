@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct RegisterJuror<'info> {
+    #[account(
+        mut,
+        seeds = [b"marketplace"],
+        bump = marketplace.bump
+    )]
+    pub marketplace: Account<'info, Marketplace>,
+
+    #[account(
+        init,
+        payer = juror,
+        space = JurorStake::LEN,
+        seeds = [b"juror", juror.key().as_ref()],
+        bump
+    )]
+    pub juror_stake: Account<'info, JurorStake>,
+
+    #[account(mut)]
+    pub juror: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn register_juror(ctx: Context<RegisterJuror>, stake_amount: u64) -> Result<()> {
+    ctx.accounts.marketplace.require_funds_unfrozen()?;
+
+    require!(stake_amount >= JurorStake::MIN_STAKE, SolanaGigsError::InsufficientJurorStake);
+
+    let cpi_accounts = system_program::Transfer {
+        from: ctx.accounts.juror.to_account_info(),
+        to: ctx.accounts.juror_stake.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.system_program.to_account_info();
+    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+    system_program::transfer(cpi_ctx, stake_amount)?;
+
+    let marketplace = &mut ctx.accounts.marketplace;
+    let juror_stake = &mut ctx.accounts.juror_stake;
+
+    juror_stake.index = marketplace.juror_count;
+    juror_stake.juror = ctx.accounts.juror.key();
+    juror_stake.staked_amount = stake_amount;
+    juror_stake.is_active = true;
+    juror_stake.active_cases = 0;
+    juror_stake.total_votes = 0;
+    juror_stake.majority_votes = 0;
+    juror_stake.slashed_amount = 0;
+    juror_stake.registered_at = Clock::get()?.unix_timestamp;
+    juror_stake.bump = ctx.bumps.juror_stake;
+
+    marketplace.juror_count = marketplace.juror_count.checked_add(1)
+        .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+
+    emit!(JurorStakeUpdated {
+        juror: juror_stake.juror,
+        staked_amount: stake_amount,
+        is_active: true,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct JurorStakeUpdated {
+    pub juror: Pubkey,
+    pub staked_amount: u64,
+    pub is_active: bool,
+}
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct RegisterReferral<'info> {
+    #[account(
+        init,
+        payer = user,
+        space = Referral::LEN,
+        seeds = [b"referral", user.key().as_ref()],
+        bump
+    )]
+    pub referral: Account<'info, Referral>,
+
+    #[account(
+        seeds = [b"user", user.key().as_ref()],
+        bump = user_profile.bump
+    )]
+    pub user_profile: Box<Account<'info, UserProfile>>,
+
+    #[account(
+        seeds = [b"user", referrer_profile.authority.as_ref()],
+        bump = referrer_profile.bump
+    )]
+    pub referrer_profile: Box<Account<'info, UserProfile>>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Binds the caller to a referrer. The referral PDA is keyed by the user
+/// alone, so a second registration fails and the binding is permanent.
+pub fn register_referral(ctx: Context<RegisterReferral>) -> Result<()> {
+    let user = ctx.accounts.user.key();
+    let referrer = ctx.accounts.referrer_profile.authority;
+    require_keys_neq!(user, referrer, SolanaGigsError::CannotReferYourself);
+
+    let referral = &mut ctx.accounts.referral;
+    referral.user = user;
+    referral.referrer = referrer;
+    referral.total_rewards = 0;
+    referral.reward_count = 0;
+    referral.created_at = Clock::get()?.unix_timestamp;
+    referral.bump = ctx.bumps.referral;
+
+    emit!(ReferralRegistered {
+        referral: referral.key(),
+        user,
+        referrer,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ReferralRegistered {
+    pub referral: Pubkey,
+    pub user: Pubkey,
+    pub referrer: Pubkey,
+}
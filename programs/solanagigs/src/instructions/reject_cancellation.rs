@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct RejectCancellation<'info> {
+    #[account(
+        seeds = [b"order", order.id.to_le_bytes().as_ref()],
+        bump = order.bump,
+        constraint = order.is_participant(&signer.key()) @ SolanaGigsError::Unauthorized
+    )]
+    pub order: Box<Account<'info, Order>>,
+
+    #[account(
+        mut,
+        seeds = [b"cancellation", order.key().as_ref()],
+        bump = proposal.bump,
+        has_one = order,
+        close = proposer
+    )]
+    pub proposal: Box<Account<'info, CancellationProposal>>,
+
+    /// CHECK: Receives the proposal rent back
+    #[account(
+        mut,
+        address = proposal.proposed_by @ SolanaGigsError::Unauthorized
+    )]
+    pub proposer: UncheckedAccount<'info>,
+
+    pub signer: Signer<'info>,
+}
+
+/// Declines a proposal as the counterparty, or withdraws it as the proposer.
+pub fn reject_cancellation(ctx: Context<RejectCancellation>) -> Result<()> {
+    emit!(CancellationRejected {
+        order: ctx.accounts.order.key(),
+        proposed_by: ctx.accounts.proposal.proposed_by,
+        rejected_by: ctx.accounts.signer.key(),
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct CancellationRejected {
+    pub order: Pubkey,
+    pub proposed_by: Pubkey,
+    pub rejected_by: Pubkey,
+}
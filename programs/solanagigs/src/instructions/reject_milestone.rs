@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct RejectMilestone<'info> {
+    #[account(
+        seeds = [b"order", order.id.to_le_bytes().as_ref()],
+        bump = order.bump,
+        has_one = buyer
+    )]
+    pub order: Box<Account<'info, Order>>,
+
+    #[account(
+        mut,
+        seeds = [b"milestone", order.key().as_ref(), &[milestone.milestone_id]],
+        bump = milestone.bump,
+        has_one = order
+    )]
+    pub milestone: Box<Account<'info, OrderMilestone>>,
+
+    pub buyer: Signer<'info>,
+}
+
+pub fn reject_milestone(ctx: Context<RejectMilestone>, reason: String) -> Result<()> {
+    require!(
+        !reason.is_empty() && reason.len() <= OrderMilestone::MAX_REJECTION_REASON_LEN,
+        SolanaGigsError::InvalidMilestoneDetails
+    );
+
+    let order = &ctx.accounts.order;
+    let milestone = &mut ctx.accounts.milestone;
+
+    require!(order.status == OrderStatus::InProgress, SolanaGigsError::InvalidOrderStatus);
+    require!(milestone.can_be_approved(), SolanaGigsError::InvalidMilestoneDetails);
+
+    let current_time = Clock::get()?.unix_timestamp;
+    milestone.status = MilestoneStatus::Rejected;
+    milestone.rejected_at = Some(current_time);
+    milestone.rejection_reason = Some(reason.clone());
+
+    emit!(MilestoneRejected {
+        order: order.key(),
+        milestone: milestone.key(),
+        milestone_id: milestone.milestone_id,
+        reason,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct MilestoneRejected {
+    pub order: Pubkey,
+    pub milestone: Pubkey,
+    pub milestone_id: u8,
+    pub reason: String,
+}
@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct RequestExtension<'info> {
+    #[account(
+        mut,
+        seeds = [b"order", order.id.to_le_bytes().as_ref()],
+        bump = order.bump,
+        has_one = seller
+    )]
+    pub order: Box<Account<'info, Order>>,
+
+    #[account(
+        init,
+        payer = seller,
+        space = ExtensionRequest::LEN,
+        seeds = [b"extension", order.key().as_ref(), &[order.extension_count.saturating_add(1)]],
+        bump
+    )]
+    pub extension: Box<Account<'info, ExtensionRequest>>,
+
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn request_extension(
+    ctx: Context<RequestExtension>,
+    new_deadline: i64,
+    price_concession: u64,
+    reason: String,
+) -> Result<()> {
+    require!(
+        reason.len() <= ExtensionRequest::MAX_REASON_LEN,
+        SolanaGigsError::InvalidOrderModification
+    );
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let order = &mut ctx.accounts.order;
+    let extension = &mut ctx.accounts.extension;
+
+    require!(order.status == OrderStatus::InProgress, SolanaGigsError::InvalidOrderStatus);
+    require!(!order.has_pending_extension, SolanaGigsError::ExtensionAlreadyPending);
+    require!(order.extension_count < Order::MAX_EXTENSIONS, SolanaGigsError::TooManyExtensions);
+    require!(
+        new_deadline > order.delivery_deadline && new_deadline > current_time,
+        SolanaGigsError::InvalidDeliveryTime
+    );
+    require!(
+        new_deadline <= current_time + Gig::MAX_DELIVERY_TIME as i64 * 86400,
+        SolanaGigsError::TimestampTooFarInFuture
+    );
+    // Milestone amounts are fixed against the order price, so only flat orders can be discounted
+    require!(
+        price_concession == 0 || !order.has_milestones(),
+        SolanaGigsError::InvalidOrderModification
+    );
+    require!(price_concession < order.amount, SolanaGigsError::InvalidDiscountAmount);
+
+    order.extension_count = order.extension_count.checked_add(1)
+        .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+    order.has_pending_extension = true;
+
+    extension.order = order.key();
+    extension.request_number = order.extension_count;
+    extension.requested_by = ctx.accounts.seller.key();
+    extension.previous_deadline = order.delivery_deadline;
+    extension.new_deadline = new_deadline;
+    extension.price_concession = price_concession;
+    extension.reason = reason;
+    extension.status = ExtensionStatus::Pending;
+    extension.requested_at = current_time;
+    extension.responded_at = None;
+    extension.bump = ctx.bumps.extension;
+
+    emit!(ExtensionRequested {
+        order: order.key(),
+        extension: extension.key(),
+        request_number: extension.request_number,
+        previous_deadline: extension.previous_deadline,
+        new_deadline,
+        price_concession,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ExtensionRequested {
+    pub order: Pubkey,
+    pub extension: Pubkey,
+    pub request_number: u8,
+    pub previous_deadline: i64,
+    pub new_deadline: i64,
+    pub price_concession: u64,
+}
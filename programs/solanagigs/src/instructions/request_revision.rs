@@ -0,0 +1,128 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct RequestRevision<'info> {
+    #[account(
+        seeds = [b"marketplace"],
+        bump = marketplace.bump
+    )]
+    pub marketplace: Box<Account<'info, Marketplace>>,
+
+    #[account(
+        mut,
+        seeds = [b"order", order.id.to_le_bytes().as_ref()],
+        bump = order.bump,
+        has_one = buyer
+    )]
+    pub order: Box<Account<'info, Order>>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", order.key().as_ref()],
+        bump = escrow.bump,
+        has_one = order
+    )]
+    pub escrow: Box<Account<'info, Escrow>>,
+
+    #[account(
+        init,
+        payer = buyer,
+        space = OrderRevision::LEN,
+        seeds = [b"revision", order.key().as_ref(), &[order.revision_count + 1]],
+        bump
+    )]
+    pub revision: Box<Account<'info, OrderRevision>>,
+
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn request_revision(
+    ctx: Context<RequestRevision>,
+    reason: String,
+    description: String,
+    extension_days: Option<u32>,
+) -> Result<()> {
+    require!(
+        !reason.is_empty() && reason.len() <= OrderRevision::MAX_REASON_LEN,
+        SolanaGigsError::InvalidOrderModification
+    );
+    require!(
+        description.len() <= OrderRevision::MAX_DESCRIPTION_LEN,
+        SolanaGigsError::InvalidOrderModification
+    );
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let order = &mut ctx.accounts.order;
+    let escrow = &mut ctx.accounts.escrow;
+    let revision = &mut ctx.accounts.revision;
+
+    require!(
+        order.status != OrderStatus::Completed,
+        SolanaGigsError::CannotRequestRevisionOnCompletedOrder
+    );
+    require!(order.status == OrderStatus::Delivered, SolanaGigsError::InvalidOrderStatus);
+    require!(order.max_revisions > 0, SolanaGigsError::NoRevisionsRemaining);
+    require!(order.can_request_revision(), SolanaGigsError::RevisionLimitExceeded);
+
+    // A lapsed deadline must be extended, otherwise the buyer could cancel the revision outright
+    let extension_days = extension_days.unwrap_or(0);
+    require!(
+        extension_days > 0 || order.delivery_deadline > current_time,
+        SolanaGigsError::InvalidDeliveryTime
+    );
+    require!(
+        extension_days <= Gig::MAX_DELIVERY_TIME,
+        SolanaGigsError::DeliveryTimeTooLong
+    );
+
+    if extension_days > 0 {
+        order.delivery_deadline = order.delivery_deadline
+            .max(current_time)
+            .checked_add(extension_days as i64 * 86400)
+            .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+    }
+
+    order.revision_count += 1;
+    order.status = OrderStatus::InProgress;
+
+    escrow.dispute_deadline = order.delivery_deadline
+        .checked_add(ctx.accounts.marketplace.dispute_period)
+        .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+
+    revision.order = order.key();
+    revision.revision_number = order.revision_count;
+    revision.requested_by = ctx.accounts.buyer.key();
+    revision.reason = reason;
+    revision.description = description;
+    revision.requested_at = current_time;
+    revision.completed_at = None;
+    revision.status = RevisionStatus::Requested;
+    revision.deliverable_url = None;
+    revision.feedback = None;
+    revision.bump = ctx.bumps.revision;
+
+    emit!(RevisionRequested {
+        order: order.key(),
+        revision: revision.key(),
+        revision_number: revision.revision_number,
+        revisions_remaining: order.max_revisions - order.revision_count,
+        delivery_deadline: order.delivery_deadline,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct RevisionRequested {
+    pub order: Pubkey,
+    pub revision: Pubkey,
+    pub revision_number: u8,
+    pub revisions_remaining: u8,
+    pub delivery_deadline: i64,
+}
@@ -0,0 +1,150 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::spl_token::native_mint;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::utils::debit_lamports;
+
+#[derive(Accounts)]
+pub struct ResolveAppeal<'info> {
+    #[account(
+        seeds = [b"marketplace"],
+        bump = marketplace.bump
+    )]
+    pub marketplace: Box<Account<'info, Marketplace>>,
+
+    #[account(
+        seeds = [b"gig", gig.id.to_le_bytes().as_ref()],
+        bump = gig.bump
+    )]
+    pub gig: Box<Account<'info, Gig>>,
+
+    #[account(
+        seeds = [b"order", order.id.to_le_bytes().as_ref()],
+        bump = order.bump,
+        has_one = gig
+    )]
+    pub order: Box<Account<'info, Order>>,
+
+    #[account(
+        mut,
+        seeds = [b"dispute", order.key().as_ref()],
+        bump = dispute.bump,
+        has_one = order
+    )]
+    pub dispute: Box<Account<'info, Dispute>>,
+
+    #[account(
+        mut,
+        seeds = [b"appeal", dispute.key().as_ref()],
+        bump = appeal.bump,
+        has_one = dispute,
+        has_one = appellant,
+        close = appellant
+    )]
+    pub appeal: Box<Account<'info, DisputeAppeal>>,
+
+    #[account(
+        seeds = [b"arbiter", arbiter.index.to_le_bytes().as_ref()],
+        bump = arbiter.bump,
+        has_one = authority,
+        constraint = dispute.arbiter != Some(arbiter.key()) @ SolanaGigsError::ArbiterNotEligible
+    )]
+    pub arbiter: Box<Account<'info, Arbiter>>,
+
+    /// CHECK: Receives the appeal rent, and the bond when the appeal succeeds
+    #[account(mut)]
+    pub appellant: UncheckedAccount<'info>,
+
+    /// SOL treasury, which keeps forfeited bonds
+    #[account(
+        mut,
+        seeds = [b"treasury", native_mint::ID.as_ref()],
+        bump = treasury.bump
+    )]
+    pub treasury: Box<Account<'info, Treasury>>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Decides an appeal as a second-tier arbiter. The bond goes back to the
+/// appellant if the refund moves in their favour and to the SOL treasury
+/// otherwise. The decision settles through `finalize_dispute` right away.
+pub fn resolve_appeal(
+    ctx: Context<ResolveAppeal>,
+    resolution: DisputeResolution,
+    refund_bps: u16,
+    resolution_reason: Option<String>,
+) -> Result<()> {
+    ctx.accounts.marketplace.require_funds_unfrozen()?;
+
+    require!(refund_bps <= 10_000, SolanaGigsError::InvalidRefundPercentage);
+    require!(
+        resolution.is_consistent_with(refund_bps),
+        SolanaGigsError::InvalidDisputeResolution
+    );
+    if let Some(ref reason) = resolution_reason {
+        require!(
+            reason.len() <= Dispute::MAX_RESOLUTION_REASON_LEN,
+            SolanaGigsError::InvalidDisputeResolution
+        );
+    }
+    require!(
+        ctx.accounts.arbiter.can_hear_appeal(ctx.accounts.gig.category),
+        SolanaGigsError::ArbiterNotEligible
+    );
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let order = &ctx.accounts.order;
+    let dispute = &mut ctx.accounts.dispute;
+    let appeal = &ctx.accounts.appeal;
+
+    require!(dispute.status == DisputeStatus::Appealed, SolanaGigsError::NoDisputeFound);
+
+    let appellant_is_buyer = appeal.appellant == order.buyer;
+    let appeal_successful = appeal.is_successful(appellant_is_buyer, refund_bps);
+
+    // A failed appeal forfeits the bond; the rent goes back either way on close
+    if !appeal_successful && appeal.bond > 0 {
+        debit_lamports(
+            &appeal.to_account_info(),
+            &ctx.accounts.treasury.to_account_info(),
+            appeal.bond,
+        )?;
+    }
+
+    dispute.status = DisputeStatus::PendingSettlement;
+    dispute.resolution = Some(resolution);
+    dispute.refund_bps = refund_bps;
+    dispute.resolution_reason = resolution_reason;
+    dispute.resolved_by = Some(ctx.accounts.authority.key());
+    dispute.appeal_deadline = current_time;
+    dispute.updated_at = current_time;
+
+    emit!(AppealResolved {
+        order: order.key(),
+        dispute: dispute.key(),
+        appellant: appeal.appellant,
+        resolved_by: ctx.accounts.authority.key(),
+        resolution,
+        original_refund_bps: appeal.original_refund_bps,
+        refund_bps,
+        appeal_successful,
+        bond: appeal.bond,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct AppealResolved {
+    pub order: Pubkey,
+    pub dispute: Pubkey,
+    pub appellant: Pubkey,
+    pub resolved_by: Pubkey,
+    pub resolution: DisputeResolution,
+    pub original_refund_bps: u16,
+    pub refund_bps: u16,
+    pub appeal_successful: bool,
+    pub bond: u64,
+}
@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct ResolveDispute<'info> {
+    #[account(
+        seeds = [b"marketplace"],
+        bump = marketplace.bump
+    )]
+    pub marketplace: Box<Account<'info, Marketplace>>,
+
+    #[account(
+        seeds = [b"order", order.id.to_le_bytes().as_ref()],
+        bump = order.bump
+    )]
+    pub order: Box<Account<'info, Order>>,
+
+    #[account(
+        mut,
+        seeds = [b"dispute", order.key().as_ref()],
+        bump = dispute.bump,
+        has_one = order
+    )]
+    pub dispute: Box<Account<'info, Dispute>>,
+
+    #[account(
+        mut,
+        seeds = [b"arbiter", arbiter.index.to_le_bytes().as_ref()],
+        bump = arbiter.bump,
+        has_one = authority,
+        constraint = dispute.arbiter == Some(arbiter.key()) @ SolanaGigsError::ArbiterNotAssigned
+    )]
+    pub arbiter: Box<Account<'info, Arbiter>>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Records the arbiter's decision. Funds stay in escrow until the appeal
+/// window closes and `finalize_dispute` settles them.
+pub fn resolve_dispute(
+    ctx: Context<ResolveDispute>,
+    resolution: DisputeResolution,
+    refund_bps: u16,
+    resolution_reason: Option<String>,
+) -> Result<()> {
+    require!(refund_bps <= 10_000, SolanaGigsError::InvalidRefundPercentage);
+    require!(
+        resolution.is_consistent_with(refund_bps),
+        SolanaGigsError::InvalidDisputeResolution
+    );
+    if let Some(ref reason) = resolution_reason {
+        require!(
+            reason.len() <= Dispute::MAX_RESOLUTION_REASON_LEN,
+            SolanaGigsError::InvalidDisputeResolution
+        );
+    }
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let order = &ctx.accounts.order;
+    let dispute = &mut ctx.accounts.dispute;
+
+    require!(order.status == OrderStatus::Disputed, SolanaGigsError::InvalidOrderStatus);
+    require!(dispute.is_active(), SolanaGigsError::NoDisputeFound);
+
+    let appeal_deadline = current_time.checked_add(ctx.accounts.marketplace.appeal_period)
+        .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+
+    dispute.status = DisputeStatus::PendingSettlement;
+    dispute.resolution = Some(resolution);
+    dispute.refund_bps = refund_bps;
+    dispute.resolution_reason = resolution_reason;
+    dispute.resolved_by = Some(ctx.accounts.authority.key());
+    dispute.appeal_deadline = appeal_deadline;
+    dispute.updated_at = current_time;
+
+    let arbiter = &mut ctx.accounts.arbiter;
+    arbiter.active_cases = arbiter.active_cases.saturating_sub(1);
+    arbiter.resolved_cases = arbiter.resolved_cases.checked_add(1)
+        .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+
+    emit!(DisputeResolutionProposed {
+        order: order.key(),
+        dispute: dispute.key(),
+        resolved_by: ctx.accounts.authority.key(),
+        resolution,
+        refund_bps,
+        appeal_deadline,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct DisputeResolutionProposed {
+    pub order: Pubkey,
+    pub dispute: Pubkey,
+    pub resolved_by: Pubkey,
+    pub resolution: DisputeResolution,
+    pub refund_bps: u16,
+    pub appeal_deadline: i64,
+}
@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::state::*;
+use crate::errors::*;
+use crate::utils::{payout_account, EscrowPayer};
+
+#[derive(Accounts)]
+pub struct RespondExtension<'info> {
+    #[account(
+        seeds = [b"marketplace"],
+        bump = marketplace.bump
+    )]
+    pub marketplace: Box<Account<'info, Marketplace>>,
+
+    #[account(
+        mut,
+        seeds = [b"order", order.id.to_le_bytes().as_ref()],
+        bump = order.bump,
+        has_one = buyer
+    )]
+    pub order: Box<Account<'info, Order>>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", order.key().as_ref()],
+        bump = escrow.bump,
+        has_one = order
+    )]
+    pub escrow: Box<Account<'info, Escrow>>,
+
+    #[account(
+        mut,
+        seeds = [b"extension", order.key().as_ref(), &[order.extension_count]],
+        bump = extension.bump,
+        has_one = order
+    )]
+    pub extension: Box<Account<'info, ExtensionRequest>>,
+
+    #[account(
+        mut,
+        constraint = vault.key() == escrow.vault @ SolanaGigsError::InvalidEscrowState,
+        token::mint = escrow.mint
+    )]
+    pub vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(address = escrow.mint @ SolanaGigsError::InvalidTokenMint)]
+    pub mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        mut,
+        token::mint = escrow.mint,
+        token::authority = order.buyer
+    )]
+    pub buyer_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn respond_extension<'info>(
+    ctx: Context<'_, '_, '_, 'info, RespondExtension<'info>>,
+    accept: bool,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let order = &mut ctx.accounts.order;
+    let escrow = &mut ctx.accounts.escrow;
+    let extension = &mut ctx.accounts.extension;
+
+    require!(order.status == OrderStatus::InProgress, SolanaGigsError::InvalidOrderStatus);
+    require!(
+        order.has_pending_extension && extension.status == ExtensionStatus::Pending,
+        SolanaGigsError::ExtensionNotPending
+    );
+
+    let mut refund_amount = 0;
+    if accept {
+        if extension.price_concession > 0 {
+            ctx.accounts.marketplace.require_funds_unfrozen()?;
+            refund_amount = escrow.escrowed_share(order.amount, extension.price_concession);
+            require!(
+                refund_amount < escrow.remaining_amount(),
+                SolanaGigsError::InsufficientFunds
+            );
+
+            let payer = EscrowPayer {
+                escrow,
+                vault: &ctx.accounts.vault,
+                mint: &ctx.accounts.mint,
+                token_program: &ctx.accounts.token_program,
+                remaining_accounts: ctx.remaining_accounts,
+            };
+
+            let buyer_destination = if escrow.is_native {
+                ctx.accounts.buyer.to_account_info()
+            } else {
+                payout_account(escrow.is_native, &ctx.accounts.buyer_token_account, &None)?
+            };
+            payer.transfer(buyer_destination, refund_amount)?;
+
+            order.amount -= extension.price_concession;
+            escrow.amount -= refund_amount;
+        }
+
+        order.delivery_deadline = extension.new_deadline;
+        escrow.dispute_deadline = extension.new_deadline
+            .checked_add(ctx.accounts.marketplace.dispute_period)
+            .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+        extension.status = ExtensionStatus::Accepted;
+    } else {
+        extension.status = ExtensionStatus::Declined;
+    }
+
+    order.has_pending_extension = false;
+    extension.responded_at = Some(current_time);
+
+    emit!(ExtensionResponded {
+        order: order.key(),
+        extension: extension.key(),
+        request_number: extension.request_number,
+        accepted: accept,
+        delivery_deadline: order.delivery_deadline,
+        refund_amount,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ExtensionResponded {
+    pub order: Pubkey,
+    pub extension: Pubkey,
+    pub request_number: u8,
+    pub accepted: bool,
+    pub delivery_deadline: i64,
+    pub refund_amount: u64,
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct RespondToDispute<'info> {
+    #[account(
+        seeds = [b"order", order.id.to_le_bytes().as_ref()],
+        bump = order.bump
+    )]
+    pub order: Box<Account<'info, Order>>,
+
+    #[account(
+        mut,
+        seeds = [b"dispute", order.key().as_ref()],
+        bump = dispute.bump,
+        has_one = order,
+        has_one = respondent @ SolanaGigsError::Unauthorized
+    )]
+    pub dispute: Box<Account<'info, Dispute>>,
+
+    pub respondent: Signer<'info>,
+}
+
+/// Records the counterparty's statement. The response is stored in the
+/// buyer or seller slot depending on who was disputed and can be given once.
+pub fn respond_to_dispute(ctx: Context<RespondToDispute>, response: String) -> Result<()> {
+    require!(
+        !response.is_empty() && response.len() <= Dispute::MAX_RESPONSE_LEN,
+        SolanaGigsError::InvalidDisputeResponse
+    );
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let order = &ctx.accounts.order;
+    let dispute = &mut ctx.accounts.dispute;
+
+    require!(dispute.is_active(), SolanaGigsError::NoDisputeFound);
+    require!(
+        current_time <= dispute.response_deadline,
+        SolanaGigsError::ResponseDeadlinePassed
+    );
+
+    let slot = if dispute.respondent == order.buyer {
+        &mut dispute.buyer_response
+    } else {
+        &mut dispute.seller_response
+    };
+    require!(slot.is_none(), SolanaGigsError::DisputeAlreadyAnswered);
+    *slot = Some(response);
+    dispute.updated_at = current_time;
+
+    emit!(DisputeResponded {
+        order: order.key(),
+        dispute: dispute.key(),
+        respondent: dispute.respondent,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct DisputeResponded {
+    pub order: Pubkey,
+    pub dispute: Pubkey,
+    pub respondent: Pubkey,
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct RevealVote<'info> {
+    #[account(
+        mut,
+        seeds = [b"jury", jury_case.dispute.as_ref()],
+        bump = jury_case.bump
+    )]
+    pub jury_case: Box<Account<'info, JuryCase>>,
+
+    pub juror: Signer<'info>,
+}
+
+pub fn reveal_vote(ctx: Context<RevealVote>, refund_bps: u16, salt: [u8; 32]) -> Result<()> {
+    require!(refund_bps <= 10_000, SolanaGigsError::InvalidRefundPercentage);
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let jury_case = &mut ctx.accounts.jury_case;
+    let juror = ctx.accounts.juror.key();
+
+    require!(jury_case.status == JuryStatus::Voting, SolanaGigsError::VotingPhaseClosed);
+    // Reveals open once every juror has committed or the commit window ends
+    require!(
+        jury_case.all_committed() || current_time > jury_case.commit_deadline,
+        SolanaGigsError::VotingPhaseActive
+    );
+    require!(current_time <= jury_case.reveal_deadline, SolanaGigsError::VotingPhaseClosed);
+
+    let position = jury_case.juror_position(&juror)
+        .ok_or(SolanaGigsError::JurorNotOnPanel)?;
+    let vote = &mut jury_case.jurors[position];
+    require!(vote.refund_bps.is_none(), SolanaGigsError::VoteAlreadyCast);
+    require!(
+        vote.commitment == Some(JuryCase::commitment(refund_bps, &salt, &juror)),
+        SolanaGigsError::InvalidVoteCommitment
+    );
+    vote.refund_bps = Some(refund_bps);
+
+    emit!(VoteRevealed {
+        dispute: jury_case.dispute,
+        juror,
+        refund_bps,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct VoteRevealed {
+    pub dispute: Pubkey,
+    pub juror: Pubkey,
+    pub refund_bps: u16,
+}
@@ -0,0 +1,177 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::spl_token::native_mint;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::utils::debit_lamports;
+
+#[derive(Accounts)]
+pub struct SettleJury<'info> {
+    #[account(
+        seeds = [b"marketplace"],
+        bump = marketplace.bump
+    )]
+    pub marketplace: Box<Account<'info, Marketplace>>,
+
+    #[account(
+        seeds = [b"order", order.id.to_le_bytes().as_ref()],
+        bump = order.bump
+    )]
+    pub order: Box<Account<'info, Order>>,
+
+    #[account(
+        mut,
+        seeds = [b"dispute", order.key().as_ref()],
+        bump = dispute.bump,
+        has_one = order
+    )]
+    pub dispute: Box<Account<'info, Dispute>>,
+
+    #[account(
+        mut,
+        seeds = [b"jury", dispute.key().as_ref()],
+        bump = jury_case.bump,
+        has_one = dispute
+    )]
+    pub jury_case: Box<Account<'info, JuryCase>>,
+
+    /// SOL treasury, which keeps stakes slashed on a failed panel
+    #[account(
+        mut,
+        seeds = [b"treasury", native_mint::ID.as_ref()],
+        bump = sol_treasury.bump
+    )]
+    pub sol_treasury: Box<Account<'info, Treasury>>,
+
+    pub caller: Signer<'info>,
+}
+
+/// Permissionless once every juror has revealed or the reveal window ends.
+/// The remaining accounts are the panel's juror stakes in panel order. The
+/// median vote becomes a pending resolution that can be appealed like an
+/// arbiter's, and `finalize_dispute` pays it out.
+pub fn settle_jury<'info>(ctx: Context<'_, '_, 'info, 'info, SettleJury<'info>>) -> Result<()> {
+    ctx.accounts.marketplace.require_funds_unfrozen()?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let order = &ctx.accounts.order;
+    let dispute = &mut ctx.accounts.dispute;
+    let jury_case = &mut ctx.accounts.jury_case;
+
+    require!(order.status == OrderStatus::Disputed, SolanaGigsError::InvalidOrderStatus);
+    require!(dispute.is_active(), SolanaGigsError::NoDisputeFound);
+    require!(jury_case.status == JuryStatus::Voting, SolanaGigsError::VotingPhaseClosed);
+    require!(
+        jury_case.all_revealed() || current_time > jury_case.reveal_deadline,
+        SolanaGigsError::VotingPhaseActive
+    );
+    require!(
+        ctx.remaining_accounts.len() >= JuryCase::PANEL_SIZE,
+        SolanaGigsError::JurorNotOnPanel
+    );
+    let juror_accounts = &ctx.remaining_accounts[..JuryCase::PANEL_SIZE];
+
+    let median = jury_case.median_vote();
+    if let Some(median) = median {
+        jury_case.median_refund_bps = median;
+    }
+
+    // Release every juror from the case, tally votes and slash no-shows into the case account
+    let jury_case_info = jury_case.to_account_info();
+    let mut majority_count = 0u8;
+    let mut slashed_total = 0u64;
+    for (vote, account_info) in jury_case.jurors.iter().zip(juror_accounts.iter()) {
+        let mut juror_stake = Account::<JurorStake>::try_from(account_info)?;
+        require!(juror_stake.juror == vote.juror, SolanaGigsError::JurorNotOnPanel);
+        juror_stake.active_cases = juror_stake.active_cases.saturating_sub(1);
+
+        match vote.refund_bps {
+            Some(refund_bps) => {
+                juror_stake.total_votes += 1;
+                if median.is_some() && jury_case.is_majority_vote(refund_bps) {
+                    juror_stake.majority_votes += 1;
+                    majority_count += 1;
+                }
+            }
+            None => {
+                let slash = ((juror_stake.staked_amount as u128 * JuryCase::NO_SHOW_SLASH_BPS as u128) / 10_000) as u64;
+                debit_lamports(&juror_stake.to_account_info(), &jury_case_info, slash)?;
+
+                juror_stake.staked_amount -= slash;
+                juror_stake.slashed_amount = juror_stake.slashed_amount.checked_add(slash)
+                    .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+                juror_stake.is_active = juror_stake.staked_amount >= JurorStake::MIN_STAKE;
+                slashed_total = slashed_total.checked_add(slash)
+                    .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+            }
+        }
+
+        juror_stake.exit(&crate::ID)?;
+    }
+    jury_case.majority_count = majority_count;
+    jury_case.slashed_pool = slashed_total;
+
+    let Some(median) = median else {
+        // Nobody revealed: the slashed stakes go to the treasury and the
+        // dispute goes back to the arbiter queue
+        if jury_case.slashed_pool > 0 {
+            debit_lamports(
+                &jury_case_info,
+                &ctx.accounts.sol_treasury.to_account_info(),
+                jury_case.slashed_pool,
+            )?;
+            jury_case.slashed_pool = 0;
+        }
+
+        jury_case.status = JuryStatus::Failed;
+        dispute.resolution_mode = ResolutionMode::Arbiter;
+        dispute.status = DisputeStatus::Open;
+        dispute.updated_at = current_time;
+
+        emit!(JuryFailed {
+            order: order.key(),
+            dispute: dispute.key(),
+        });
+
+        return Ok(());
+    };
+
+    let appeal_deadline = current_time.checked_add(ctx.accounts.marketplace.appeal_period)
+        .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+
+    dispute.status = DisputeStatus::PendingSettlement;
+    dispute.resolution = Some(DisputeResolution::from_refund_bps(median));
+    dispute.refund_bps = median;
+    dispute.resolved_by = Some(jury_case.key());
+    dispute.appeal_deadline = appeal_deadline;
+    dispute.updated_at = current_time;
+
+    jury_case.status = JuryStatus::Settled;
+
+    emit!(JurySettled {
+        order: order.key(),
+        dispute: dispute.key(),
+        median_refund_bps: median,
+        appeal_deadline,
+        slashed_pool: jury_case.slashed_pool,
+        majority_count,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct JurySettled {
+    pub order: Pubkey,
+    pub dispute: Pubkey,
+    pub median_refund_bps: u16,
+    pub appeal_deadline: i64,
+    pub slashed_pool: u64,
+    pub majority_count: u8,
+}
+
+#[event]
+pub struct JuryFailed {
+    pub order: Pubkey,
+    pub dispute: Pubkey,
+}
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct SubmitDelivery<'info> {
+    #[account(
+        seeds = [b"marketplace"],
+        bump = marketplace.bump
+    )]
+    pub marketplace: Account<'info, Marketplace>,
+
+    #[account(
+        mut,
+        seeds = [b"order", order.id.to_le_bytes().as_ref()],
+        bump = order.bump,
+        has_one = seller
+    )]
+    pub order: Box<Account<'info, Order>>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", order.key().as_ref()],
+        bump = escrow.bump,
+        has_one = order
+    )]
+    pub escrow: Box<Account<'info, Escrow>>,
+
+    pub seller: Signer<'info>,
+}
+
+pub fn submit_delivery(
+    ctx: Context<SubmitDelivery>,
+    delivery_message: String,
+    delivery_files: Vec<String>,
+) -> Result<()> {
+    require!(
+        delivery_message.len() <= Order::MAX_DELIVERY_MESSAGE_LEN,
+        SolanaGigsError::DeliveryMessageTooLong
+    );
+    require!(
+        delivery_files.len() <= Order::MAX_DELIVERY_FILES,
+        SolanaGigsError::TooManyFiles
+    );
+    for file in &delivery_files {
+        require!(
+            file.len() <= Order::MAX_DELIVERY_FILE_URL_LEN,
+            SolanaGigsError::UrlTooLong
+        );
+    }
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let order = &mut ctx.accounts.order;
+    let escrow = &mut ctx.accounts.escrow;
+
+    require!(order.status == OrderStatus::InProgress, SolanaGigsError::InvalidOrderStatus);
+    // Re-deliveries after a revision request go through submit_revision
+    require!(order.delivered_at.is_none(), SolanaGigsError::InvalidOrderStatus);
+
+    order.status = OrderStatus::Delivered;
+    order.has_pending_extension = false;
+    order.delivery_message = Some(delivery_message);
+    order.delivery_files = delivery_files;
+    order.delivered_at = Some(current_time);
+
+    // The buyer has until the end of the dispute period to accept or dispute the delivery
+    escrow.dispute_deadline = current_time
+        .checked_add(ctx.accounts.marketplace.dispute_period)
+        .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+
+    emit!(OrderDelivered {
+        order: order.key(),
+        seller: order.seller,
+        dispute_deadline: escrow.dispute_deadline,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct OrderDelivered {
+    pub order: Pubkey,
+    pub seller: Pubkey,
+    pub dispute_deadline: i64,
+}
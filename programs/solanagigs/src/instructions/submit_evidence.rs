@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct SubmitEvidence<'info> {
+    #[account(
+        seeds = [b"order", order.id.to_le_bytes().as_ref()],
+        bump = order.bump,
+        constraint = order.is_participant(&submitter.key()) @ SolanaGigsError::Unauthorized
+    )]
+    pub order: Box<Account<'info, Order>>,
+
+    #[account(
+        mut,
+        seeds = [b"dispute", order.key().as_ref()],
+        bump = dispute.bump,
+        has_one = order
+    )]
+    pub dispute: Box<Account<'info, Dispute>>,
+
+    pub submitter: Signer<'info>,
+}
+
+/// Appends a content-hashed evidence entry to the dispute record. Either
+/// party may submit until the evidence window closes.
+pub fn submit_evidence(
+    ctx: Context<SubmitEvidence>,
+    content_hash: [u8; 32],
+    uri: String,
+) -> Result<()> {
+    require!(content_hash != [0u8; 32], SolanaGigsError::InvalidEvidenceHash);
+    require!(
+        !uri.is_empty() && uri.len() <= DisputeEvidence::MAX_URI_LEN,
+        SolanaGigsError::UrlTooLong
+    );
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let dispute = &mut ctx.accounts.dispute;
+    let submitter = ctx.accounts.submitter.key();
+
+    require!(dispute.is_evidence_open(current_time), SolanaGigsError::EvidenceWindowClosed);
+    require!(
+        dispute.evidence_count_by(&submitter) < Dispute::MAX_EVIDENCE_PER_PARTY,
+        SolanaGigsError::EvidenceLimitReached
+    );
+
+    dispute.evidence.push(DisputeEvidence {
+        content_hash,
+        uri: uri.clone(),
+        submitter,
+        submitted_at: current_time,
+    });
+    dispute.updated_at = current_time;
+
+    emit!(EvidenceSubmitted {
+        order: dispute.order,
+        dispute: dispute.key(),
+        submitter,
+        content_hash,
+        uri,
+        entry_index: (dispute.evidence.len() - 1) as u8,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct EvidenceSubmitted {
+    pub order: Pubkey,
+    pub dispute: Pubkey,
+    pub submitter: Pubkey,
+    pub content_hash: [u8; 32],
+    pub uri: String,
+    pub entry_index: u8,
+}
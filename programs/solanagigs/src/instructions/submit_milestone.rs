@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct SubmitMilestone<'info> {
+    #[account(
+        seeds = [b"order", order.id.to_le_bytes().as_ref()],
+        bump = order.bump,
+        has_one = seller
+    )]
+    pub order: Box<Account<'info, Order>>,
+
+    #[account(
+        mut,
+        seeds = [b"milestone", order.key().as_ref(), &[milestone.milestone_id]],
+        bump = milestone.bump,
+        has_one = order
+    )]
+    pub milestone: Box<Account<'info, OrderMilestone>>,
+
+    pub seller: Signer<'info>,
+}
+
+pub fn submit_milestone(ctx: Context<SubmitMilestone>, deliverable_url: String) -> Result<()> {
+    require!(
+        deliverable_url.len() <= OrderMilestone::MAX_DELIVERABLE_URL_LEN,
+        SolanaGigsError::UrlTooLong
+    );
+
+    let order = &ctx.accounts.order;
+    let milestone = &mut ctx.accounts.milestone;
+
+    require!(order.status == OrderStatus::InProgress, SolanaGigsError::InvalidOrderStatus);
+    require!(
+        milestone.status != MilestoneStatus::Approved,
+        SolanaGigsError::MilestoneAlreadyCompleted
+    );
+    require!(milestone.can_be_completed(), SolanaGigsError::InvalidMilestoneDetails);
+
+    // Milestones are delivered strictly in sequence
+    require!(
+        milestone.milestone_id == order.completed_milestones,
+        SolanaGigsError::CannotCompleteMilestoneOutOfOrder
+    );
+
+    let current_time = Clock::get()?.unix_timestamp;
+    milestone.status = MilestoneStatus::Submitted;
+    milestone.deliverable_url = Some(deliverable_url);
+    milestone.completed_at = Some(current_time);
+
+    emit!(MilestoneSubmitted {
+        order: order.key(),
+        milestone: milestone.key(),
+        milestone_id: milestone.milestone_id,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct MilestoneSubmitted {
+    pub order: Pubkey,
+    pub milestone: Pubkey,
+    pub milestone_id: u8,
+    pub timestamp: i64,
+}
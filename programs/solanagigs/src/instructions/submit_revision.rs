@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct SubmitRevision<'info> {
+    #[account(
+        seeds = [b"marketplace"],
+        bump = marketplace.bump
+    )]
+    pub marketplace: Box<Account<'info, Marketplace>>,
+
+    #[account(
+        mut,
+        seeds = [b"order", order.id.to_le_bytes().as_ref()],
+        bump = order.bump,
+        has_one = seller
+    )]
+    pub order: Box<Account<'info, Order>>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", order.key().as_ref()],
+        bump = escrow.bump,
+        has_one = order
+    )]
+    pub escrow: Box<Account<'info, Escrow>>,
+
+    #[account(
+        mut,
+        seeds = [b"revision", order.key().as_ref(), &[order.revision_count]],
+        bump = revision.bump,
+        has_one = order
+    )]
+    pub revision: Box<Account<'info, OrderRevision>>,
+
+    pub seller: Signer<'info>,
+}
+
+pub fn submit_revision(
+    ctx: Context<SubmitRevision>,
+    deliverable_url: String,
+    feedback: Option<String>,
+) -> Result<()> {
+    require!(
+        deliverable_url.len() <= OrderRevision::MAX_DELIVERABLE_URL_LEN,
+        SolanaGigsError::UrlTooLong
+    );
+    if let Some(ref feedback) = feedback {
+        require!(
+            feedback.len() <= OrderRevision::MAX_FEEDBACK_LEN,
+            SolanaGigsError::DeliveryMessageTooLong
+        );
+    }
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let order = &mut ctx.accounts.order;
+    let escrow = &mut ctx.accounts.escrow;
+    let revision = &mut ctx.accounts.revision;
+
+    require!(order.status == OrderStatus::InProgress, SolanaGigsError::InvalidOrderStatus);
+    require!(revision.is_pending(), SolanaGigsError::InvalidOrderModification);
+
+    revision.status = RevisionStatus::Completed;
+    revision.deliverable_url = Some(deliverable_url);
+    revision.feedback = feedback;
+    revision.completed_at = Some(current_time);
+
+    order.status = OrderStatus::Delivered;
+    order.delivered_at = Some(current_time);
+
+    // The buyer gets a fresh dispute window for the revised delivery
+    escrow.dispute_deadline = current_time
+        .checked_add(ctx.accounts.marketplace.dispute_period)
+        .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+
+    emit!(RevisionSubmitted {
+        order: order.key(),
+        revision: revision.key(),
+        revision_number: revision.revision_number,
+        dispute_deadline: escrow.dispute_deadline,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct RevisionSubmitted {
+    pub order: Pubkey,
+    pub revision: Pubkey,
+    pub revision_number: u8,
+    pub dispute_deadline: i64,
+}
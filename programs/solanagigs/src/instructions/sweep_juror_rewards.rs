@@ -0,0 +1,150 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::spl_token::native_mint;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::state::*;
+use crate::errors::*;
+use crate::utils::{debit_lamports, treasury_account, EscrowPayer};
+
+#[derive(Accounts)]
+pub struct SweepJurorRewards<'info> {
+    #[account(
+        seeds = [b"marketplace"],
+        bump = marketplace.bump
+    )]
+    pub marketplace: Box<Account<'info, Marketplace>>,
+
+    #[account(
+        seeds = [b"order", order.id.to_le_bytes().as_ref()],
+        bump = order.bump
+    )]
+    pub order: Box<Account<'info, Order>>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", order.key().as_ref()],
+        bump = escrow.bump,
+        has_one = order
+    )]
+    pub escrow: Box<Account<'info, Escrow>>,
+
+    #[account(
+        mut,
+        seeds = [b"jury", jury_case.dispute.as_ref()],
+        bump = jury_case.bump,
+        has_one = order
+    )]
+    pub jury_case: Box<Account<'info, JuryCase>>,
+
+    #[account(
+        mut,
+        constraint = vault.key() == escrow.vault @ SolanaGigsError::InvalidEscrowState,
+        token::mint = escrow.mint
+    )]
+    pub vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        address = escrow.mint @ SolanaGigsError::InvalidTokenMint
+    )]
+    pub mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury", escrow.mint.as_ref()],
+        bump = treasury.bump
+    )]
+    pub treasury: Box<Account<'info, Treasury>>,
+
+    #[account(
+        mut,
+        address = treasury.vault @ SolanaGigsError::InvalidAccountData
+    )]
+    pub treasury_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// SOL treasury, which takes the unclaimed slashed stakes
+    #[account(
+        mut,
+        seeds = [b"treasury", native_mint::ID.as_ref()],
+        bump = sol_treasury.bump
+    )]
+    pub sol_treasury: Box<Account<'info, Treasury>>,
+
+    #[account(
+        mut,
+        address = order.buyer @ SolanaGigsError::Unauthorized
+    )]
+    pub buyer_wallet: Option<SystemAccount<'info>>,
+
+    pub caller: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Permissionless once the claim deadline has passed. Whatever the majority
+/// jurors left unclaimed goes to the treasury so the escrow can be closed.
+pub fn sweep_juror_rewards<'info>(ctx: Context<'_, '_, '_, 'info, SweepJurorRewards<'info>>) -> Result<()> {
+    ctx.accounts.marketplace.require_funds_unfrozen()?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let escrow = &mut ctx.accounts.escrow;
+    let jury_case = &mut ctx.accounts.jury_case;
+
+    require!(jury_case.status == JuryStatus::Settled, SolanaGigsError::VotingPhaseActive);
+    require!(ctx.accounts.order.status == OrderStatus::Resolved, SolanaGigsError::InvalidOrderStatus);
+    require!(current_time > jury_case.claim_deadline, SolanaGigsError::ClaimDeadlineNotReached);
+    require!(
+        jury_case.claims_made < jury_case.majority_count,
+        SolanaGigsError::JurorRewardUnavailable
+    );
+
+    let unclaimed_fee = escrow.remaining_amount();
+    let payer = EscrowPayer {
+        escrow,
+        vault: &ctx.accounts.vault,
+        mint: &ctx.accounts.mint,
+        token_program: &ctx.accounts.token_program,
+        remaining_accounts: ctx.remaining_accounts,
+    };
+
+    if unclaimed_fee > 0 {
+        let fee_destination = treasury_account(&ctx.accounts.treasury, &ctx.accounts.treasury_vault)?;
+        payer.transfer(fee_destination, unclaimed_fee)?;
+    }
+    // Without a juror fee the vault was already closed by finalize_dispute
+    if jury_case.juror_fee > 0 {
+        payer.close_vault(
+            &ctx.accounts.treasury_vault,
+            ctx.accounts.buyer_wallet.as_ref().map(|buyer| buyer.to_account_info()),
+        )?;
+    }
+
+    escrow.partial_release_amount = escrow.amount;
+
+    let unclaimed_slash = jury_case.slashed_pool;
+    if unclaimed_slash > 0 {
+        debit_lamports(
+            &jury_case.to_account_info(),
+            &ctx.accounts.sol_treasury.to_account_info(),
+            unclaimed_slash,
+        )?;
+        jury_case.slashed_pool = 0;
+    }
+
+    jury_case.status = JuryStatus::Expired;
+
+    emit!(JurorRewardsSwept {
+        dispute: jury_case.dispute,
+        unclaimed_fee,
+        unclaimed_slash,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct JurorRewardsSwept {
+    pub dispute: Pubkey,
+    pub unclaimed_fee: u64,
+    pub unclaimed_slash: u64,
+}
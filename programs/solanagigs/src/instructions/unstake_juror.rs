@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::instructions::register_juror::JurorStakeUpdated;
+
+#[derive(Accounts)]
+pub struct UnstakeJuror<'info> {
+    #[account(
+        seeds = [b"marketplace"],
+        bump = marketplace.bump
+    )]
+    pub marketplace: Box<Account<'info, Marketplace>>,
+
+    #[account(
+        mut,
+        seeds = [b"juror", juror.key().as_ref()],
+        bump = juror_stake.bump,
+        has_one = juror
+    )]
+    pub juror_stake: Account<'info, JurorStake>,
+
+    #[account(mut)]
+    pub juror: Signer<'info>,
+}
+
+pub fn unstake_juror(ctx: Context<UnstakeJuror>, amount: u64) -> Result<()> {
+    ctx.accounts.marketplace.require_funds_unfrozen()?;
+
+    let juror_stake = &mut ctx.accounts.juror_stake;
+
+    require!(juror_stake.active_cases == 0, SolanaGigsError::JurorHasActiveCases);
+    require!(
+        amount > 0 && amount <= juror_stake.staked_amount,
+        SolanaGigsError::InvalidWithdrawalAmount
+    );
+
+    let stake_info = juror_stake.to_account_info();
+    let juror_info = ctx.accounts.juror.to_account_info();
+    **stake_info.try_borrow_mut_lamports()? = stake_info.lamports()
+        .checked_sub(amount)
+        .ok_or(SolanaGigsError::InsufficientFunds)?;
+    **juror_info.try_borrow_mut_lamports()? = juror_info.lamports()
+        .checked_add(amount)
+        .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+
+    juror_stake.staked_amount -= amount;
+    // Jurors below the minimum stay registered but are skipped by panel draws
+    juror_stake.is_active = juror_stake.staked_amount >= JurorStake::MIN_STAKE;
+
+    emit!(JurorStakeUpdated {
+        juror: juror_stake.juror,
+        staked_amount: juror_stake.staked_amount,
+        is_active: juror_stake.is_active,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::instructions::add_accepted_mint::AcceptedMintUpdated;
+
+#[derive(Accounts)]
+pub struct UpdateAcceptedMint<'info> {
+    #[account(
+        seeds = [b"marketplace"],
+        bump = marketplace.bump,
+        has_one = authority
+    )]
+    pub marketplace: Account<'info, Marketplace>,
+
+    #[account(
+        mut,
+        seeds = [b"accepted_mint", accepted_mint.mint.as_ref()],
+        bump = accepted_mint.bump
+    )]
+    pub accepted_mint: Account<'info, AcceptedMint>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn update_accepted_mint(
+    ctx: Context<UpdateAcceptedMint>,
+    min_price: Option<u64>,
+    max_price: Option<u64>,
+    withdrawal_threshold: Option<u64>,
+    is_active: Option<bool>,
+) -> Result<()> {
+    let accepted_mint = &mut ctx.accounts.accepted_mint;
+
+    let min_price = min_price.unwrap_or(accepted_mint.min_price);
+    let max_price = max_price.unwrap_or(accepted_mint.max_price);
+    AcceptedMint::validate_bounds(min_price, max_price)?;
+
+    accepted_mint.min_price = min_price;
+    accepted_mint.max_price = max_price;
+
+    if let Some(withdrawal_threshold) = withdrawal_threshold {
+        accepted_mint.withdrawal_threshold = withdrawal_threshold;
+    }
+
+    // Delisting only blocks new gigs and orders; open escrows settle in their original mint
+    if let Some(is_active) = is_active {
+        accepted_mint.is_active = is_active;
+    }
+
+    emit!(AcceptedMintUpdated {
+        mint: accepted_mint.mint,
+        min_price,
+        max_price,
+        withdrawal_threshold: accepted_mint.withdrawal_threshold,
+        is_active: accepted_mint.is_active,
+    });
+
+    Ok(())
+}
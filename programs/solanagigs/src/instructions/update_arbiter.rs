@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::instructions::register_arbiter::ArbiterUpdated;
+
+#[derive(Accounts)]
+pub struct UpdateArbiter<'info> {
+    #[account(
+        seeds = [b"marketplace"],
+        bump = marketplace.bump
+    )]
+    pub marketplace: Account<'info, Marketplace>,
+
+    #[account(
+        mut,
+        seeds = [b"arbiter", arbiter.index.to_le_bytes().as_ref()],
+        bump = arbiter.bump
+    )]
+    pub arbiter: Account<'info, Arbiter>,
+
+    #[account(address = marketplace.dispute_admin @ SolanaGigsError::AdminPrivilegesRequired)]
+    pub dispute_admin: Signer<'info>,
+}
+
+pub fn update_arbiter(
+    ctx: Context<UpdateArbiter>,
+    categories: Option<Vec<ServiceCategory>>,
+    is_active: Option<bool>,
+    tier: Option<ArbiterTier>,
+) -> Result<()> {
+    let arbiter = &mut ctx.accounts.arbiter;
+
+    if let Some(categories) = categories {
+        require!(
+            categories.len() <= Arbiter::MAX_CATEGORIES,
+            SolanaGigsError::TooManyCategories
+        );
+        arbiter.categories = categories;
+    }
+
+    // Deactivation only stops new assignments; open cases stay with the arbiter
+    if let Some(is_active) = is_active {
+        arbiter.is_active = is_active;
+    }
+
+    if let Some(tier) = tier {
+        arbiter.tier = tier;
+    }
+
+    emit!(ArbiterUpdated {
+        arbiter: arbiter.key(),
+        authority: arbiter.authority,
+        categories: arbiter.categories.clone(),
+        tier: arbiter.tier,
+        is_active: arbiter.is_active,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::instructions::create_category::CategoryUpdated;
+
+#[derive(Accounts)]
+#[instruction(category: ServiceCategory)]
+pub struct UpdateCategory<'info> {
+    #[account(
+        seeds = [b"marketplace"],
+        bump = marketplace.bump
+    )]
+    pub marketplace: Account<'info, Marketplace>,
+
+    #[account(
+        mut,
+        seeds = [b"category".as_ref(), &[category as u8]],
+        bump = category_account.bump
+    )]
+    pub category_account: Account<'info, Category>,
+
+    #[account(address = marketplace.category_curator @ SolanaGigsError::AdminPrivilegesRequired)]
+    pub category_curator: Signer<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct UpdateCategoryParams {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub subcategories: Option<Vec<String>>,
+    pub is_active: Option<bool>,
+}
+
+pub fn update_category(
+    ctx: Context<UpdateCategory>,
+    category: ServiceCategory,
+    params: UpdateCategoryParams,
+) -> Result<()> {
+    let category_account = &mut ctx.accounts.category_account;
+
+    if let Some(name) = params.name {
+        category_account.name = name;
+    }
+
+    if let Some(description) = params.description {
+        category_account.description = description;
+    }
+
+    if let Some(subcategories) = params.subcategories {
+        category_account.subcategories = subcategories;
+    }
+
+    if let Some(is_active) = params.is_active {
+        category_account.is_active = is_active;
+    }
+
+    Category::validate(
+        &category_account.name,
+        &category_account.description,
+        &category_account.subcategories,
+    )?;
+
+    emit!(CategoryUpdated {
+        category,
+        name: category_account.name.clone(),
+        is_active: category_account.is_active,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct UpdateFeeSchedule<'info> {
+    #[account(
+        seeds = [b"marketplace"],
+        bump = marketplace.bump
+    )]
+    pub marketplace: Account<'info, Marketplace>,
+
+    #[account(
+        mut,
+        seeds = [b"fee_schedule"],
+        bump = fee_schedule.bump
+    )]
+    pub fee_schedule: Account<'info, FeeSchedule>,
+
+    #[account(address = marketplace.fee_manager @ SolanaGigsError::AdminPrivilegesRequired)]
+    pub fee_manager: Signer<'info>,
+}
+
+/// Each list, when given, replaces the stored one wholesale.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct UpdateFeeScheduleParams {
+    pub default_fee_bps: Option<u16>,
+    pub category_fees: Option<Vec<CategoryFee>>,
+    pub level_fees: Option<Vec<LevelFee>>,
+    pub volume_tiers: Option<Vec<VolumeTier>>,
+}
+
+pub fn update_fee_schedule(ctx: Context<UpdateFeeSchedule>, params: UpdateFeeScheduleParams) -> Result<()> {
+    let fee_schedule = &mut ctx.accounts.fee_schedule;
+
+    if let Some(default_fee_bps) = params.default_fee_bps {
+        fee_schedule.default_fee_bps = default_fee_bps;
+    }
+
+    if let Some(category_fees) = params.category_fees {
+        fee_schedule.category_fees = category_fees;
+    }
+
+    if let Some(level_fees) = params.level_fees {
+        fee_schedule.level_fees = level_fees;
+    }
+
+    if let Some(volume_tiers) = params.volume_tiers {
+        fee_schedule.volume_tiers = volume_tiers;
+    }
+
+    fee_schedule.validate()?;
+    fee_schedule.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(FeeScheduleUpdated {
+        fee_schedule: fee_schedule.key(),
+        default_fee_bps: fee_schedule.default_fee_bps,
+        category_fees: fee_schedule.category_fees.clone(),
+        level_fees: fee_schedule.level_fees.clone(),
+        volume_tiers: fee_schedule.volume_tiers.clone(),
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct FeeScheduleUpdated {
+    pub fee_schedule: Pubkey,
+    pub default_fee_bps: u16,
+    pub category_fees: Vec<CategoryFee>,
+    pub level_fees: Vec<LevelFee>,
+    pub volume_tiers: Vec<VolumeTier>,
+}
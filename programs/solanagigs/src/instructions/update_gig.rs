@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct UpdateGig<'info> {
+    #[account(
+        mut,
+        seeds = [b"gig", gig.id.to_le_bytes().as_ref()],
+        bump = gig.bump,
+        has_one = seller
+    )]
+    pub gig: Box<Account<'info, Gig>>,
+
+    #[account(
+        seeds = [b"accepted_mint", gig.payment_mint.as_ref()],
+        bump = accepted_mint.bump
+    )]
+    pub accepted_mint: Box<Account<'info, AcceptedMint>>,
+
+    pub seller: Signer<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct UpdateGigParams {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub category: Option<ServiceCategory>,
+    pub subcategory: Option<String>,
+    pub tags: Option<Vec<String>>,
+    pub images: Option<Vec<String>>,
+    pub requirements: Option<String>,
+    pub price: Option<u64>,
+    pub delivery_time: Option<u32>,
+    pub revisions: Option<u8>,
+    pub packages: Option<Vec<GigPackage>>,
+    pub extras: Option<Vec<GigExtra>>,
+    pub is_active: Option<bool>,
+}
+
+pub fn update_gig(ctx: Context<UpdateGig>, params: UpdateGigParams) -> Result<()> {
+    let gig = &mut ctx.accounts.gig;
+
+    if let Some(title) = params.title {
+        gig.title = title;
+    }
+
+    if let Some(description) = params.description {
+        gig.description = description;
+    }
+
+    if let Some(category) = params.category {
+        gig.category = category;
+    }
+
+    if let Some(subcategory) = params.subcategory {
+        gig.subcategory = subcategory;
+    }
+
+    if let Some(tags) = params.tags {
+        gig.tags = tags;
+    }
+
+    if let Some(images) = params.images {
+        gig.images = images;
+    }
+
+    if let Some(requirements) = params.requirements {
+        gig.requirements = requirements;
+    }
+
+    if let Some(price) = params.price {
+        gig.price = price;
+    }
+
+    if let Some(delivery_time) = params.delivery_time {
+        gig.delivery_time = delivery_time;
+    }
+
+    if let Some(revisions) = params.revisions {
+        gig.revisions = revisions;
+    }
+
+    if let Some(packages) = params.packages {
+        gig.packages = packages;
+    }
+
+    if let Some(extras) = params.extras {
+        gig.extras = extras;
+    }
+
+    if let Some(is_active) = params.is_active {
+        gig.is_active = is_active;
+    }
+
+    gig.sync_base_package();
+    gig.validate()?;
+    gig.validate_prices(&ctx.accounts.accepted_mint)?;
+    gig.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(GigUpdated {
+        gig: gig.key(),
+        seller: gig.seller,
+        price: gig.price,
+        is_active: gig.is_active,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct GigUpdated {
+    pub gig: Pubkey,
+    pub seller: Pubkey,
+    pub price: u64,
+    pub is_active: bool,
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::instructions::initialize_governance::GovernanceUpdated;
+
+#[derive(Accounts)]
+pub struct UpdateGovernance<'info> {
+    #[account(
+        mut,
+        seeds = [b"governance"],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    /// Only an executed proposal can sign as this PDA
+    #[account(
+        seeds = [b"governance_signer"],
+        bump = governance.signer_bump
+    )]
+    pub governance_signer: Signer<'info>,
+}
+
+/// Replaces the signer set, threshold and delay. Proposals created before
+/// the change can no longer be approved or executed.
+pub fn update_governance(
+    ctx: Context<UpdateGovernance>,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+    min_delay: i64,
+) -> Result<()> {
+    Governance::validate_config(&signers, threshold, min_delay)?;
+
+    let governance = &mut ctx.accounts.governance;
+    governance.signers = signers;
+    governance.threshold = threshold;
+    governance.min_delay = min_delay;
+    governance.config_version = governance.config_version.wrapping_add(1);
+
+    emit!(GovernanceUpdated {
+        governance: governance.key(),
+        signers: governance.signers.clone(),
+        threshold,
+        min_delay,
+        config_version: governance.config_version,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct UpdateMarketplace<'info> {
+    #[account(
+        mut,
+        seeds = [b"marketplace"],
+        bump = marketplace.bump
+    )]
+    pub marketplace: Account<'info, Marketplace>,
+
+    pub admin: Signer<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct UpdateMarketplaceParams {
+    pub dispute_period: Option<i64>,
+    pub auto_release_bounty: Option<u16>,
+    pub referral_share: Option<u16>,
+    pub appeal_period: Option<i64>,
+    pub appeal_bond: Option<u64>,
+    pub response_period: Option<i64>,
+    pub arbiter_period: Option<i64>,
+    pub retention_period: Option<i64>,
+}
+
+pub fn update_marketplace(ctx: Context<UpdateMarketplace>, params: UpdateMarketplaceParams) -> Result<()> {
+    let admin = ctx.accounts.admin.key();
+    let marketplace = &mut ctx.accounts.marketplace;
+
+    // Each setting is gated on the role that administers its area
+    if params.auto_release_bounty.is_some() || params.referral_share.is_some() {
+        marketplace.require_role(AdminRole::FeeManager, &admin)?;
+    }
+    if params.dispute_period.is_some()
+        || params.appeal_period.is_some()
+        || params.appeal_bond.is_some()
+        || params.response_period.is_some()
+        || params.arbiter_period.is_some()
+    {
+        marketplace.require_role(AdminRole::DisputeAdmin, &admin)?;
+    }
+    if params.retention_period.is_some() {
+        require_keys_eq!(admin, marketplace.authority, SolanaGigsError::AdminPrivilegesRequired);
+    }
+
+    if let Some(dispute_period) = params.dispute_period {
+        require!(dispute_period > 0, SolanaGigsError::InvalidTimestamp);
+        marketplace.dispute_period = dispute_period;
+    }
+
+    if let Some(auto_release_bounty) = params.auto_release_bounty {
+        require!(
+            auto_release_bounty <= Marketplace::MAX_AUTO_RELEASE_BOUNTY,
+            SolanaGigsError::InvalidFeeStructure
+        );
+        marketplace.auto_release_bounty = auto_release_bounty;
+    }
+
+    if let Some(referral_share) = params.referral_share {
+        require!(
+            referral_share <= Marketplace::MAX_REFERRAL_SHARE,
+            SolanaGigsError::InvalidFeeStructure
+        );
+        marketplace.referral_share = referral_share;
+    }
+
+    // A zero appeal period lets resolutions settle as soon as they are made
+    if let Some(appeal_period) = params.appeal_period {
+        require!(appeal_period >= 0, SolanaGigsError::InvalidTimestamp);
+        marketplace.appeal_period = appeal_period;
+    }
+
+    if let Some(appeal_bond) = params.appeal_bond {
+        marketplace.appeal_bond = appeal_bond;
+    }
+
+    if let Some(response_period) = params.response_period {
+        require!(response_period > 0, SolanaGigsError::InvalidTimestamp);
+        marketplace.response_period = response_period;
+    }
+
+    if let Some(arbiter_period) = params.arbiter_period {
+        require!(arbiter_period > 0, SolanaGigsError::InvalidTimestamp);
+        marketplace.arbiter_period = arbiter_period;
+    }
+
+    if let Some(retention_period) = params.retention_period {
+        require!(retention_period >= 0, SolanaGigsError::InvalidTimestamp);
+        marketplace.retention_period = retention_period;
+    }
+
+    emit!(MarketplaceUpdated {
+        marketplace: marketplace.key(),
+        dispute_period: marketplace.dispute_period,
+        auto_release_bounty: marketplace.auto_release_bounty,
+        referral_share: marketplace.referral_share,
+        appeal_period: marketplace.appeal_period,
+        appeal_bond: marketplace.appeal_bond,
+        response_period: marketplace.response_period,
+        arbiter_period: marketplace.arbiter_period,
+        retention_period: marketplace.retention_period,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct MarketplaceUpdated {
+    pub marketplace: Pubkey,
+    pub dispute_period: i64,
+    pub auto_release_bounty: u16,
+    pub referral_share: u16,
+    pub appeal_period: i64,
+    pub appeal_bond: u64,
+    pub response_period: i64,
+    pub arbiter_period: i64,
+    pub retention_period: i64,
+}
@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct UpdateUserProfile<'info> {
+    #[account(
+        mut,
+        seeds = [b"user", authority.key().as_ref()],
+        bump = user_profile.bump,
+        has_one = authority
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct UpdateUserProfileParams {
+    pub username: Option<String>,
+    pub bio: Option<String>,
+    pub profile_image_url: Option<String>,
+    pub skills: Option<Vec<String>>,
+    pub hourly_rate: Option<u64>,
+    pub languages: Option<Vec<String>>,
+    pub location: Option<String>,
+    pub portfolio_items: Option<Vec<PortfolioItem>>,
+    pub social_links: Option<Vec<SocialLink>>,
+    pub preferred_categories: Option<Vec<String>>,
+    pub availability_status: Option<AvailabilityStatus>,
+    pub response_time_hours: Option<u32>,
+    pub timezone: Option<String>,
+}
+
+pub fn update_user_profile(
+    ctx: Context<UpdateUserProfile>,
+    params: UpdateUserProfileParams,
+) -> Result<()> {
+    let user_profile = &mut ctx.accounts.user_profile;
+
+    if let Some(username) = params.username {
+        user_profile.username = username;
+    }
+
+    if let Some(bio) = params.bio {
+        user_profile.bio = bio;
+    }
+
+    if let Some(profile_image_url) = params.profile_image_url {
+        user_profile.profile_image_url = profile_image_url;
+    }
+
+    if let Some(skills) = params.skills {
+        user_profile.skills = skills;
+    }
+
+    if let Some(hourly_rate) = params.hourly_rate {
+        user_profile.hourly_rate = hourly_rate;
+    }
+
+    if let Some(languages) = params.languages {
+        user_profile.languages = languages;
+    }
+
+    if let Some(location) = params.location {
+        user_profile.location = location;
+    }
+
+    if let Some(portfolio_items) = params.portfolio_items {
+        user_profile.portfolio_items = portfolio_items;
+    }
+
+    if let Some(social_links) = params.social_links {
+        user_profile.social_links = social_links;
+    }
+
+    if let Some(preferred_categories) = params.preferred_categories {
+        user_profile.preferred_categories = preferred_categories;
+    }
+
+    if let Some(availability_status) = params.availability_status {
+        user_profile.availability_status = availability_status;
+    }
+
+    if let Some(response_time_hours) = params.response_time_hours {
+        user_profile.response_time_hours = response_time_hours;
+    }
+
+    if let Some(timezone) = params.timezone {
+        user_profile.timezone = timezone;
+    }
+
+    user_profile.validate()?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    user_profile.updated_at = current_time;
+    user_profile.last_active = current_time;
+
+    Ok(())
+}
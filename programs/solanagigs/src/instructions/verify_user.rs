@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct VerifyUser<'info> {
+    #[account(
+        seeds = [b"marketplace"],
+        bump = marketplace.bump
+    )]
+    pub marketplace: Account<'info, Marketplace>,
+
+    #[account(
+        mut,
+        seeds = [b"user", user_profile.authority.as_ref()],
+        bump = user_profile.bump
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    #[account(address = marketplace.verifier @ SolanaGigsError::AdminPrivilegesRequired)]
+    pub verifier: Signer<'info>,
+}
+
+pub fn verify_user(ctx: Context<VerifyUser>, is_verified: bool) -> Result<()> {
+    let user_profile = &mut ctx.accounts.user_profile;
+    user_profile.is_verified = is_verified;
+
+    emit!(UserVerificationUpdated {
+        user: user_profile.authority,
+        is_verified,
+        verifier: ctx.accounts.verifier.key(),
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct UserVerificationUpdated {
+    pub user: Pubkey,
+    pub is_verified: bool,
+    pub verifier: Pubkey,
+}
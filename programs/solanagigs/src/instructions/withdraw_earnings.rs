@@ -0,0 +1,160 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::state::*;
+use crate::errors::*;
+use crate::utils::{payout_account, EarningsPayer};
+
+#[derive(Accounts)]
+pub struct WithdrawEarnings<'info> {
+    #[account(
+        seeds = [b"marketplace"],
+        bump = marketplace.bump
+    )]
+    pub marketplace: Box<Account<'info, Marketplace>>,
+
+    #[account(
+        mut,
+        seeds = [b"earnings", seller.key().as_ref(), earnings.mint.as_ref()],
+        bump = earnings.bump
+    )]
+    pub earnings: Box<Account<'info, UserEarnings>>,
+
+    #[account(
+        seeds = [b"accepted_mint", earnings.mint.as_ref()],
+        bump = accepted_mint.bump
+    )]
+    pub accepted_mint: Box<Account<'info, AcceptedMint>>,
+
+    #[account(
+        mut,
+        constraint = earnings_vault.key() == earnings.vault @ SolanaGigsError::InvalidAccountData
+    )]
+    pub earnings_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(address = earnings.mint @ SolanaGigsError::InvalidTokenMint)]
+    pub mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        mut,
+        token::mint = earnings.mint
+    )]
+    pub destination_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(mut)]
+    pub destination_wallet: Option<SystemAccount<'info>>,
+
+    #[account(
+        init,
+        payer = seller,
+        space = WithdrawalRequest::LEN,
+        seeds = [b"withdrawal", earnings.key().as_ref(), earnings.withdrawal_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub withdrawal_request: Option<Box<Account<'info, WithdrawalRequest>>>,
+
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Withdraws part or all of the available earnings to any destination of the
+/// right mint. The mint's withdrawal threshold caps what can be paid out
+/// without approval per `UserEarnings::WITHDRAWAL_PERIOD`; a withdrawal that
+/// would go over it is parked in a withdrawal request until an admin decides.
+pub fn withdraw_earnings<'info>(
+    ctx: Context<'_, '_, '_, 'info, WithdrawEarnings<'info>>,
+    amount: u64,
+) -> Result<()> {
+    ctx.accounts.marketplace.require_funds_unfrozen()?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let threshold = ctx.accounts.accepted_mint.withdrawal_threshold;
+    let earnings = &mut ctx.accounts.earnings;
+
+    let destination = payout_account(
+        earnings.is_native,
+        &ctx.accounts.destination_token_account,
+        &ctx.accounts.destination_wallet,
+    )?;
+
+    earnings.debit_available(amount)?;
+    earnings.last_updated = current_time;
+    let period_total = earnings.period_total(amount, current_time)?;
+
+    if threshold > 0 && period_total > threshold {
+        let withdrawal_request = ctx.accounts.withdrawal_request.as_mut()
+            .ok_or(SolanaGigsError::WithdrawalApprovalRequired)?;
+
+        withdrawal_request.id = earnings.withdrawal_count;
+        withdrawal_request.user = earnings.user;
+        withdrawal_request.earnings = earnings.key();
+        withdrawal_request.amount = amount;
+        withdrawal_request.destination = destination.key();
+        withdrawal_request.status = WithdrawalStatus::Pending;
+        withdrawal_request.processed_by = None;
+        withdrawal_request.created_at = current_time;
+        withdrawal_request.processed_at = None;
+        withdrawal_request.bump = ctx.bumps.withdrawal_request;
+
+        earnings.pending_balance = earnings.pending_balance.checked_add(amount)
+            .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+        earnings.withdrawal_count = earnings.withdrawal_count.checked_add(1)
+            .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+
+        emit!(WithdrawalRequested {
+            earnings: earnings.key(),
+            withdrawal_request: withdrawal_request.key(),
+            user: earnings.user,
+            amount,
+            destination: withdrawal_request.destination,
+        });
+
+        return Ok(());
+    }
+
+    require!(ctx.accounts.withdrawal_request.is_none(), SolanaGigsError::InvalidAccountData);
+
+    let payer = EarningsPayer {
+        earnings,
+        vault: &ctx.accounts.earnings_vault,
+        mint: &ctx.accounts.mint,
+        token_program: &ctx.accounts.token_program,
+        remaining_accounts: ctx.remaining_accounts,
+    };
+    payer.transfer(destination.clone(), amount)?;
+
+    earnings.period_withdrawn = period_total;
+    earnings.total_withdrawn = earnings.total_withdrawn.checked_add(amount)
+        .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+
+    emit!(EarningsWithdrawn {
+        earnings: earnings.key(),
+        user: earnings.user,
+        amount,
+        destination: destination.key(),
+        approved_by: None,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct WithdrawalRequested {
+    pub earnings: Pubkey,
+    pub withdrawal_request: Pubkey,
+    pub user: Pubkey,
+    pub amount: u64,
+    pub destination: Pubkey,
+}
+
+#[event]
+pub struct EarningsWithdrawn {
+    pub earnings: Pubkey,
+    pub user: Pubkey,
+    pub amount: u64,
+    pub destination: Pubkey,
+    pub approved_by: Option<Pubkey>,
+}
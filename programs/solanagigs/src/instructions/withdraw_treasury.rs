@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::state::*;
+use crate::errors::*;
+use crate::utils::{debit_lamports, payout_account, transfer_checked};
+
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    #[account(
+        seeds = [b"marketplace"],
+        bump = marketplace.bump,
+        has_one = authority
+    )]
+    pub marketplace: Box<Account<'info, Marketplace>>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury", treasury.mint.as_ref()],
+        bump = treasury.bump
+    )]
+    pub treasury: Box<Account<'info, Treasury>>,
+
+    #[account(
+        mut,
+        address = treasury.vault @ SolanaGigsError::InvalidAccountData
+    )]
+    pub treasury_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(address = treasury.mint @ SolanaGigsError::InvalidTokenMint)]
+    pub mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        mut,
+        token::mint = treasury.mint
+    )]
+    pub destination_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(mut)]
+    pub destination_wallet: Option<SystemAccount<'info>>,
+
+    pub authority: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Moves collected fees out of a mint's treasury. Only the marketplace
+/// authority can sign for it; SOL treasuries always keep their rent.
+pub fn withdraw_treasury<'info>(
+    ctx: Context<'_, '_, '_, 'info, WithdrawTreasury<'info>>,
+    amount: u64,
+) -> Result<()> {
+    ctx.accounts.marketplace.require_funds_unfrozen()?;
+
+    require!(amount > 0, SolanaGigsError::InvalidWithdrawalAmount);
+
+    let treasury = &mut ctx.accounts.treasury;
+    let destination = payout_account(
+        treasury.is_native,
+        &ctx.accounts.destination_token_account,
+        &ctx.accounts.destination_wallet,
+    )?;
+
+    if treasury.is_native {
+        let treasury_info = treasury.to_account_info();
+        let available = treasury_info
+            .lamports()
+            .saturating_sub(Rent::get()?.minimum_balance(treasury_info.data_len()));
+        require!(amount <= available, SolanaGigsError::TreasuryInsufficientFunds);
+
+        debit_lamports(&treasury_info, &destination, amount)?;
+    } else {
+        let treasury_vault = ctx.accounts.treasury_vault.as_ref()
+            .ok_or(SolanaGigsError::TokenAccountNotFound)?;
+        let mint = ctx.accounts.mint.as_ref().ok_or(SolanaGigsError::InvalidTokenMint)?;
+        require!(amount <= treasury_vault.amount, SolanaGigsError::TreasuryInsufficientFunds);
+
+        let seeds = &[
+            b"treasury",
+            treasury.mint.as_ref(),
+            &[treasury.bump],
+        ];
+        let signer = &[&seeds[..]];
+
+        transfer_checked(
+            &ctx.accounts.token_program,
+            treasury_vault.to_account_info(),
+            mint,
+            destination.clone(),
+            treasury.to_account_info(),
+            ctx.remaining_accounts,
+            amount,
+            signer,
+        )?;
+    }
+
+    treasury.total_withdrawn = treasury.total_withdrawn.checked_add(amount)
+        .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+    treasury.withdrawal_count = treasury.withdrawal_count.checked_add(1)
+        .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+
+    emit!(TreasuryWithdrawn {
+        treasury: treasury.key(),
+        mint: treasury.mint,
+        amount,
+        destination: destination.key(),
+        authority: ctx.accounts.authority.key(),
+        total_withdrawn: treasury.total_withdrawn,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct TreasuryWithdrawn {
+    pub treasury: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub destination: Pubkey,
+    pub authority: Pubkey,
+    pub total_withdrawn: u64,
+}
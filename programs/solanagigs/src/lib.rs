@@ -1,6 +1,12 @@
-```rust
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+pub mod errors;
+pub mod instructions;
+pub mod state;
+pub mod utils;
+
+use instructions::*;
+use state::*;
 
 declare_id!("GigsProgram11111111111111111111111111111111");
 
@@ -10,160 +16,182 @@ pub mod solanagigs {
 
     pub fn initialize_marketplace(
         ctx: Context<InitializeMarketplace>,
-        fee_percentage: u16,
+        default_fee_bps: u16,
+        dispute_period: Option<i64>,
+    ) -> Result<()> {
+        instructions::initialize_marketplace::initialize_marketplace(ctx, default_fee_bps, dispute_period)
+    }
+
+    pub fn update_marketplace(
+        ctx: Context<UpdateMarketplace>,
+        params: UpdateMarketplaceParams,
     ) -> Result<()> {
-        let marketplace = &mut ctx.accounts.marketplace;
-        marketplace.authority = ctx.accounts.authority.key();
-        marketplace.fee_percentage = fee_percentage;
-        marketplace.total_gigs = 0;
-        marketplace.total_orders = 0;
-        marketplace.total_volume = 0;
-        marketplace.bump = ctx.bumps.marketplace;
-        Ok(())
-    }
-
-    pub fn create_gig(
-        ctx: Context<CreateGig>,
-        title: String,
+        instructions::update_marketplace::update_marketplace(ctx, params)
+    }
+
+    pub fn update_fee_schedule(
+        ctx: Context<UpdateFeeSchedule>,
+        params: UpdateFeeScheduleParams,
+    ) -> Result<()> {
+        instructions::update_fee_schedule::update_fee_schedule(ctx, params)
+    }
+
+    pub fn nominate_authority(ctx: Context<NominateAuthority>, new_authority: Option<Pubkey>) -> Result<()> {
+        instructions::nominate_authority::nominate_authority(ctx, new_authority)
+    }
+
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        instructions::accept_authority::accept_authority(ctx)
+    }
+
+    pub fn assign_role(ctx: Context<AssignRole>, role: AdminRole, holder: Pubkey) -> Result<()> {
+        instructions::assign_role::assign_role(ctx, role, holder)
+    }
+
+    pub fn pause(ctx: Context<PauseMarketplace>, emergency: bool) -> Result<()> {
+        instructions::pause_marketplace::pause(ctx, emergency)
+    }
+
+    pub fn unpause(ctx: Context<PauseMarketplace>) -> Result<()> {
+        instructions::pause_marketplace::unpause(ctx)
+    }
+
+    pub fn verify_user(ctx: Context<VerifyUser>, is_verified: bool) -> Result<()> {
+        instructions::verify_user::verify_user(ctx, is_verified)
+    }
+
+    pub fn create_category(
+        ctx: Context<CreateCategory>,
+        category: ServiceCategory,
+        name: String,
         description: String,
-        price: u64,
-        delivery_time: u32,
-        category: String,
-        tags: Vec<String>,
+        subcategories: Vec<String>,
     ) -> Result<()> {
-        require!(title.len() <= 100, ErrorCode::TitleTooLong);
-        require!(description.len() <= 1000, ErrorCode::DescriptionTooLong);
-        require!(price > 0, ErrorCode::InvalidPrice);
-        require!(delivery_time > 0, ErrorCode::InvalidDeliveryTime);
-        require!(tags.len() <= 10, ErrorCode::TooManyTags);
-
-        let gig = &mut ctx.accounts.gig;
-        let marketplace = &mut ctx.accounts.marketplace;
-
-        gig.seller = ctx.accounts.seller.key();
-        gig.title = title;
-        gig.description = description;
-        gig.price = price;
-        gig.delivery_time = delivery_time;
-        gig.category = category;
-        gig.tags = tags;
-        gig.is_active = true;
-        gig.total_orders = 0;
-        gig.rating = 0;
-        gig.rating_count = 0;
-        gig.created_at = Clock::get()?.unix_timestamp;
-        gig.bump = ctx.bumps.gig;
-
-        marketplace.total_gigs += 1;
-
-        emit!(GigCreated {
-            gig: gig.key(),
-            seller: gig.seller,
-            title: gig.title.clone(),
-            price: gig.price,
-        });
-
-        Ok(())
-    }
-
-    pub fn update_gig(
-        ctx: Context<UpdateGig>,
-        title: Option<String>,
-        description: Option<String>,
-        price: Option<u64>,
-        delivery_time: Option<u32>,
-        is_active: Option<bool>,
+        instructions::create_category::create_category(ctx, category, name, description, subcategories)
+    }
+
+    pub fn update_category(
+        ctx: Context<UpdateCategory>,
+        category: ServiceCategory,
+        params: UpdateCategoryParams,
     ) -> Result<()> {
-        let gig = &mut ctx.accounts.gig;
+        instructions::update_category::update_category(ctx, category, params)
+    }
 
-        if let Some(new_title) = title {
-            require!(new_title.len() <= 100, ErrorCode::TitleTooLong);
-            gig.title = new_title;
-        }
+    pub fn withdraw_treasury<'info>(
+        ctx: Context<'_, '_, '_, 'info, WithdrawTreasury<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::withdraw_treasury::withdraw_treasury(ctx, amount)
+    }
 
-        if let Some(new_description) = description {
-            require!(new_description.len() <= 1000, ErrorCode::DescriptionTooLong);
-            gig.description = new_description;
-        }
+    pub fn initialize_governance(
+        ctx: Context<InitializeGovernance>,
+        signers: Vec<Pubkey>,
+        threshold: u8,
+        min_delay: i64,
+    ) -> Result<()> {
+        instructions::initialize_governance::initialize_governance(ctx, signers, threshold, min_delay)
+    }
 
-        if let Some(new_price) = price {
-            require!(new_price > 0, ErrorCode::InvalidPrice);
-            gig.price = new_price;
-        }
+    pub fn update_governance(
+        ctx: Context<UpdateGovernance>,
+        signers: Vec<Pubkey>,
+        threshold: u8,
+        min_delay: i64,
+    ) -> Result<()> {
+        instructions::update_governance::update_governance(ctx, signers, threshold, min_delay)
+    }
 
-        if let Some(new_delivery_time) = delivery_time {
-            require!(new_delivery_time > 0, ErrorCode::InvalidDeliveryTime);
-            gig.delivery_time = new_delivery_time;
-        }
+    pub fn create_proposal(
+        ctx: Context<CreateProposal>,
+        accounts: Vec<ProposalAccount>,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        instructions::create_proposal::create_proposal(ctx, accounts, data)
+    }
 
-        if let Some(active) = is_active {
-            gig.is_active = active;
-        }
+    pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+        instructions::approve_proposal::approve_proposal(ctx)
+    }
 
-        Ok(())
+    pub fn cancel_proposal(ctx: Context<CancelProposal>) -> Result<()> {
+        instructions::cancel_proposal::cancel_proposal(ctx)
     }
 
-    pub fn create_order(
-        ctx: Context<CreateOrder>,
-        requirements: String,
+    pub fn execute_proposal<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteProposal<'info>>,
     ) -> Result<()> {
-        require!(requirements.len() <= 500, ErrorCode::RequirementsTooLong);
-
-        let order = &mut ctx.accounts.order;
-        let gig = &mut ctx.accounts.gig;
-        let marketplace = &mut ctx.accounts.marketplace;
-
-        require!(gig.is_active, ErrorCode::GigNotActive);
-
-        order.buyer = ctx.accounts.buyer.key();
-        order.seller = gig.seller;
-        order.gig = gig.key();
-        order.amount = gig.price;
-        order.requirements = requirements;
-        order.status = OrderStatus::Pending;
-        order.created_at = Clock::get()?.unix_timestamp;
-        order.delivery_deadline = Clock::get()?.unix_timestamp + (gig.delivery_time as i64 * 86400);
-        order.bump = ctx.bumps.order;
-
-        // Transfer payment to escrow
-        let cpi_accounts = Transfer {
-            from: ctx.accounts.buyer_token_account.to_account_info(),
-            to: ctx.accounts.escrow_token_account.to_account_info(),
-            authority: ctx.accounts.buyer.to_account_info(),
-        };
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-        token::transfer(cpi_ctx, order.amount)?;
-
-        gig.total_orders += 1;
-        marketplace.total_orders += 1;
-        marketplace.total_volume += order.amount;
-
-        emit!(OrderCreated {
-            order: order.key(),
-            buyer: order.buyer,
-            seller: order.seller,
-            gig: order.gig,
-            amount: order.amount,
-        });
-
-        Ok(())
+        instructions::execute_proposal::execute_proposal(ctx)
     }
 
-    pub fn accept_order(ctx: Context<AcceptOrder>) -> Result<()> {
-        let order = &mut ctx.accounts.order;
-        
-        require!(order.status == OrderStatus::Pending, ErrorCode::InvalidOrderStatus);
-        
-        order.status = OrderStatus::InProgress;
-        order.accepted_at = Some(Clock::get()?.unix_timestamp);
+    pub fn add_accepted_mint(
+        ctx: Context<AddAcceptedMint>,
+        min_price: u64,
+        max_price: u64,
+        withdrawal_threshold: u64,
+    ) -> Result<()> {
+        instructions::add_accepted_mint::add_accepted_mint(ctx, min_price, max_price, withdrawal_threshold)
+    }
+
+    pub fn update_accepted_mint(
+        ctx: Context<UpdateAcceptedMint>,
+        min_price: Option<u64>,
+        max_price: Option<u64>,
+        withdrawal_threshold: Option<u64>,
+        is_active: Option<bool>,
+    ) -> Result<()> {
+        instructions::update_accepted_mint::update_accepted_mint(
+            ctx,
+            min_price,
+            max_price,
+            withdrawal_threshold,
+            is_active,
+        )
+    }
+
+    pub fn initialize_user(
+        ctx: Context<InitializeUser>,
+        username: String,
+        bio: String,
+        profile_image_url: String,
+        skills: Vec<String>,
+        hourly_rate: u64,
+    ) -> Result<()> {
+        instructions::initialize_user::initialize_user(ctx, username, bio, profile_image_url, skills, hourly_rate)
+    }
+
+    pub fn update_user_profile(
+        ctx: Context<UpdateUserProfile>,
+        params: UpdateUserProfileParams,
+    ) -> Result<()> {
+        instructions::update_user_profile::update_user_profile(ctx, params)
+    }
+
+    pub fn create_gig(ctx: Context<CreateGig>, params: CreateGigParams) -> Result<()> {
+        instructions::create_gig::create_gig(ctx, params)
+    }
+
+    pub fn update_gig(ctx: Context<UpdateGig>, params: UpdateGigParams) -> Result<()> {
+        instructions::update_gig::update_gig(ctx, params)
+    }
+
+    pub fn delete_gig(ctx: Context<DeleteGig>) -> Result<()> {
+        instructions::delete_gig::delete_gig(ctx)
+    }
 
-        emit!(OrderAccepted {
-            order: order.key(),
-            seller: order.seller,
-        });
+    pub fn create_order<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateOrder<'info>>,
+        package_index: u8,
+        extras: Vec<u8>,
+        requirements: String,
+        milestones: Vec<MilestoneParams>,
+    ) -> Result<()> {
+        instructions::create_order::create_order(ctx, package_index, extras, requirements, milestones)
+    }
 
-        Ok(())
+    pub fn accept_order(ctx: Context<AcceptOrder>) -> Result<()> {
+        instructions::accept_order::accept_order(ctx)
     }
 
     pub fn submit_delivery(
@@ -171,232 +199,226 @@ pub mod solanagigs {
         delivery_message: String,
         delivery_files: Vec<String>,
     ) -> Result<()> {
-        require!(delivery_message.len() <= 1000, ErrorCode::DeliveryMessageTooLong);
-        require!(delivery_files.len() <= 10, ErrorCode::TooManyFiles);
+        instructions::submit_delivery::submit_delivery(ctx, delivery_message, delivery_files)
+    }
+
+    pub fn request_revision(
+        ctx: Context<RequestRevision>,
+        reason: String,
+        description: String,
+        extension_days: Option<u32>,
+    ) -> Result<()> {
+        instructions::request_revision::request_revision(ctx, reason, description, extension_days)
+    }
 
-        let order = &mut ctx.accounts.order;
-        
-        require!(order.status == OrderStatus::InProgress, ErrorCode::InvalidOrderStatus);
-        
-        order.status = OrderStatus::Delivered;
-        order.delivery_message = Some(delivery_message);
-        order.delivery_files = delivery_files;
-        order.delivered_at = Some(Clock::get()?.unix_timestamp);
+    pub fn submit_revision(
+        ctx: Context<SubmitRevision>,
+        deliverable_url: String,
+        feedback: Option<String>,
+    ) -> Result<()> {
+        instructions::submit_revision::submit_revision(ctx, deliverable_url, feedback)
+    }
 
-        emit!(OrderDelivered {
-            order: order.key(),
-            seller: order.seller,
-        });
+    pub fn request_extension(
+        ctx: Context<RequestExtension>,
+        new_deadline: i64,
+        price_concession: u64,
+        reason: String,
+    ) -> Result<()> {
+        instructions::request_extension::request_extension(ctx, new_deadline, price_concession, reason)
+    }
 
-        Ok(())
+    pub fn respond_extension<'info>(
+        ctx: Context<'_, '_, '_, 'info, RespondExtension<'info>>,
+        accept: bool,
+    ) -> Result<()> {
+        instructions::respond_extension::respond_extension(ctx, accept)
     }
 
-    pub fn complete_order(
-        ctx: Context<CompleteOrder>,
-        rating: u8,
-        review: String,
+    pub fn complete_order<'info>(ctx: Context<'_, '_, '_, 'info, CompleteOrder<'info>>) -> Result<()> {
+        instructions::complete_order::complete_order(ctx)
+    }
+
+    pub fn auto_release<'info>(ctx: Context<'_, '_, '_, 'info, AutoRelease<'info>>) -> Result<()> {
+        instructions::auto_release::auto_release(ctx)
+    }
+
+    pub fn cancel_order<'info>(ctx: Context<'_, '_, '_, 'info, CancelOrder<'info>>) -> Result<()> {
+        instructions::cancel_order::cancel_order(ctx)
+    }
+
+    pub fn propose_cancellation(
+        ctx: Context<ProposeCancellation>,
+        refund_bps: u16,
+        reason: String,
     ) -> Result<()> {
-        require!(rating >= 1 && rating <= 5, ErrorCode::InvalidRating);
-        require!(review.len() <= 500, ErrorCode::ReviewTooLong);
-
-        let order = &mut ctx.accounts.order;
-        let gig = &mut ctx.accounts.gig;
-        let marketplace = &ctx.accounts.marketplace;
-
-        require!(order.status == OrderStatus::Delivered, ErrorCode::InvalidOrderStatus);
-
-        order.status = OrderStatus::Completed;
-        order.rating = Some(rating);
-        order.review = Some(review);
-        order.completed_at = Some(Clock::get()?.unix_timestamp);
-
-        // Update gig rating
-        let total_rating = (gig.rating as u64 * gig.rating_count as u64) + rating as u64;
-        gig.rating_count += 1;
-        gig.rating = (total_rating / gig.rating_count as u64) as u8;
-
-        // Calculate fees
-        let marketplace_fee = (order.amount * marketplace.fee_percentage as u64) / 10000;
-        let seller_amount = order.amount - marketplace_fee;
-
-        // Transfer payment to seller
-        let seeds = &[
-            b"escrow",
-            order.key().as_ref(),
-            &[ctx.bumps.escrow_token_account],
-        ];
-        let signer = &[&seeds[..]];
-
-        let cpi_accounts = Transfer {
-            from: ctx.accounts.escrow_token_account.to_account_info(),
-            to: ctx.accounts.seller_token_account.to_account_info(),
-            authority: ctx.accounts.escrow_token_account.to_account_info(),
-        };
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
-        token::transfer(cpi_ctx, seller_amount)?;
-
-        // Transfer fee to marketplace
-        let cpi_accounts = Transfer {
-            from: ctx.accounts.escrow_token_account.to_account_info(),
-            to: ctx.accounts.marketplace_token_account.to_account_info(),
-            authority: ctx.accounts.escrow_token_account.to_account_info(),
-        };
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
-        token::transfer(cpi_ctx, marketplace_fee)?;
-
-        emit!(OrderCompleted {
-            order: order.key(),
-            buyer: order.buyer,
-            seller: order.seller,
-            rating: rating,
-        });
-
-        Ok(())
-    }
-
-    pub fn cancel_order(ctx: Context<CancelOrder>) -> Result<()> {
-        let order = &mut ctx.accounts.order;
-        
-        require!(
-            order.status == OrderStatus::Pending || order.status == OrderStatus::InProgress,
-            ErrorCode::CannotCancelOrder
-        );
-
-        let current_time = Clock::get()?.unix_timestamp;
-        
-        // Only allow cancellation if order is pending or past deadline
-        if order.status == OrderStatus::InProgress {
-            require!(current_time > order.delivery_deadline, ErrorCode::CannotCancelOrder);
-        }
-
-        order.status = OrderStatus::Cancelled;
-        order.cancelled_at = Some(current_time);
-
-        // Refund buyer
-        let seeds = &[
-            b"escrow",
-            order.key().as_ref(),
-            &[ctx.bumps.escrow_token_account],
-        ];
-        let signer = &[&seeds[..]];
-
-        let cpi_accounts = Transfer {
-            from: ctx.accounts.escrow_token_account.to_account_info(),
-            to: ctx.accounts.buyer_token_account.to_account_info(),
-            authority: ctx.accounts.escrow_token_account.to_account_info(),
-        };
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
-        token::transfer(cpi_ctx, order.amount)?;
-
-        emit!(OrderCancelled {
-            order: order.key(),
-            buyer: order.buyer,
-        });
-
-        Ok(())
+        instructions::propose_cancellation::propose_cancellation(ctx, refund_bps, reason)
+    }
+
+    pub fn accept_cancellation<'info>(ctx: Context<'_, '_, '_, 'info, AcceptCancellation<'info>>) -> Result<()> {
+        instructions::accept_cancellation::accept_cancellation(ctx)
+    }
+
+    pub fn reject_cancellation(ctx: Context<RejectCancellation>) -> Result<()> {
+        instructions::reject_cancellation::reject_cancellation(ctx)
+    }
+
+    pub fn register_arbiter(
+        ctx: Context<RegisterArbiter>,
+        categories: Vec<ServiceCategory>,
+        tier: ArbiterTier,
+    ) -> Result<()> {
+        instructions::register_arbiter::register_arbiter(ctx, categories, tier)
+    }
+
+    pub fn update_arbiter(
+        ctx: Context<UpdateArbiter>,
+        categories: Option<Vec<ServiceCategory>>,
+        is_active: Option<bool>,
+        tier: Option<ArbiterTier>,
+    ) -> Result<()> {
+        instructions::update_arbiter::update_arbiter(ctx, categories, is_active, tier)
     }
 
     pub fn dispute_order(
         ctx: Context<DisputeOrder>,
         reason: String,
+        evidence_hash: Option<String>,
+        requested_resolution: DisputeResolution,
     ) -> Result<()> {
-        require!(reason.len() <= 500, ErrorCode::ReasonTooLong);
+        instructions::dispute_order::dispute_order(ctx, reason, evidence_hash, requested_resolution)
+    }
 
-        let order = &mut ctx.accounts.order;
-        
-        require!(
-            order.status == OrderStatus::Delivered || order.status == OrderStatus::InProgress,
-            ErrorCode::InvalidOrderStatus
-        );
+    pub fn submit_evidence(
+        ctx: Context<SubmitEvidence>,
+        content_hash: [u8; 32],
+        uri: String,
+    ) -> Result<()> {
+        instructions::submit_evidence::submit_evidence(ctx, content_hash, uri)
+    }
 
-        order.status = OrderStatus::Disputed;
-        order.dispute_reason = Some(reason);
-        order.disputed_at = Some(Clock::get()?.unix_timestamp);
+    pub fn respond_to_dispute(ctx: Context<RespondToDispute>, response: String) -> Result<()> {
+        instructions::respond_to_dispute::respond_to_dispute(ctx, response)
+    }
 
-        emit!(OrderDisputed {
-            order: order.key(),
-            buyer: order.buyer,
-            seller: order.seller,
-        });
+    pub fn assign_arbiter<'info>(ctx: Context<'_, '_, 'info, 'info, AssignArbiter<'info>>) -> Result<()> {
+        instructions::assign_arbiter::assign_arbiter(ctx)
+    }
 
-        Ok(())
+    pub fn appoint_arbiter(ctx: Context<AppointArbiter>) -> Result<()> {
+        instructions::appoint_arbiter::appoint_arbiter(ctx)
     }
 
     pub fn resolve_dispute(
         ctx: Context<ResolveDispute>,
         resolution: DisputeResolution,
-        refund_percentage: u8,
+        refund_bps: u16,
+        resolution_reason: Option<String>,
     ) -> Result<()> {
-        require!(refund_percentage <= 100, ErrorCode::InvalidRefundPercentage);
-
-        let order = &mut ctx.accounts.order;
-        let marketplace = &ctx.accounts.marketplace;
-        
-        require!(order.status == OrderStatus::Disputed, ErrorCode::InvalidOrderStatus);
-
-        order.status = OrderStatus::Resolved;
-        order.resolution = Some(resolution);
-        order.resolved_at = Some(Clock::get()?.unix_timestamp);
-
-        let refund_amount = (order.amount * refund_percentage as u64) / 100;
-        let seller_amount = order.amount - refund_amount;
-
-        let seeds = &[
-            b"escrow",
-            order.key().as_ref(),
-            &[ctx.bumps.escrow_token_account],
-        ];
-        let signer = &[&seeds[..]];
-
-        // Refund buyer if applicable
-        if refund_amount > 0 {
-            let cpi_accounts = Transfer {
-                from: ctx.accounts.escrow_token_account.to_account_info(),
-                to: ctx.accounts.buyer_token_account.to_account_info(),
-                authority: ctx.accounts.escrow_token_account.to_account_info(),
-            };
-            let cpi_program = ctx.accounts.token_program.to_account_info();
-            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
-            token::transfer(cpi_ctx, refund_amount)?;
-        }
-
-        // Pay seller if applicable
-        if seller_amount > 0 {
-            let marketplace_fee = (seller_amount * marketplace.fee_percentage as u64) / 10000;
-            let final_seller_amount = seller_amount - marketplace_fee;
-
-            let cpi_accounts = Transfer {
-                from: ctx.accounts.escrow_token_account.to_account_info(),
-                to: ctx.accounts.seller_token_account.to_account_info(),
-                authority: ctx.accounts.escrow_token_account.to_account_info(),
-            };
-            let cpi_program = ctx.accounts.token_program.to_account_info();
-            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
-            token::transfer(cpi_ctx, final_seller_amount)?;
-
-            // Transfer fee to marketplace
-            let cpi_accounts = Transfer {
-                from: ctx.accounts.escrow_token_account.to_account_info(),
-                to: ctx.accounts.marketplace_token_account.to_account_info(),
-                authority: ctx.accounts.escrow_token_account.to_account_info(),
-            };
-            let cpi_program = ctx.accounts.token_program.to_account_info();
-            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
-            token::transfer(cpi_ctx, marketplace_fee)?;
-        }
-
-        emit!(DisputeResolved {
-            order: order.key(),
-            resolution: resolution,
-            refund_percentage: refund_percentage,
-        });
-
-        Ok(())
+        instructions::resolve_dispute::resolve_dispute(ctx, resolution, refund_bps, resolution_reason)
+    }
+
+    pub fn appeal_dispute(ctx: Context<AppealDispute>, reason: String) -> Result<()> {
+        instructions::appeal_dispute::appeal_dispute(ctx, reason)
+    }
+
+    pub fn resolve_appeal(
+        ctx: Context<ResolveAppeal>,
+        resolution: DisputeResolution,
+        refund_bps: u16,
+        resolution_reason: Option<String>,
+    ) -> Result<()> {
+        instructions::resolve_appeal::resolve_appeal(ctx, resolution, refund_bps, resolution_reason)
+    }
+
+    pub fn finalize_dispute<'info>(ctx: Context<'_, '_, '_, 'info, FinalizeDispute<'info>>) -> Result<()> {
+        instructions::finalize_dispute::finalize_dispute(ctx)
+    }
+
+    pub fn reassign_dispute<'info>(ctx: Context<'_, '_, 'info, 'info, ReassignDispute<'info>>) -> Result<()> {
+        instructions::reassign_dispute::reassign_dispute(ctx)
+    }
+
+    pub fn default_dispute(ctx: Context<DefaultDispute>) -> Result<()> {
+        instructions::default_dispute::default_dispute(ctx)
+    }
+
+    pub fn register_juror(ctx: Context<RegisterJuror>, stake_amount: u64) -> Result<()> {
+        instructions::register_juror::register_juror(ctx, stake_amount)
     }
-}
 
-#[derive(Accounts)]
-pub struct InitializeMarketplace<'info>
\ No newline at end of file
+    pub fn unstake_juror(ctx: Context<UnstakeJuror>, amount: u64) -> Result<()> {
+        instructions::unstake_juror::unstake_juror(ctx, amount)
+    }
+
+    pub fn draw_jury<'info>(ctx: Context<'_, '_, 'info, 'info, DrawJury<'info>>) -> Result<()> {
+        instructions::draw_jury::draw_jury(ctx)
+    }
+
+    pub fn commit_vote(ctx: Context<CommitVote>, commitment: [u8; 32]) -> Result<()> {
+        instructions::commit_vote::commit_vote(ctx, commitment)
+    }
+
+    pub fn reveal_vote(ctx: Context<RevealVote>, refund_bps: u16, salt: [u8; 32]) -> Result<()> {
+        instructions::reveal_vote::reveal_vote(ctx, refund_bps, salt)
+    }
+
+    pub fn settle_jury<'info>(ctx: Context<'_, '_, 'info, 'info, SettleJury<'info>>) -> Result<()> {
+        instructions::settle_jury::settle_jury(ctx)
+    }
+
+    pub fn claim_juror_reward<'info>(ctx: Context<'_, '_, '_, 'info, ClaimJurorReward<'info>>) -> Result<()> {
+        instructions::claim_juror_reward::claim_juror_reward(ctx)
+    }
+
+    pub fn sweep_juror_rewards<'info>(ctx: Context<'_, '_, '_, 'info, SweepJurorRewards<'info>>) -> Result<()> {
+        instructions::sweep_juror_rewards::sweep_juror_rewards(ctx)
+    }
+
+    pub fn create_review(
+        ctx: Context<CreateReview>,
+        rating: u8,
+        comment: String,
+        is_public: bool,
+    ) -> Result<()> {
+        instructions::create_review::create_review(ctx, rating, comment, is_public)
+    }
+
+    pub fn close_order(ctx: Context<CloseOrder>) -> Result<()> {
+        instructions::close_order::close_order(ctx)
+    }
+
+    pub fn open_earnings(ctx: Context<OpenEarnings>) -> Result<()> {
+        instructions::open_earnings::open_earnings(ctx)
+    }
+
+    pub fn withdraw_earnings<'info>(
+        ctx: Context<'_, '_, '_, 'info, WithdrawEarnings<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::withdraw_earnings::withdraw_earnings(ctx, amount)
+    }
+
+    pub fn process_withdrawal<'info>(
+        ctx: Context<'_, '_, '_, 'info, ProcessWithdrawal<'info>>,
+        approve: bool,
+    ) -> Result<()> {
+        instructions::process_withdrawal::process_withdrawal(ctx, approve)
+    }
+
+    pub fn register_referral(ctx: Context<RegisterReferral>) -> Result<()> {
+        instructions::register_referral::register_referral(ctx)
+    }
+
+    pub fn submit_milestone(ctx: Context<SubmitMilestone>, deliverable_url: String) -> Result<()> {
+        instructions::submit_milestone::submit_milestone(ctx, deliverable_url)
+    }
+
+    pub fn approve_milestone<'info>(ctx: Context<'_, '_, '_, 'info, ApproveMilestone<'info>>) -> Result<()> {
+        instructions::approve_milestone::approve_milestone(ctx)
+    }
+
+    pub fn reject_milestone(ctx: Context<RejectMilestone>, reason: String) -> Result<()> {
+        instructions::reject_milestone::reject_milestone(ctx, reason)
+    }
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use super::ServiceCategory;
+
+#[account]
+pub struct Arbiter {
+    pub index: u32,
+    pub authority: Pubkey,
+    pub categories: Vec<ServiceCategory>, // Empty means the arbiter takes every category
+    pub tier: ArbiterTier,
+    pub is_active: bool,
+    pub active_cases: u32,
+    pub resolved_cases: u32,
+    pub total_cases: u32,
+    pub registered_at: i64,
+    pub bump: u8,
+}
+
+impl Arbiter {
+    pub const MAX_CATEGORIES: usize = 8;
+
+    pub const LEN: usize = 8 + // discriminator
+        4 + // index
+        32 + // authority
+        4 + Self::MAX_CATEGORIES + // categories
+        1 + // tier
+        1 + // is_active
+        4 + // active_cases
+        4 + // resolved_cases
+        4 + // total_cases
+        8 + // registered_at
+        1; // bump
+
+    pub fn handles(&self, category: ServiceCategory) -> bool {
+        self.categories.is_empty() || self.categories.contains(&category)
+    }
+
+    /// First-tier arbiters take fresh disputes from the rotation.
+    pub fn is_eligible(&self, category: ServiceCategory) -> bool {
+        self.is_active && self.tier == ArbiterTier::Standard && self.handles(category)
+    }
+
+    pub fn can_hear_appeal(&self, category: ServiceCategory) -> bool {
+        self.is_active && self.tier == ArbiterTier::Appeals && self.handles(category)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArbiterTier {
+    Standard,
+    Appeals, // Second tier, decides appealed resolutions
+}
@@ -0,0 +1,161 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::SolanaGigsError;
+use crate::state::{ReputationLevel, ServiceCategory};
+
+#[account]
+pub struct FeeSchedule {
+    pub default_fee_bps: u16, // Basis points (100 = 1%)
+    pub category_fees: Vec<CategoryFee>, // Replace the default for gigs in a category
+    pub level_fees: Vec<LevelFee>, // Caps the rate for sellers at a reputation level
+    pub volume_tiers: Vec<VolumeTier>, // Sorted by min_volume, ascending, within each mint
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl FeeSchedule {
+    pub const MAX_CATEGORY_FEES: usize = 16;
+    pub const MAX_LEVEL_FEES: usize = 5;
+    pub const MAX_VOLUME_TIERS: usize = 16;
+    pub const MAX_FEE_BPS: u16 = 1000; // 10%
+
+    pub const LEN: usize = 8 + // discriminator
+        2 + // default_fee_bps
+        4 + Self::MAX_CATEGORY_FEES * CategoryFee::LEN + // category_fees
+        4 + Self::MAX_LEVEL_FEES * LevelFee::LEN + // level_fees
+        4 + Self::MAX_VOLUME_TIERS * VolumeTier::LEN + // volume_tiers
+        8 + // updated_at
+        1; // bump
+
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.default_fee_bps <= Self::MAX_FEE_BPS,
+            SolanaGigsError::InvalidPlatformFee
+        );
+        require!(
+            self.category_fees.len() <= Self::MAX_CATEGORY_FEES
+                && self.level_fees.len() <= Self::MAX_LEVEL_FEES
+                && self.volume_tiers.len() <= Self::MAX_VOLUME_TIERS,
+            SolanaGigsError::InvalidFeeStructure
+        );
+
+        for (i, entry) in self.category_fees.iter().enumerate() {
+            require!(entry.fee_bps <= Self::MAX_FEE_BPS, SolanaGigsError::InvalidPlatformFee);
+            require!(
+                !self.category_fees[..i].iter().any(|other| other.category == entry.category),
+                SolanaGigsError::InvalidFeeStructure
+            );
+        }
+
+        for (i, entry) in self.level_fees.iter().enumerate() {
+            require!(entry.fee_bps <= Self::MAX_FEE_BPS, SolanaGigsError::InvalidPlatformFee);
+            require!(
+                !self.level_fees[..i].iter().any(|other| other.level == entry.level),
+                SolanaGigsError::InvalidFeeStructure
+            );
+        }
+
+        for (i, tier) in self.volume_tiers.iter().enumerate() {
+            require!(tier.discount_bps <= Self::MAX_FEE_BPS, SolanaGigsError::InvalidPlatformFee);
+            require!(
+                self.volume_tiers[..i]
+                    .iter()
+                    .filter(|other| other.mint == tier.mint)
+                    .all(|other| tier.min_volume > other.min_volume),
+                SolanaGigsError::InvalidFeeStructure
+            );
+        }
+
+        Ok(())
+    }
+
+    /// The category override (or the default) sets the base rate, a level
+    /// override can only lower it, and the highest tier the seller has reached
+    /// in the payout's mint is then discounted off. Fees round down.
+    pub fn quote(
+        &self,
+        category: ServiceCategory,
+        level: ReputationLevel,
+        mint: Pubkey,
+        seller_volume: u64,
+        amount: u64,
+    ) -> Result<FeeQuote> {
+        let base_fee_bps = self
+            .category_fees
+            .iter()
+            .find(|entry| entry.category == category)
+            .map_or(self.default_fee_bps, |entry| entry.fee_bps);
+
+        let level_fee_bps = self
+            .level_fees
+            .iter()
+            .find(|entry| entry.level == level)
+            .map(|entry| entry.fee_bps);
+
+        let volume_discount_bps = self
+            .volume_tiers
+            .iter()
+            .rev()
+            .find(|tier| tier.mint == mint && seller_volume >= tier.min_volume)
+            .map_or(0, |tier| tier.discount_bps);
+
+        let fee_bps = level_fee_bps
+            .map_or(base_fee_bps, |level_fee_bps| base_fee_bps.min(level_fee_bps))
+            .saturating_sub(volume_discount_bps);
+
+        let fee = (amount as u128)
+            .checked_mul(fee_bps as u128)
+            .ok_or(SolanaGigsError::ArithmeticOverflow)?
+            / 10_000;
+
+        Ok(FeeQuote {
+            base_fee_bps,
+            level_fee_bps,
+            volume_discount_bps,
+            fee_bps,
+            fee: fee as u64,
+        })
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CategoryFee {
+    pub category: ServiceCategory,
+    pub fee_bps: u16,
+}
+
+impl CategoryFee {
+    pub const LEN: usize = 1 + 2;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LevelFee {
+    pub level: ReputationLevel,
+    pub fee_bps: u16,
+}
+
+impl LevelFee {
+    pub const LEN: usize = 1 + 2;
+}
+
+/// Sellers whose completed volume in `mint` reaches `min_volume`, in that
+/// mint's base units, get `discount_bps` taken off their rate for it.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VolumeTier {
+    pub mint: Pubkey,
+    pub min_volume: u64,
+    pub discount_bps: u16,
+}
+
+impl VolumeTier {
+    pub const LEN: usize = 32 + 8 + 2;
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct FeeQuote {
+    pub base_fee_bps: u16,
+    pub level_fee_bps: Option<u16>,
+    pub volume_discount_bps: u16,
+    pub fee_bps: u16,
+    pub fee: u64,
+}
@@ -0,0 +1,138 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::SolanaGigsError;
+
+/// M-of-N signer set that owns the marketplace once governance is enabled.
+/// `Marketplace.authority` is then the system-owned governance signer PDA,
+/// so admin instructions only run through an executed proposal.
+#[account]
+pub struct Governance {
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+    pub min_delay: i64, // Seconds between reaching the threshold and execution
+    pub proposal_count: u64,
+    pub config_version: u32, // Bumped on every signer-set change to retire older proposals
+    pub signer_bump: u8,
+    pub bump: u8,
+}
+
+impl Governance {
+    pub const MAX_SIGNERS: usize = 10;
+    pub const MAX_DELAY: i64 = 30 * 24 * 60 * 60; // 30 days
+
+    pub const LEN: usize = 8 + // discriminator
+        4 + Self::MAX_SIGNERS * 32 + // signers
+        1 + // threshold
+        8 + // min_delay
+        8 + // proposal_count
+        4 + // config_version
+        1 + // signer_bump
+        1; // bump
+
+    pub fn validate_config(signers: &[Pubkey], threshold: u8, min_delay: i64) -> Result<()> {
+        require!(
+            !signers.is_empty() && signers.len() <= Self::MAX_SIGNERS,
+            SolanaGigsError::InvalidGovernanceConfig
+        );
+        require!(
+            threshold > 0 && threshold as usize <= signers.len(),
+            SolanaGigsError::InvalidGovernanceConfig
+        );
+        require!(
+            (0..=Self::MAX_DELAY).contains(&min_delay),
+            SolanaGigsError::InvalidGovernanceConfig
+        );
+        for (i, signer) in signers.iter().enumerate() {
+            require!(!signers[..i].contains(signer), SolanaGigsError::InvalidGovernanceConfig);
+        }
+        Ok(())
+    }
+
+    pub fn require_signer(&self, key: &Pubkey) -> Result<()> {
+        require!(self.signers.contains(key), SolanaGigsError::AdminPrivilegesRequired);
+        Ok(())
+    }
+}
+
+/// A queued call into this program, signed by the governance signer PDA when
+/// executed. Proposals stay on chain so every pending admin action is visible
+/// for the whole timelock.
+#[account]
+pub struct Proposal {
+    pub id: u64,
+    pub proposer: Pubkey,
+    pub accounts: Vec<ProposalAccount>,
+    pub data: Vec<u8>,
+    pub approvals: Vec<Pubkey>,
+    pub status: ProposalStatus,
+    pub config_version: u32,
+    pub created_at: i64,
+    pub approved_at: Option<i64>,
+    pub executable_at: Option<i64>,
+    pub executed_at: Option<i64>,
+    pub bump: u8,
+}
+
+impl Proposal {
+    pub const MAX_ACCOUNTS: usize = 16;
+    pub const MAX_DATA_LEN: usize = 512;
+
+    pub const LEN: usize = 8 + // discriminator
+        8 + // id
+        32 + // proposer
+        4 + Self::MAX_ACCOUNTS * ProposalAccount::LEN + // accounts
+        4 + Self::MAX_DATA_LEN + // data
+        4 + Governance::MAX_SIGNERS * 32 + // approvals
+        1 + // status
+        4 + // config_version
+        8 + // created_at
+        1 + 8 + // approved_at
+        1 + 8 + // executable_at
+        1 + 8 + // executed_at
+        1; // bump
+
+    /// Records an approval and starts the timelock once the threshold is met.
+    pub fn approve(&mut self, governance: &Governance, approver: Pubkey, now: i64) -> Result<()> {
+        require!(self.status == ProposalStatus::Pending, SolanaGigsError::InvalidProposalStatus);
+        require!(
+            self.config_version == governance.config_version,
+            SolanaGigsError::StaleProposal
+        );
+        require!(!self.approvals.contains(&approver), SolanaGigsError::AlreadyApproved);
+
+        self.approvals.push(approver);
+        if self.approvals.len() >= governance.threshold as usize {
+            self.status = ProposalStatus::Approved;
+            self.approved_at = Some(now);
+            self.executable_at = Some(
+                now.checked_add(governance.min_delay)
+                    .ok_or(SolanaGigsError::ArithmeticOverflow)?,
+            );
+        }
+        Ok(())
+    }
+
+    pub fn can_execute(&self, now: i64) -> bool {
+        self.status == ProposalStatus::Approved
+            && self.executable_at.is_some_and(|executable_at| now >= executable_at)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ProposalAccount {
+    pub pubkey: Pubkey,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+impl ProposalAccount {
+    pub const LEN: usize = 32 + 1 + 1;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProposalStatus {
+    Pending,
+    Approved,
+    Executed,
+    Cancelled,
+}
@@ -0,0 +1,143 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+#[account]
+pub struct JurorStake {
+    pub index: u32,
+    pub juror: Pubkey,
+    pub staked_amount: u64, // Lamports held on this PDA on top of its rent
+    pub is_active: bool,
+    pub active_cases: u32,
+    pub total_votes: u32,
+    pub majority_votes: u32,
+    pub slashed_amount: u64,
+    pub registered_at: i64,
+    pub bump: u8,
+}
+
+impl JurorStake {
+    pub const MIN_STAKE: u64 = 1_000_000_000; // 1 SOL in lamports
+
+    pub const LEN: usize = 8 + // discriminator
+        4 + // index
+        32 + // juror
+        8 + // staked_amount
+        1 + // is_active
+        4 + // active_cases
+        4 + // total_votes
+        4 + // majority_votes
+        8 + // slashed_amount
+        8 + // registered_at
+        1; // bump
+
+    pub fn can_serve(&self, buyer: &Pubkey, seller: &Pubkey) -> bool {
+        self.is_active &&
+        self.staked_amount >= Self::MIN_STAKE &&
+        self.juror != *buyer &&
+        self.juror != *seller
+    }
+}
+
+#[account]
+pub struct JuryCase {
+    pub dispute: Pubkey,
+    pub order: Pubkey,
+    pub jurors: Vec<JurorVote>,
+    pub status: JuryStatus,
+    pub commit_deadline: i64,
+    pub reveal_deadline: i64,
+    pub median_refund_bps: u16,
+    pub juror_fee: u64, // Escrow share left in the vault for majority jurors
+    pub slashed_pool: u64, // Lamports slashed from no-shows, held on this PDA
+    pub majority_count: u8,
+    pub claims_made: u8,
+    pub claim_deadline: i64, // Unclaimed rewards can be swept to the treasury after this
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl JuryCase {
+    pub const PANEL_SIZE: usize = 5;
+    pub const COMMIT_PERIOD: i64 = 3 * 24 * 60 * 60; // 3 days
+    pub const REVEAL_PERIOD: i64 = 2 * 24 * 60 * 60; // 2 days
+    pub const MAJORITY_TOLERANCE_BPS: u16 = 1000; // Votes within 10% of the median count as majority
+    pub const NO_SHOW_SLASH_BPS: u16 = 2000; // 20% of stake
+    pub const CLAIM_PERIOD: i64 = 30 * 24 * 60 * 60; // 30 days
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // dispute
+        32 + // order
+        4 + (Self::PANEL_SIZE * JurorVote::LEN) + // jurors
+        1 + // status
+        8 + // commit_deadline
+        8 + // reveal_deadline
+        2 + // median_refund_bps
+        8 + // juror_fee
+        8 + // slashed_pool
+        1 + // majority_count
+        1 + // claims_made
+        8 + // claim_deadline
+        8 + // created_at
+        1; // bump
+
+    /// Index into the juror registry where the panel draw starts. The slot
+    /// hash is only known at draw time, so nobody can register jurors at
+    /// the winning indices ahead of the dispute.
+    pub fn draw_start(dispute: &Pubkey, slot_hash: &[u8; 32], juror_pool: u32) -> u32 {
+        let seed = hashv(&[b"jury", dispute.as_ref(), slot_hash]).to_bytes();
+        u32::from_le_bytes([seed[0], seed[1], seed[2], seed[3]]) % juror_pool
+    }
+
+    pub fn commitment(refund_bps: u16, salt: &[u8; 32], juror: &Pubkey) -> [u8; 32] {
+        hashv(&[&refund_bps.to_le_bytes(), salt, juror.as_ref()]).to_bytes()
+    }
+
+    pub fn juror_position(&self, juror: &Pubkey) -> Option<usize> {
+        self.jurors.iter().position(|vote| vote.juror == *juror)
+    }
+
+    pub fn all_committed(&self) -> bool {
+        self.jurors.iter().all(|vote| vote.commitment.is_some())
+    }
+
+    pub fn all_revealed(&self) -> bool {
+        self.jurors.iter().all(|vote| vote.refund_bps.is_some())
+    }
+
+    /// Median of the revealed votes, taking the lower middle on even counts.
+    pub fn median_vote(&self) -> Option<u16> {
+        let mut votes: Vec<u16> = self.jurors.iter().filter_map(|vote| vote.refund_bps).collect();
+        if votes.is_empty() {
+            return None;
+        }
+        votes.sort_unstable();
+        Some(votes[(votes.len() - 1) / 2])
+    }
+
+    pub fn is_majority_vote(&self, refund_bps: u16) -> bool {
+        refund_bps.abs_diff(self.median_refund_bps) <= Self::MAJORITY_TOLERANCE_BPS
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct JurorVote {
+    pub juror: Pubkey,
+    pub commitment: Option<[u8; 32]>,
+    pub refund_bps: Option<u16>,
+    pub reward_claimed: bool,
+}
+
+impl JurorVote {
+    pub const LEN: usize = 32 + // juror
+        33 + // commitment (Option<[u8; 32]>)
+        3 + // refund_bps (Option<u16>)
+        1; // reward_claimed
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JuryStatus {
+    Voting,
+    Settled,
+    Failed, // Nobody revealed; the dispute falls back to an arbiter
+    Expired, // Claim window closed; unclaimed rewards went to the treasury
+}
@@ -1,333 +1,211 @@
-```rust
 use anchor_lang::prelude::*;
 
+use crate::errors::SolanaGigsError;
+
+pub mod arbiter;
+pub mod fee_schedule;
+pub mod governance;
+pub mod jury;
+pub mod order;
+pub mod referral;
+pub mod service;
+pub mod user_profile;
+
+pub use arbiter::*;
+pub use fee_schedule::*;
+pub use governance::*;
+pub use jury::*;
+pub use order::*;
+pub use referral::*;
+pub use service::*;
+pub use user_profile::*;
+
 #[account]
 pub struct Marketplace {
     pub authority: Pubkey,
-    pub fee_percentage: u16, // Basis points (100 = 1%)
+    pub pending_authority: Option<Pubkey>, // Nominated successor, until they accept
+    pub fee_manager: Pubkey,
+    pub pauser: Pubkey,
+    pub dispute_admin: Pubkey,
+    pub category_curator: Pubkey,
+    pub verifier: Pubkey,
+    pub dispute_period: i64, // Seconds after delivery during which a dispute can be raised
+    pub auto_release_bounty: u16, // Basis points of the marketplace fee paid to auto_release callers
+    pub referral_share: u16, // Basis points of the marketplace fee paid to each party's referrer
     pub total_gigs: u64,
     pub total_orders: u64,
     pub total_volume: u64,
-    pub is_paused: bool,
+    pub total_disputes: u64,
+    pub active_disputes: u64,
+    pub arbiter_count: u32,
+    pub next_arbiter_index: u32, // Round-robin pointer for deterministic dispute assignment
+    pub juror_count: u32,
+    pub appeal_period: i64, // Seconds a dispute resolution stays pending before it can settle
+    pub appeal_bond: u64, // Lamports an appellant posts, returned if the appeal succeeds
+    pub response_period: i64, // Seconds the respondent has to engage before the dispute defaults
+    pub arbiter_period: i64, // Seconds an assigned arbiter has to decide before reassignment
+    pub retention_period: i64, // Seconds a finalized order is kept before its accounts can be closed
+    pub is_paused: bool, // Blocks new gigs and orders
+    pub is_emergency_stopped: bool, // Also freezes funds, except refunds of pending orders
     pub bump: u8,
 }
 
 impl Marketplace {
     pub const LEN: usize = 8 + // discriminator
         32 + // authority
-        2 + // fee_percentage
+        1 + 32 + // pending_authority
+        32 + // fee_manager
+        32 + // pauser
+        32 + // dispute_admin
+        32 + // category_curator
+        32 + // verifier
+        8 + // dispute_period
+        2 + // auto_release_bounty
+        2 + // referral_share
         8 + // total_gigs
         8 + // total_orders
         8 + // total_volume
+        8 + // total_disputes
+        8 + // active_disputes
+        4 + // arbiter_count
+        4 + // next_arbiter_index
+        4 + // juror_count
+        8 + // appeal_period
+        8 + // appeal_bond
+        8 + // response_period
+        8 + // arbiter_period
+        8 + // retention_period
         1 + // is_paused
+        1 + // is_emergency_stopped
         1; // bump
-}
 
-#[account]
-pub struct UserProfile {
-    pub owner: Pubkey,
-    pub username: String,
-    pub bio: String,
-    pub profile_image_url: String,
-    pub skills: Vec<String>,
-    pub rating: u32, // Rating * 100 (e.g., 450 = 4.5 stars)
-    pub total_reviews: u32,
-    pub total_earnings: u64,
-    pub total_orders_completed: u32,
-    pub is_verified: bool,
-    pub verification_level: u8, // 0 = none, 1 = email, 2 = kyc, 3 = premium
-    pub created_at: i64,
-    pub updated_at: i64,
-    pub bump: u8,
+    pub const DEFAULT_DISPUTE_PERIOD: i64 = 7 * 24 * 60 * 60; // 7 days
+    pub const MAX_AUTO_RELEASE_BOUNTY: u16 = 5000; // 50% of the fee
+    pub const MAX_REFERRAL_SHARE: u16 = 5000; // Buyer and seller referrers together never exceed the fee
+    pub const DEFAULT_APPEAL_PERIOD: i64 = 3 * 24 * 60 * 60; // 3 days
+    pub const DEFAULT_APPEAL_BOND: u64 = 100_000_000; // 0.1 SOL in lamports
+    pub const DEFAULT_RESPONSE_PERIOD: i64 = 3 * 24 * 60 * 60; // 3 days
+    pub const DEFAULT_ARBITER_PERIOD: i64 = 7 * 24 * 60 * 60; // 7 days
+    pub const DEFAULT_RETENTION_PERIOD: i64 = 90 * 24 * 60 * 60; // 90 days
+
+    /// Either pause level stops new gigs and orders.
+    pub fn require_not_paused(&self) -> Result<()> {
+        require!(
+            !self.is_paused && !self.is_emergency_stopped,
+            SolanaGigsError::ProgramIsPaused
+        );
+        Ok(())
+    }
+
+    /// Guards every instruction that moves funds. Buyer refunds of pending
+    /// orders skip it so nobody is locked out of an order that never started.
+    pub fn require_funds_unfrozen(&self) -> Result<()> {
+        require!(!self.is_emergency_stopped, SolanaGigsError::EmergencyStopActivated);
+        Ok(())
+    }
+
+    /// Hands the marketplace to `authority` along with every delegated role,
+    /// so keys trusted by the previous authority keep no admin access.
+    pub fn set_authority(&mut self, authority: Pubkey) {
+        self.authority = authority;
+        self.pending_authority = None;
+        self.fee_manager = authority;
+        self.pauser = authority;
+        self.dispute_admin = authority;
+        self.category_curator = authority;
+        self.verifier = authority;
+    }
+
+    pub fn role_holder(&self, role: AdminRole) -> Pubkey {
+        match role {
+            AdminRole::FeeManager => self.fee_manager,
+            AdminRole::Pauser => self.pauser,
+            AdminRole::DisputeAdmin => self.dispute_admin,
+            AdminRole::CategoryCurator => self.category_curator,
+            AdminRole::Verifier => self.verifier,
+        }
+    }
+
+    pub fn require_role(&self, role: AdminRole, key: &Pubkey) -> Result<()> {
+        require_keys_eq!(self.role_holder(role), *key, SolanaGigsError::AdminPrivilegesRequired);
+        Ok(())
+    }
+
+    pub fn calculate_auto_release_bounty(&self, marketplace_fee: u64) -> u64 {
+        ((marketplace_fee as u128 * self.auto_release_bounty as u128) / 10_000) as u64
+    }
+
+    pub fn calculate_referral_reward(&self, marketplace_fee: u64) -> u64 {
+        ((marketplace_fee as u128 * self.referral_share as u128) / 10_000) as u64
+    }
 }
 
-impl UserProfile {
-    pub const MAX_USERNAME_LEN: usize = 32;
-    pub const MAX_BIO_LEN: usize = 500;
-    pub const MAX_IMAGE_URL_LEN: usize = 200;
-    pub const MAX_SKILLS: usize = 10;
-    pub const MAX_SKILL_LEN: usize = 30;
-
-    pub const LEN: usize = 8 + // discriminator
-        32 + // owner
-        4 + Self::MAX_USERNAME_LEN + // username
-        4 + Self::MAX_BIO_LEN + // bio
-        4 + Self::MAX_IMAGE_URL_LEN + // profile_image_url
-        4 + (Self::MAX_SKILLS * (4 + Self::MAX_SKILL_LEN)) + // skills
-        4 + // rating
-        4 + // total_reviews
-        8 + // total_earnings
-        4 + // total_orders_completed
-        1 + // is_verified
-        1 + // verification_level
-        8 + // created_at
-        8 + // updated_at
-        1; // bump
+/// Keys the authority can delegate, one per area of administration. All of
+/// them start out as the authority itself.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AdminRole {
+    FeeManager,
+    Pauser,
+    DisputeAdmin,
+    CategoryCurator,
+    Verifier,
 }
 
 #[account]
-pub struct Gig {
-    pub id: u64,
-    pub seller: Pubkey,
-    pub title: String,
-    pub description: String,
-    pub category: String,
-    pub subcategory: String,
-    pub tags: Vec<String>,
-    pub images: Vec<String>,
-    pub packages: Vec<GigPackage>,
-    pub requirements: String,
-    pub delivery_time: u32, // in days
-    pub revisions: u32,
+pub struct AcceptedMint {
+    pub mint: Pubkey,
+    pub min_price: u64, // in base units of the mint
+    pub max_price: u64,
+    pub withdrawal_threshold: u64, // Withdrawals above this need admin approval; 0 disables the check
     pub is_active: bool,
-    pub total_orders: u32,
-    pub rating: u32, // Rating * 100
-    pub total_reviews: u32,
-    pub created_at: i64,
-    pub updated_at: i64,
+    pub added_at: i64,
     pub bump: u8,
 }
 
-impl Gig {
-    pub const MAX_TITLE_LEN: usize = 100;
-    pub const MAX_DESCRIPTION_LEN: usize = 2000;
-    pub const MAX_CATEGORY_LEN: usize = 50;
-    pub const MAX_SUBCATEGORY_LEN: usize = 50;
-    pub const MAX_TAGS: usize = 5;
-    pub const MAX_TAG_LEN: usize = 20;
-    pub const MAX_IMAGES: usize = 5;
-    pub const MAX_IMAGE_URL_LEN: usize = 200;
-    pub const MAX_PACKAGES: usize = 3;
-    pub const MAX_REQUIREMENTS_LEN: usize = 1000;
-
+impl AcceptedMint {
     pub const LEN: usize = 8 + // discriminator
-        8 + // id
-        32 + // seller
-        4 + Self::MAX_TITLE_LEN + // title
-        4 + Self::MAX_DESCRIPTION_LEN + // description
-        4 + Self::MAX_CATEGORY_LEN + // category
-        4 + Self::MAX_SUBCATEGORY_LEN + // subcategory
-        4 + (Self::MAX_TAGS * (4 + Self::MAX_TAG_LEN)) + // tags
-        4 + (Self::MAX_IMAGES * (4 + Self::MAX_IMAGE_URL_LEN)) + // images
-        4 + (Self::MAX_PACKAGES * GigPackage::LEN) + // packages
-        4 + Self::MAX_REQUIREMENTS_LEN + // requirements
-        4 + // delivery_time
-        4 + // revisions
+        32 + // mint
+        8 + // min_price
+        8 + // max_price
+        8 + // withdrawal_threshold
         1 + // is_active
-        4 + // total_orders
-        4 + // rating
-        4 + // total_reviews
-        8 + // created_at
-        8 + // updated_at
+        8 + // added_at
         1; // bump
-}
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
-pub struct GigPackage {
-    pub name: String,
-    pub description: String,
-    pub price: u64, // in lamports
-    pub delivery_time: u32, // in days
-    pub revisions: u32,
-    pub features: Vec<String>,
-}
+    pub fn validate_bounds(min_price: u64, max_price: u64) -> Result<()> {
+        require!(min_price > 0 && min_price <= max_price, SolanaGigsError::InvalidGigPrice);
+        Ok(())
+    }
 
-impl GigPackage {
-    pub const MAX_NAME_LEN: usize = 50;
-    pub const MAX_DESCRIPTION_LEN: usize = 300;
-    pub const MAX_FEATURES: usize = 10;
-    pub const MAX_FEATURE_LEN: usize = 100;
-
-    pub const LEN: usize = 4 + Self::MAX_NAME_LEN + // name
-        4 + Self::MAX_DESCRIPTION_LEN + // description
-        8 + // price
-        4 + // delivery_time
-        4 + // revisions
-        4 + (Self::MAX_FEATURES * (4 + Self::MAX_FEATURE_LEN)); // features
+    pub fn check_price(&self, price: u64) -> Result<()> {
+        require!(price >= self.min_price, SolanaGigsError::GigPriceTooLow);
+        require!(price <= self.max_price, SolanaGigsError::GigPriceTooHigh);
+        Ok(())
+    }
 }
 
+/// Collects marketplace fees for one mint. SOL fees are held as lamports on
+/// the account itself; SPL fees sit in a vault it owns.
 #[account]
-pub struct Order {
-    pub id: u64,
-    pub gig: Pubkey,
-    pub buyer: Pubkey,
-    pub seller: Pubkey,
-    pub package_index: u8,
-    pub custom_requirements: String,
-    pub price: u64,
-    pub status: OrderStatus,
-    pub delivery_time: u32,
-    pub revisions_remaining: u32,
-    pub deliverables: Vec<String>,
-    pub messages: Vec<OrderMessage>,
-    pub dispute_reason: String,
-    pub escrow_account: Pubkey,
-    pub created_at: i64,
-    pub accepted_at: Option<i64>,
-    pub delivered_at: Option<i64>,
-    pub completed_at: Option<i64>,
-    pub cancelled_at: Option<i64>,
-    pub bump: u8,
-}
-
-impl Order {
-    pub const MAX_CUSTOM_REQUIREMENTS_LEN: usize = 1000;
-    pub const MAX_DELIVERABLES: usize = 10;
-    pub const MAX_DELIVERABLE_URL_LEN: usize = 200;
-    pub const MAX_MESSAGES: usize = 50;
-    pub const MAX_DISPUTE_REASON_LEN: usize = 500;
-
-    pub const LEN: usize = 8 + // discriminator
-        8 + // id
-        32 + // gig
-        32 + // buyer
-        32 + // seller
-        1 + // package_index
-        4 + Self::MAX_CUSTOM_REQUIREMENTS_LEN + // custom_requirements
-        8 + // price
-        1 + // status
-        4 + // delivery_time
-        4 + // revisions_remaining
-        4 + (Self::MAX_DELIVERABLES * (4 + Self::MAX_DELIVERABLE_URL_LEN)) + // deliverables
-        4 + (Self::MAX_MESSAGES * OrderMessage::LEN) + // messages
-        4 + Self::MAX_DISPUTE_REASON_LEN + // dispute_reason
-        32 + // escrow_account
-        8 + // created_at
-        9 + // accepted_at (Option<i64>)
-        9 + // delivered_at
-        9 + // completed_at
-        9 + // cancelled_at
-        1; // bump
-}
-
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
-pub enum OrderStatus {
-    Pending,
-    Active,
-    InRevision,
-    Delivered,
-    Completed,
-    Cancelled,
-    Disputed,
-}
-
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
-pub struct OrderMessage {
-    pub sender: Pubkey,
-    pub content: String,
-    pub attachment_url: Option<String>,
-    pub timestamp: i64,
-    pub is_system_message: bool,
-}
-
-impl OrderMessage {
-    pub const MAX_CONTENT_LEN: usize = 1000;
-    pub const MAX_ATTACHMENT_URL_LEN: usize = 200;
-
-    pub const LEN: usize = 32 + // sender
-        4 + Self::MAX_CONTENT_LEN + // content
-        1 + 4 + Self::MAX_ATTACHMENT_URL_LEN + // attachment_url (Option)
-        8 + // timestamp
-        1; // is_system_message
-}
-
-#[account]
-pub struct Review {
-    pub id: u64,
-    pub order: Pubkey,
-    pub reviewer: Pubkey,
-    pub reviewee: Pubkey,
-    pub rating: u8, // 1-5 stars
-    pub comment: String,
-    pub is_public: bool,
-    pub created_at: i64,
-    pub bump: u8,
-}
-
-impl Review {
-    pub const MAX_COMMENT_LEN: usize = 500;
-
-    pub const LEN: usize = 8 + // discriminator
-        8 + // id
-        32 + // order
-        32 + // reviewer
-        32 + // reviewee
-        1 + // rating
-        4 + Self::MAX_COMMENT_LEN + // comment
-        1 + // is_public
-        8 + // created_at
-        1; // bump
-}
-
-#[account]
-pub struct Escrow {
-    pub order: Pubkey,
-    pub buyer: Pubkey,
-    pub seller: Pubkey,
-    pub amount: u64,
-    pub marketplace_fee: u64,
-    pub is_released: bool,
-    pub release_time: Option<i64>,
-    pub dispute_deadline: i64,
-    pub bump: u8,
-}
-
-impl Escrow {
-    pub const LEN: usize = 8 + // discriminator
-        32 + // order
-        32 + // buyer
-        32 + // seller
-        8 + // amount
-        8 + // marketplace_fee
-        1 + // is_released
-        9 + // release_time (Option<i64>)
-        8 + // dispute_deadline
-        1; // bump
-}
-
-#[account]
-pub struct Dispute {
-    pub id: u64,
-    pub order: Pubkey,
-    pub initiator: Pubkey,
-    pub reason: String,
-    pub evidence: Vec<String>,
-    pub status: DisputeStatus,
-    pub resolution: String,
-    pub resolved_by: Option<Pubkey>,
-    pub created_at: i64,
-    pub resolved_at: Option<i64>,
+pub struct Treasury {
+    pub mint: Pubkey,
+    pub vault: Pubkey, // Pubkey::default() for SOL
+    pub is_native: bool,
+    pub total_withdrawn: u64,
+    pub withdrawal_count: u64,
     pub bump: u8,
 }
 
-impl Dispute {
-    pub const MAX_REASON_LEN: usize = 1000;
-    pub const MAX_EVIDENCE: usize = 10;
-    pub const MAX_EVIDENCE_URL_LEN: usize = 200;
-    pub const MAX_RESOLUTION_LEN: usize = 1000;
-
+impl Treasury {
     pub const LEN: usize = 8 + // discriminator
-        8 + // id
-        32 + // order
-        32 + // initiator
-        4 + Self::MAX_REASON_LEN + // reason
-        4 + (Self::MAX_EVIDENCE * (4 + Self::MAX_EVIDENCE_URL_LEN)) + // evidence
-        1 + // status
-        4 + Self::MAX_RESOLUTION_LEN + // resolution
-        33 + // resolved_by (Option<Pubkey>)
-        8 + // created_at
-        9 + // resolved_at (Option<i64>)
+        32 + // mint
+        32 + // vault
+        1 + // is_native
+        8 + // total_withdrawn
+        8 + // withdrawal_count
         1; // bump
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
-pub enum DisputeStatus {
-    Open,
-    UnderReview,
-    Resolved,
-    Closed,
-}
-
 #[account]
 pub struct Category {
     pub name: String,
@@ -353,12 +231,35 @@ impl Category {
         4 + // gig_count
         8 + // created_at
         1; // bump
+
+    pub fn validate(name: &str, description: &str, subcategories: &[String]) -> Result<()> {
+        require!(
+            !name.is_empty() && name.len() <= Self::MAX_NAME_LEN,
+            SolanaGigsError::CategoryTooLong
+        );
+        require!(
+            description.len() <= Self::MAX_DESCRIPTION_LEN,
+            SolanaGigsError::CategoryTooLong
+        );
+        require!(
+            subcategories.len() <= Self::MAX_SUBCATEGORIES,
+            SolanaGigsError::InvalidCategory
+        );
+        for subcategory in subcategories {
+            require!(
+                subcategory.len() <= Self::MAX_SUBCATEGORY_LEN,
+                SolanaGigsError::SubcategoryTooLong
+            );
+        }
+        Ok(())
+    }
 }
 
 #[account]
 pub struct WithdrawalRequest {
     pub id: u64,
     pub user: Pubkey,
+    pub earnings: Pubkey,
     pub amount: u64,
     pub destination: Pubkey,
     pub status: WithdrawalStatus,
@@ -372,6 +273,7 @@ impl WithdrawalRequest {
     pub const LEN: usize = 8 + // discriminator
         8 + // id
         32 + // user
+        32 + // earnings
         8 + // amount
         32 + // destination
         1 + // status
@@ -381,7 +283,7 @@ impl WithdrawalRequest {
         1; // bump
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
 pub enum WithdrawalStatus {
     Pending,
     Approved,
@@ -392,22 +294,88 @@ pub enum WithdrawalStatus {
 #[account]
 pub struct UserEarnings {
     pub user: Pubkey,
+    pub mint: Pubkey,
+    pub vault: Pubkey, // Pubkey::default() for native SOL, which is held on this PDA
+    pub is_native: bool,
     pub available_balance: u64,
-    pub pending_balance: u64,
+    pub pending_balance: u64, // Requested withdrawals awaiting approval
     pub total_earned: u64,
     pub total_withdrawn: u64,
+    pub withdrawal_count: u64,
+    pub period_start: i64,
+    pub period_withdrawn: u64, // Paid out without approval since period_start
     pub last_updated: i64,
     pub bump: u8,
 }
 
 impl UserEarnings {
+    pub const WITHDRAWAL_PERIOD: i64 = 24 * 60 * 60; // 1 day
+
     pub const LEN: usize = 8 + // discriminator
         32 + // user
+        32 + // mint
+        32 + // vault
+        1 + // is_native
         8 + // available_balance
         8 + // pending_balance
         8 + // total_earned
         8 + // total_withdrawn
+        8 + // withdrawal_count
+        8 + // period_start
+        8 + // period_withdrawn
         8 + // last_updated
         1; // bump
+
+    pub fn credit(&mut self, amount: u64, current_time: i64) -> Result<()> {
+        self.available_balance = self.available_balance.checked_add(amount)
+            .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+        self.total_earned = self.total_earned.checked_add(amount)
+            .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+        self.last_updated = current_time;
+        Ok(())
+    }
+
+    pub fn debit_available(&mut self, amount: u64) -> Result<()> {
+        require!(
+            amount > 0 && amount <= self.available_balance,
+            SolanaGigsError::InvalidWithdrawalAmount
+        );
+        self.available_balance -= amount;
+        Ok(())
+    }
+
+    /// What the current period's unapproved withdrawals would add up to with
+    /// `amount` included, starting a new period once the last one has ended.
+    pub fn period_total(&mut self, amount: u64, current_time: i64) -> Result<u64> {
+        if current_time >= self.period_start.saturating_add(Self::WITHDRAWAL_PERIOD) {
+            self.period_start = current_time;
+            self.period_withdrawn = 0;
+        }
+        Ok(self.period_withdrawn.checked_add(amount)
+            .ok_or(SolanaGigsError::ArithmeticOverflow)?)
+    }
+}
+
+/// A seller's completed volume in one mint, created when they accept their
+/// first order in it. Volume tiers for the mint are measured against it.
+#[account]
+pub struct SellerVolume {
+    pub seller: Pubkey,
+    pub mint: Pubkey,
+    pub total_volume: u64,
+    pub bump: u8,
+}
+
+impl SellerVolume {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // seller
+        32 + // mint
+        8 + // total_volume
+        1; // bump
+
+    pub fn add_volume(&mut self, amount: u64) -> Result<()> {
+        self.total_volume = self.total_volume.checked_add(amount)
+            .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+        Ok(())
+    }
 }
-```
\ No newline at end of file
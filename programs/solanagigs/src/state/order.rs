@@ -1,92 +1,141 @@
-```rust
 use anchor_lang::prelude::*;
 
+use super::Gig;
+
 #[account]
 pub struct Order {
-    pub order_id: u64,
+    pub id: u64,
+    pub gig: Pubkey,
     pub buyer: Pubkey,
     pub seller: Pubkey,
-    pub gig: Pubkey,
+    pub package_index: u8,
+    pub extras: Vec<u8>,
     pub amount: u64,
-    pub escrow_amount: u64,
+    pub requirements: String,
     pub status: OrderStatus,
     pub created_at: i64,
-    pub deadline: i64,
+    pub delivery_deadline: i64,
+    pub accepted_at: Option<i64>,
+    pub delivered_at: Option<i64>,
     pub completed_at: Option<i64>,
     pub cancelled_at: Option<i64>,
-    pub dispute_raised_at: Option<i64>,
-    pub requirements: String,
-    pub delivery_notes: Option<String>,
+    pub disputed_at: Option<i64>,
+    pub resolved_at: Option<i64>,
+    pub delivery_message: Option<String>,
+    pub delivery_files: Vec<String>,
     pub revision_count: u8,
     pub max_revisions: u8,
     pub dispute_reason: Option<String>,
     pub arbiter: Option<Pubkey>,
+    pub resolution: Option<DisputeResolution>,
     pub buyer_rating: Option<u8>,
     pub seller_rating: Option<u8>,
-    pub buyer_review: Option<String>,
-    pub seller_review: Option<String>,
     pub milestone_count: u8,
     pub completed_milestones: u8,
+    pub milestones_total: u64,
+    pub extension_count: u8,
+    pub has_pending_extension: bool, // Cleared when the order leaves InProgress
     pub bump: u8,
 }
 
 impl Order {
+    pub const MAX_REQUIREMENTS_LEN: usize = 500;
+    pub const MAX_DELIVERY_MESSAGE_LEN: usize = 1000;
+    pub const MAX_DELIVERY_FILES: usize = 10;
+    pub const MAX_DELIVERY_FILE_URL_LEN: usize = 200;
+    pub const MAX_DISPUTE_REASON_LEN: usize = 500;
+    pub const MAX_MILESTONES: u8 = 10;
+    pub const MAX_EXTENSIONS: u8 = 5;
+
     pub const LEN: usize = 8 + // discriminator
-        8 + // order_id
+        8 + // id
+        32 + // gig
         32 + // buyer
         32 + // seller
-        32 + // gig
+        1 + // package_index
+        4 + Gig::MAX_EXTRAS + // extras
         8 + // amount
-        8 + // escrow_amount
+        4 + Self::MAX_REQUIREMENTS_LEN + // requirements
         1 + // status
         8 + // created_at
-        8 + // deadline
+        8 + // delivery_deadline
+        9 + // accepted_at (Option<i64>)
+        9 + // delivered_at (Option<i64>)
         9 + // completed_at (Option<i64>)
         9 + // cancelled_at (Option<i64>)
-        9 + // dispute_raised_at (Option<i64>)
-        4 + 500 + // requirements (String with max 500 chars)
-        4 + 1000 + // delivery_notes (Option<String> with max 1000 chars)
+        9 + // disputed_at (Option<i64>)
+        9 + // resolved_at (Option<i64>)
+        1 + 4 + Self::MAX_DELIVERY_MESSAGE_LEN + // delivery_message (Option<String>)
+        4 + (Self::MAX_DELIVERY_FILES * (4 + Self::MAX_DELIVERY_FILE_URL_LEN)) + // delivery_files
         1 + // revision_count
         1 + // max_revisions
-        4 + 200 + // dispute_reason (Option<String> with max 200 chars)
+        1 + 4 + Self::MAX_DISPUTE_REASON_LEN + // dispute_reason (Option<String>)
         33 + // arbiter (Option<Pubkey>)
+        2 + // resolution (Option<DisputeResolution>)
         2 + // buyer_rating (Option<u8>)
         2 + // seller_rating (Option<u8>)
-        4 + 500 + // buyer_review (Option<String> with max 500 chars)
-        4 + 500 + // seller_review (Option<String> with max 500 chars)
         1 + // milestone_count
         1 + // completed_milestones
+        8 + // milestones_total
+        1 + // extension_count
+        1 + // has_pending_extension
         1; // bump
 
+    pub fn is_participant(&self, key: &Pubkey) -> bool {
+        self.buyer == *key || self.seller == *key
+    }
+
+    /// When the order reached a terminal state, if it has.
+    pub fn finalized_at(&self) -> Option<i64> {
+        match self.status {
+            OrderStatus::Completed => self.completed_at,
+            OrderStatus::Cancelled => self.cancelled_at,
+            OrderStatus::Resolved => self.resolved_at,
+            _ => None,
+        }
+    }
+
     pub fn is_active(&self) -> bool {
-        matches!(self.status, OrderStatus::Active | OrderStatus::InProgress | OrderStatus::InReview)
+        matches!(self.status, OrderStatus::Pending | OrderStatus::InProgress | OrderStatus::Delivered)
     }
 
-    pub fn can_be_cancelled(&self) -> bool {
-        matches!(self.status, OrderStatus::Active | OrderStatus::InProgress)
+    pub fn can_be_cancelled(&self, current_time: i64) -> bool {
+        match self.status {
+            OrderStatus::Pending => true,
+            OrderStatus::InProgress => current_time > self.delivery_deadline,
+            _ => false,
+        }
     }
 
     pub fn can_be_completed(&self) -> bool {
-        matches!(self.status, OrderStatus::InReview)
+        matches!(self.status, OrderStatus::Delivered)
     }
 
     pub fn can_request_revision(&self) -> bool {
-        matches!(self.status, OrderStatus::InReview) && self.revision_count < self.max_revisions
+        matches!(self.status, OrderStatus::Delivered) && self.revision_count < self.max_revisions
     }
 
     pub fn can_raise_dispute(&self) -> bool {
-        matches!(self.status, OrderStatus::Active | OrderStatus::InProgress | OrderStatus::InReview)
+        matches!(self.status, OrderStatus::InProgress | OrderStatus::Delivered)
     }
 
     pub fn is_overdue(&self, current_time: i64) -> bool {
-        self.is_active() && current_time > self.deadline
+        matches!(self.status, OrderStatus::Pending | OrderStatus::InProgress) && current_time > self.delivery_deadline
+    }
+
+    pub fn has_milestones(&self) -> bool {
+        self.milestone_count > 0
+    }
+
+    pub fn all_milestones_completed(&self) -> bool {
+        self.has_milestones() && self.completed_milestones == self.milestone_count
     }
 
     pub fn progress_percentage(&self) -> u8 {
         if self.milestone_count == 0 {
             match self.status {
                 OrderStatus::Completed => 100,
-                OrderStatus::InProgress | OrderStatus::InReview => 50,
+                OrderStatus::InProgress | OrderStatus::Delivered => 50,
                 _ => 0,
             }
         } else {
@@ -95,16 +144,15 @@ impl Order {
     }
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
 pub enum OrderStatus {
-    Active,
+    Pending,
     InProgress,
-    InReview,
+    Delivered,
     Completed,
     Cancelled,
     Disputed,
     Resolved,
-    Refunded,
 }
 
 #[account]
@@ -112,16 +160,19 @@ pub struct Escrow {
     pub order: Pubkey,
     pub buyer: Pubkey,
     pub seller: Pubkey,
+    pub vault: Pubkey,
+    pub mint: Pubkey,
+    pub is_native: bool, // Lamports held on the escrow PDA instead of a token vault
     pub amount: u64,
     pub platform_fee: u64,
-    pub seller_fee: u64,
+    pub partial_release_amount: u64,
     pub created_at: i64,
     pub released_at: Option<i64>,
     pub refunded_at: Option<i64>,
     pub dispute_deadline: i64,
     pub auto_release_enabled: bool,
-    pub partial_release_amount: u64,
     pub bump: u8,
+    pub vault_bump: u8,
 }
 
 impl Escrow {
@@ -129,23 +180,40 @@ impl Escrow {
         32 + // order
         32 + // buyer
         32 + // seller
+        32 + // vault
+        32 + // mint
+        1 + // is_native
         8 + // amount
         8 + // platform_fee
-        8 + // seller_fee
+        8 + // partial_release_amount
         8 + // created_at
         9 + // released_at (Option<i64>)
         9 + // refunded_at (Option<i64>)
         8 + // dispute_deadline
         1 + // auto_release_enabled
-        8 + // partial_release_amount
-        1; // bump
+        1 + // bump
+        1; // vault_bump
+
+    pub fn remaining_amount(&self) -> u64 {
+        self.amount.saturating_sub(self.partial_release_amount)
+    }
 
-    pub fn total_amount(&self) -> u64 {
-        self.amount + self.platform_fee + self.seller_fee
+    /// Scales an amount quoted against the order price to the escrowed amount,
+    /// which is lower than the order price for transfer-fee mints.
+    pub fn escrowed_share(&self, order_amount: u64, quoted_amount: u64) -> u64 {
+        if order_amount == 0 || self.amount == order_amount {
+            return quoted_amount;
+        }
+        ((quoted_amount as u128 * self.amount as u128) / order_amount as u128) as u64
     }
 
-    pub fn seller_payout(&self) -> u64 {
-        self.amount.saturating_sub(self.seller_fee)
+    /// Splits `amount` into the buyer refund and the seller share. The seller
+    /// share rounds down, so rounding dust always goes to the buyer and the
+    /// two parts add up to `amount` exactly.
+    pub fn split_refund(amount: u64, refund_bps: u16) -> (u64, u64) {
+        let seller_bps = 10_000u16.saturating_sub(refund_bps);
+        let seller_share = ((amount as u128 * seller_bps as u128) / 10_000) as u64;
+        (amount - seller_share, seller_share)
     }
 
     pub fn is_released(&self) -> bool {
@@ -156,11 +224,14 @@ impl Escrow {
         self.refunded_at.is_some()
     }
 
+    pub fn is_settled(&self) -> bool {
+        self.is_released() || self.is_refunded()
+    }
+
     pub fn can_auto_release(&self, current_time: i64) -> bool {
-        self.auto_release_enabled && 
-        current_time > self.dispute_deadline && 
-        !self.is_released() && 
-        !self.is_refunded()
+        self.auto_release_enabled &&
+        current_time > self.dispute_deadline &&
+        !self.is_settled()
     }
 
     pub fn remaining_dispute_time(&self, current_time: i64) -> i64 {
@@ -168,6 +239,33 @@ impl Escrow {
     }
 }
 
+#[account]
+pub struct CancellationProposal {
+    pub order: Pubkey,
+    pub proposed_by: Pubkey,
+    pub refund_bps: u16, // Share of the remaining escrow refunded to the buyer
+    pub reason: String,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl CancellationProposal {
+    pub const MAX_REASON_LEN: usize = 200;
+    pub const MAX_REFUND_BPS: u16 = 10_000;
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // order
+        32 + // proposed_by
+        2 + // refund_bps
+        4 + Self::MAX_REASON_LEN + // reason
+        8 + // created_at
+        1; // bump
+
+    pub fn split(&self, amount: u64) -> (u64, u64) {
+        Escrow::split_refund(amount, self.refund_bps)
+    }
+}
+
 #[account]
 pub struct OrderMilestone {
     pub order: Pubkey,
@@ -186,28 +284,33 @@ pub struct OrderMilestone {
 }
 
 impl OrderMilestone {
+    pub const MAX_TITLE_LEN: usize = 100;
+    pub const MAX_DESCRIPTION_LEN: usize = 500;
+    pub const MAX_REJECTION_REASON_LEN: usize = 200;
+    pub const MAX_DELIVERABLE_URL_LEN: usize = 200;
+
     pub const LEN: usize = 8 + // discriminator
         32 + // order
         1 + // milestone_id
-        4 + 100 + // title (String with max 100 chars)
-        4 + 500 + // description (String with max 500 chars)
+        4 + Self::MAX_TITLE_LEN + // title
+        4 + Self::MAX_DESCRIPTION_LEN + // description
         8 + // amount
         1 + // status
         8 + // due_date
         9 + // completed_at (Option<i64>)
         9 + // approved_at (Option<i64>)
         9 + // rejected_at (Option<i64>)
-        4 + 200 + // rejection_reason (Option<String> with max 200 chars)
-        4 + 200 + // deliverable_url (Option<String> with max 200 chars)
+        1 + 4 + Self::MAX_REJECTION_REASON_LEN + // rejection_reason (Option<String>)
+        1 + 4 + Self::MAX_DELIVERABLE_URL_LEN + // deliverable_url (Option<String>)
         1; // bump
 
     pub fn is_overdue(&self, current_time: i64) -> bool {
-        matches!(self.status, MilestoneStatus::Pending | MilestoneStatus::InProgress) && 
+        matches!(self.status, MilestoneStatus::Pending | MilestoneStatus::InProgress) &&
         current_time > self.due_date
     }
 
     pub fn can_be_completed(&self) -> bool {
-        matches!(self.status, MilestoneStatus::InProgress)
+        matches!(self.status, MilestoneStatus::Pending | MilestoneStatus::InProgress | MilestoneStatus::Rejected)
     }
 
     pub fn can_be_approved(&self) -> bool {
@@ -215,7 +318,7 @@ impl OrderMilestone {
     }
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
 pub enum MilestoneStatus {
     Pending,
     InProgress,
@@ -227,36 +330,77 @@ pub enum MilestoneStatus {
 #[account]
 pub struct Dispute {
     pub order: Pubkey,
-    pub raised_by: Pubkey,
+    pub initiator: Pubkey,
+    pub initiator_type: DisputeInitiator,
+    pub respondent: Pubkey,
     pub reason: String,
-    pub description: String,
-    pub evidence_urls: Vec<String>,
+    pub evidence_hash: Option<String>,
+    pub evidence: Vec<DisputeEvidence>,
+    pub evidence_deadline: i64,
+    pub response_deadline: i64,
+    pub arbiter_deadline: i64, // Set when an arbiter is assigned
+    pub requested_resolution: DisputeResolution,
     pub status: DisputeStatus,
-    pub created_at: i64,
-    pub resolved_at: Option<i64>,
+    pub resolution_mode: ResolutionMode,
+    pub juror_pool: u32, // Jurors registered when the dispute was opened, the only ones a draw may pick
     pub arbiter: Option<Pubkey>,
-    pub resolution: Option<String>,
+    pub resolution: Option<DisputeResolution>,
+    pub refund_bps: u16, // Refund the pending resolution will pay out
+    pub resolution_reason: Option<String>,
+    pub buyer_response: Option<String>,
+    pub seller_response: Option<String>,
     pub buyer_refund_amount: u64,
     pub seller_payout_amount: u64,
-    pub platform_fee_refund: u64,
+    pub platform_fee: u64,
+    pub resolved_by: Option<Pubkey>,
+    pub appeal_deadline: i64,
+    pub appealed: bool,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub resolved_at: Option<i64>,
     pub bump: u8,
 }
 
 impl Dispute {
+    pub const MIN_REASON_LEN: usize = 10;
+    pub const MAX_REASON_LEN: usize = 500;
+    pub const EVIDENCE_HASH_LEN: usize = 64; // hex-encoded SHA-256
+    pub const MAX_EVIDENCE_PER_PARTY: usize = 5;
+    pub const MAX_EVIDENCE: usize = 2 * Self::MAX_EVIDENCE_PER_PARTY;
+    pub const EVIDENCE_PERIOD: i64 = 5 * 24 * 60 * 60; // 5 days
+    pub const MAX_RESOLUTION_REASON_LEN: usize = 500;
+    pub const MAX_RESPONSE_LEN: usize = 1000;
+
     pub const LEN: usize = 8 + // discriminator
         32 + // order
-        32 + // raised_by
-        4 + 100 + // reason (String with max 100 chars)
-        4 + 1000 + // description (String with max 1000 chars)
-        4 + (4 + 200) * 5 + // evidence_urls (Vec<String> with max 5 URLs, 200 chars each)
+        32 + // initiator
+        1 + // initiator_type
+        32 + // respondent
+        4 + Self::MAX_REASON_LEN + // reason
+        1 + 4 + Self::EVIDENCE_HASH_LEN + // evidence_hash (Option<String>)
+        4 + (Self::MAX_EVIDENCE * DisputeEvidence::LEN) + // evidence
+        8 + // evidence_deadline
+        8 + // response_deadline
+        8 + // arbiter_deadline
+        1 + // requested_resolution
         1 + // status
-        8 + // created_at
-        9 + // resolved_at (Option<i64>)
+        1 + // resolution_mode
+        4 + // juror_pool
         33 + // arbiter (Option<Pubkey>)
-        4 + 500 + // resolution (Option<String> with max 500 chars)
+        2 + // resolution (Option<DisputeResolution>)
+        2 + // refund_bps
+        1 + 4 + Self::MAX_RESOLUTION_REASON_LEN + // resolution_reason (Option<String>)
+        1 + 4 + Self::MAX_RESPONSE_LEN + // buyer_response (Option<String>)
+        1 + 4 + Self::MAX_RESPONSE_LEN + // seller_response (Option<String>)
         8 + // buyer_refund_amount
         8 + // seller_payout_amount
-        8 + // platform_fee_refund
+        8 + // platform_fee
+        33 + // resolved_by (Option<Pubkey>)
+        8 + // appeal_deadline
+        1 + // appealed
+        8 + // created_at
+        8 + // updated_at
+        9 + // resolved_at (Option<i64>)
         1; // bump
 
     pub fn is_active(&self) -> bool {
@@ -268,16 +412,143 @@ impl Dispute {
     }
 
     pub fn total_disputed_amount(&self) -> u64 {
-        self.buyer_refund_amount + self.seller_payout_amount
+        self.buyer_refund_amount + self.seller_payout_amount + self.platform_fee
+    }
+
+    pub fn evidence_count_by(&self, submitter: &Pubkey) -> usize {
+        self.evidence.iter().filter(|entry| entry.submitter == *submitter).count()
+    }
+
+    pub fn is_evidence_open(&self, current_time: i64) -> bool {
+        self.is_active() && current_time <= self.evidence_deadline
+    }
+
+    pub fn has_response(&self) -> bool {
+        match self.initiator_type {
+            DisputeInitiator::Buyer => self.seller_response.is_some(),
+            DisputeInitiator::Seller => self.buyer_response.is_some(),
+        }
+    }
+
+    /// The respondent engaged if it answered or filed evidence before its deadline.
+    pub fn respondent_engaged(&self) -> bool {
+        self.has_response() ||
+        self.evidence.iter().any(|entry| {
+            entry.submitter == self.respondent && entry.submitted_at <= self.response_deadline
+        })
+    }
+
+    /// A pending resolution can be appealed once, before its window closes.
+    pub fn can_appeal(&self, current_time: i64) -> bool {
+        self.status == DisputeStatus::PendingSettlement &&
+        !self.appealed &&
+        current_time < self.appeal_deadline
+    }
+
+    pub fn can_settle(&self, current_time: i64) -> bool {
+        self.status == DisputeStatus::PendingSettlement && current_time >= self.appeal_deadline
     }
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+#[account]
+pub struct DisputeAppeal {
+    pub dispute: Pubkey,
+    pub order: Pubkey,
+    pub appellant: Pubkey,
+    pub bond: u64, // Lamports held on this PDA on top of its rent
+    pub original_resolution: DisputeResolution,
+    pub original_refund_bps: u16,
+    pub reason: String,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl DisputeAppeal {
+    pub const MAX_REASON_LEN: usize = 500;
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // dispute
+        32 + // order
+        32 + // appellant
+        8 + // bond
+        1 + // original_resolution
+        2 + // original_refund_bps
+        4 + Self::MAX_REASON_LEN + // reason
+        8 + // created_at
+        1; // bump
+
+    /// The appellant wins when the new refund moves in their favour.
+    pub fn is_successful(&self, appellant_is_buyer: bool, refund_bps: u16) -> bool {
+        if appellant_is_buyer {
+            refund_bps > self.original_refund_bps
+        } else {
+            refund_bps < self.original_refund_bps
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct DisputeEvidence {
+    pub content_hash: [u8; 32], // SHA-256 of the evidence content
+    pub uri: String,
+    pub submitter: Pubkey,
+    pub submitted_at: i64,
+}
+
+impl DisputeEvidence {
+    pub const MAX_URI_LEN: usize = 200;
+
+    pub const LEN: usize = 32 + // content_hash
+        4 + Self::MAX_URI_LEN + // uri
+        32 + // submitter
+        8; // submitted_at
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
 pub enum DisputeStatus {
     Open,
     UnderReview,
     Resolved,
     Dismissed,
+    PendingSettlement, // Resolution recorded, funds held until the appeal window ends
+    Appealed,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ResolutionMode {
+    Arbiter,
+    Jury,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DisputeInitiator {
+    Buyer,
+    Seller,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DisputeResolution {
+    RefundBuyer,
+    ReleaseToSeller,
+    Split,
+}
+
+impl DisputeResolution {
+    pub fn is_consistent_with(&self, refund_bps: u16) -> bool {
+        match self {
+            DisputeResolution::RefundBuyer => refund_bps == 10_000,
+            DisputeResolution::ReleaseToSeller => refund_bps == 0,
+            DisputeResolution::Split => refund_bps > 0 && refund_bps < 10_000,
+        }
+    }
+
+    pub fn from_refund_bps(refund_bps: u16) -> Self {
+        match refund_bps {
+            0 => DisputeResolution::ReleaseToSeller,
+            10_000 => DisputeResolution::RefundBuyer,
+            _ => DisputeResolution::Split,
+        }
+    }
 }
 
 #[account]
@@ -296,17 +567,22 @@ pub struct OrderRevision {
 }
 
 impl OrderRevision {
+    pub const MAX_REASON_LEN: usize = 100;
+    pub const MAX_DESCRIPTION_LEN: usize = 500;
+    pub const MAX_DELIVERABLE_URL_LEN: usize = 200;
+    pub const MAX_FEEDBACK_LEN: usize = 300;
+
     pub const LEN: usize = 8 + // discriminator
         32 + // order
         1 + // revision_number
         32 + // requested_by
-        4 + 100 + // reason (String with max 100 chars)
-        4 + 500 + // description (String with max 500 chars)
+        4 + Self::MAX_REASON_LEN + // reason
+        4 + Self::MAX_DESCRIPTION_LEN + // description
         8 + // requested_at
         9 + // completed_at (Option<i64>)
         1 + // status
-        4 + 200 + // deliverable_url (Option<String> with max 200 chars)
-        4 + 300 + // feedback (Option<String> with max 300 chars)
+        1 + 4 + Self::MAX_DELIVERABLE_URL_LEN + // deliverable_url (Option<String>)
+        1 + 4 + Self::MAX_FEEDBACK_LEN + // feedback (Option<String>)
         1; // bump
 
     pub fn is_pending(&self) -> bool {
@@ -318,11 +594,49 @@ impl OrderRevision {
     }
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+#[account]
+pub struct ExtensionRequest {
+    pub order: Pubkey,
+    pub request_number: u8,
+    pub requested_by: Pubkey,
+    pub previous_deadline: i64,
+    pub new_deadline: i64,
+    pub price_concession: u64, // Refunded to the buyer if accepted
+    pub reason: String,
+    pub status: ExtensionStatus,
+    pub requested_at: i64,
+    pub responded_at: Option<i64>,
+    pub bump: u8,
+}
+
+impl ExtensionRequest {
+    pub const MAX_REASON_LEN: usize = 200;
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // order
+        1 + // request_number
+        32 + // requested_by
+        8 + // previous_deadline
+        8 + // new_deadline
+        8 + // price_concession
+        4 + Self::MAX_REASON_LEN + // reason
+        1 + // status
+        8 + // requested_at
+        9 + // responded_at (Option<i64>)
+        1; // bump
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExtensionStatus {
+    Pending,
+    Accepted,
+    Declined,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
 pub enum RevisionStatus {
     Requested,
     InProgress,
     Completed,
     Rejected,
 }
-```
\ No newline at end of file
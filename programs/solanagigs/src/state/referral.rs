@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::SolanaGigsError;
+
+/// Binds a user to the referrer who brought them in. One per user, so the
+/// binding cannot be changed once made.
+#[account]
+pub struct Referral {
+    pub user: Pubkey,
+    pub referrer: Pubkey,
+    pub total_rewards: u64, // Summed across mints, in base units
+    pub reward_count: u32,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl Referral {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // user
+        32 + // referrer
+        8 + // total_rewards
+        4 + // reward_count
+        8 + // created_at
+        1; // bump
+
+    pub fn record_reward(&mut self, amount: u64) -> Result<()> {
+        self.total_rewards = self.total_rewards.checked_add(amount)
+            .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+        self.reward_count = self.reward_count.checked_add(1)
+            .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
+
+/// One referrer's cut of a settlement's marketplace fee.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReferralSplit {
+    pub user: Pubkey,
+    pub referrer: Pubkey,
+    pub amount: u64,
+}
@@ -1,216 +1,270 @@
-```rust
 use anchor_lang::prelude::*;
 
+use crate::errors::SolanaGigsError;
+
+use super::AcceptedMint;
+
 #[account]
-pub struct Service {
+pub struct Gig {
     pub id: u64,
-    pub provider: Pubkey,
+    pub seller: Pubkey,
+    pub payment_mint: Pubkey,
     pub title: String,
     pub description: String,
     pub category: ServiceCategory,
+    pub subcategory: String,
+    pub tags: Vec<String>,
+    pub images: Vec<String>,
+    pub packages: Vec<GigPackage>,
+    pub extras: Vec<GigExtra>,
+    pub requirements: String,
     pub price: u64,
-    pub delivery_time: u32,
+    pub delivery_time: u32, // in days
+    pub revisions: u8,
     pub is_active: bool,
+    pub total_orders: u64,
+    pub active_orders: u64,
+    pub completed_orders: u64,
+    pub rating: u32, // Rating * 100
+    pub total_reviews: u32,
     pub created_at: i64,
     pub updated_at: i64,
-    pub rating: f64,
-    pub total_orders: u64,
-    pub tags: Vec<String>,
-    pub requirements: Vec<String>,
-    pub portfolio_items: Vec<String>,
-    pub revision_count: u8,
     pub bump: u8,
 }
 
-impl Service {
-    pub const MAX_TITLE_LENGTH: usize = 100;
-    pub const MAX_DESCRIPTION_LENGTH: usize = 2000;
-    pub const MAX_TAGS: usize = 10;
-    pub const MAX_TAG_LENGTH: usize = 30;
-    pub const MAX_REQUIREMENTS: usize = 20;
-    pub const MAX_REQUIREMENT_LENGTH: usize = 200;
-    pub const MAX_PORTFOLIO_ITEMS: usize = 10;
-    pub const MAX_PORTFOLIO_ITEM_LENGTH: usize = 200;
-    pub const MIN_PRICE: u64 = 1_000_000; // 0.001 SOL in lamports
-    pub const MAX_PRICE: u64 = 1_000_000_000_000; // 1000 SOL in lamports
+impl Gig {
+    pub const MAX_TITLE_LEN: usize = 100;
+    pub const MAX_DESCRIPTION_LEN: usize = 2000;
+    pub const MAX_SUBCATEGORY_LEN: usize = 50;
+    pub const MAX_TAGS: usize = 5;
+    pub const MAX_TAG_LEN: usize = 20;
+    pub const MAX_IMAGES: usize = 5;
+    pub const MAX_IMAGE_URL_LEN: usize = 200;
+    pub const MAX_PACKAGES: usize = 3;
+    pub const MAX_EXTRAS: usize = 5;
+    pub const MAX_REQUIREMENTS_LEN: usize = 1000;
     pub const MIN_DELIVERY_TIME: u32 = 1; // 1 day
     pub const MAX_DELIVERY_TIME: u32 = 365; // 365 days
-    pub const MAX_REVISION_COUNT: u8 = 10;
+    pub const MAX_REVISIONS: u8 = 10;
 
-    pub fn space() -> usize {
-        8 + // discriminator
+    pub const LEN: usize = 8 + // discriminator
         8 + // id
-        32 + // provider
-        4 + Self::MAX_TITLE_LENGTH + // title
-        4 + Self::MAX_DESCRIPTION_LENGTH + // description
+        32 + // seller
+        32 + // payment_mint
+        4 + Self::MAX_TITLE_LEN + // title
+        4 + Self::MAX_DESCRIPTION_LEN + // description
         1 + // category
+        4 + Self::MAX_SUBCATEGORY_LEN + // subcategory
+        4 + (Self::MAX_TAGS * (4 + Self::MAX_TAG_LEN)) + // tags
+        4 + (Self::MAX_IMAGES * (4 + Self::MAX_IMAGE_URL_LEN)) + // images
+        4 + (Self::MAX_PACKAGES * GigPackage::LEN) + // packages
+        4 + (Self::MAX_EXTRAS * GigExtra::LEN) + // extras
+        4 + Self::MAX_REQUIREMENTS_LEN + // requirements
         8 + // price
         4 + // delivery_time
+        1 + // revisions
         1 + // is_active
+        8 + // total_orders
+        8 + // active_orders
+        8 + // completed_orders
+        4 + // rating
+        4 + // total_reviews
         8 + // created_at
         8 + // updated_at
-        8 + // rating
-        8 + // total_orders
-        4 + (Self::MAX_TAGS * (4 + Self::MAX_TAG_LENGTH)) + // tags
-        4 + (Self::MAX_REQUIREMENTS * (4 + Self::MAX_REQUIREMENT_LENGTH)) + // requirements
-        4 + (Self::MAX_PORTFOLIO_ITEMS * (4 + Self::MAX_PORTFOLIO_ITEM_LENGTH)) + // portfolio_items
-        1 + // revision_count
-        1 // bump
-    }
+        1; // bump
 
-    pub fn initialize(
-        &mut self,
-        id: u64,
-        provider: Pubkey,
-        title: String,
-        description: String,
-        category: ServiceCategory,
-        price: u64,
-        delivery_time: u32,
-        tags: Vec<String>,
-        requirements: Vec<String>,
-        portfolio_items: Vec<String>,
-        revision_count: u8,
-        bump: u8,
-    ) -> Result<()> {
-        require!(title.len() <= Self::MAX_TITLE_LENGTH, ServiceError::TitleTooLong);
-        require!(description.len() <= Self::MAX_DESCRIPTION_LENGTH, ServiceError::DescriptionTooLong);
-        require!(price >= Self::MIN_PRICE && price <= Self::MAX_PRICE, ServiceError::InvalidPrice);
-        require!(delivery_time >= Self::MIN_DELIVERY_TIME && delivery_time <= Self::MAX_DELIVERY_TIME, ServiceError::InvalidDeliveryTime);
-        require!(tags.len() <= Self::MAX_TAGS, ServiceError::TooManyTags);
-        require!(requirements.len() <= Self::MAX_REQUIREMENTS, ServiceError::TooManyRequirements);
-        require!(portfolio_items.len() <= Self::MAX_PORTFOLIO_ITEMS, ServiceError::TooManyPortfolioItems);
-        require!(revision_count <= Self::MAX_REVISION_COUNT, ServiceError::InvalidRevisionCount);
-
-        for tag in &tags {
-            require!(tag.len() <= Self::MAX_TAG_LENGTH, ServiceError::TagTooLong);
+    pub fn validate(&self) -> Result<()> {
+        require!(self.title.len() <= Self::MAX_TITLE_LEN, SolanaGigsError::GigTitleTooLong);
+        require!(self.description.len() <= Self::MAX_DESCRIPTION_LEN, SolanaGigsError::GigDescriptionTooLong);
+        require!(self.subcategory.len() <= Self::MAX_SUBCATEGORY_LEN, SolanaGigsError::SubcategoryTooLong);
+        require!(self.tags.len() <= Self::MAX_TAGS, SolanaGigsError::TooManyTags);
+        require!(self.images.len() <= Self::MAX_IMAGES, SolanaGigsError::TooManyImages);
+        require!(self.requirements.len() <= Self::MAX_REQUIREMENTS_LEN, SolanaGigsError::RequirementsTooLong);
+        require!(self.delivery_time >= Self::MIN_DELIVERY_TIME, SolanaGigsError::DeliveryTimeTooShort);
+        require!(self.delivery_time <= Self::MAX_DELIVERY_TIME, SolanaGigsError::DeliveryTimeTooLong);
+        require!(self.revisions <= Self::MAX_REVISIONS, SolanaGigsError::InvalidRevisionCount);
+
+        for tag in &self.tags {
+            require!(tag.len() <= Self::MAX_TAG_LEN, SolanaGigsError::TagTooLong);
         }
 
-        for requirement in &requirements {
-            require!(requirement.len() <= Self::MAX_REQUIREMENT_LENGTH, ServiceError::RequirementTooLong);
+        for image in &self.images {
+            require!(image.len() <= Self::MAX_IMAGE_URL_LEN, SolanaGigsError::UrlTooLong);
         }
 
-        for item in &portfolio_items {
-            require!(item.len() <= Self::MAX_PORTFOLIO_ITEM_LENGTH, ServiceError::PortfolioItemTooLong);
+        require!(self.packages.len() <= Self::MAX_PACKAGES, SolanaGigsError::TooManyPackages);
+        for package in &self.packages {
+            package.validate()?;
         }
 
-        let clock = Clock::get()?;
-
-        self.id = id;
-        self.provider = provider;
-        self.title = title;
-        self.description = description;
-        self.category = category;
-        self.price = price;
-        self.delivery_time = delivery_time;
-        self.is_active = true;
-        self.created_at = clock.unix_timestamp;
-        self.updated_at = clock.unix_timestamp;
-        self.rating = 0.0;
-        self.total_orders = 0;
-        self.tags = tags;
-        self.requirements = requirements;
-        self.portfolio_items = portfolio_items;
-        self.revision_count = revision_count;
-        self.bump = bump;
+        require!(self.extras.len() <= Self::MAX_EXTRAS, SolanaGigsError::TooManyExtras);
+        for extra in &self.extras {
+            extra.validate()?;
+        }
 
         Ok(())
     }
 
-    pub fn update(
-        &mut self,
-        title: Option<String>,
-        description: Option<String>,
-        category: Option<ServiceCategory>,
-        price: Option<u64>,
-        delivery_time: Option<u32>,
-        tags: Option<Vec<String>>,
-        requirements: Option<Vec<String>>,
-        portfolio_items: Option<Vec<String>>,
-        revision_count: Option<u8>,
-    ) -> Result<()> {
-        let clock = Clock::get()?;
-
-        if let Some(title) = title {
-            require!(title.len() <= Self::MAX_TITLE_LENGTH, ServiceError::TitleTooLong);
-            self.title = title;
-        }
+    /// Price bounds are per payment mint, so they are checked against the
+    /// allowlist entry rather than in `validate`.
+    pub fn validate_prices(&self, accepted_mint: &AcceptedMint) -> Result<()> {
+        require!(accepted_mint.mint == self.payment_mint, SolanaGigsError::InvalidTokenMint);
+        accepted_mint.check_price(self.price)?;
 
-        if let Some(description) = description {
-            require!(description.len() <= Self::MAX_DESCRIPTION_LENGTH, ServiceError::DescriptionTooLong);
-            self.description = description;
+        for package in &self.packages {
+            accepted_mint.check_price(package.price)?;
         }
 
-        if let Some(category) = category {
-            self.category = category;
+        for extra in &self.extras {
+            require!(extra.price <= accepted_mint.max_price, SolanaGigsError::GigPriceTooHigh);
         }
 
-        if let Some(price) = price {
-            require!(price >= Self::MIN_PRICE && price <= Self::MAX_PRICE, ServiceError::InvalidPrice);
-            self.price = price;
-        }
+        Ok(())
+    }
 
-        if let Some(delivery_time) = delivery_time {
-            require!(delivery_time >= Self::MIN_DELIVERY_TIME && delivery_time <= Self::MAX_DELIVERY_TIME, ServiceError::InvalidDeliveryTime);
-            self.delivery_time = delivery_time;
+    /// Mirrors the first (Basic) package onto the flat listing fields so
+    /// browsing clients can show a "starting at" price without decoding packages.
+    pub fn sync_base_package(&mut self) {
+        if let Some(basic) = self.packages.first() {
+            self.price = basic.price;
+            self.delivery_time = basic.delivery_time;
+            self.revisions = basic.revisions as u8;
         }
+    }
 
-        if let Some(tags) = tags {
-            require!(tags.len() <= Self::MAX_TAGS, ServiceError::TooManyTags);
-            for tag in &tags {
-                require!(tag.len() <= Self::MAX_TAG_LENGTH, ServiceError::TagTooLong);
-            }
-            self.tags = tags;
+    /// Returns (price, delivery_time, revisions) for the chosen package.
+    /// Gigs without packages only accept index 0, which maps to the flat listing.
+    pub fn package_terms(&self, package_index: u8) -> Result<(u64, u32, u8)> {
+        if self.packages.is_empty() {
+            require!(package_index == 0, SolanaGigsError::InvalidPackageIndex);
+            return Ok((self.price, self.delivery_time, self.revisions));
         }
 
-        if let Some(requirements) = requirements {
-            require!(requirements.len() <= Self::MAX_REQUIREMENTS, ServiceError::TooManyRequirements);
-            for requirement in &requirements {
-                require!(requirement.len() <= Self::MAX_REQUIREMENT_LENGTH, ServiceError::RequirementTooLong);
-            }
-            self.requirements = requirements;
-        }
+        let package = self.packages
+            .get(package_index as usize)
+            .ok_or(SolanaGigsError::InvalidPackageIndex)?;
+        Ok((package.price, package.delivery_time, package.revisions as u8))
+    }
 
-        if let Some(portfolio_items) = portfolio_items {
-            require!(portfolio_items.len() <= Self::MAX_PORTFOLIO_ITEMS, ServiceError::TooManyPortfolioItems);
-            for item in &portfolio_items {
-                require!(item.len() <= Self::MAX_PORTFOLIO_ITEM_LENGTH, ServiceError::PortfolioItemTooLong);
-            }
-            self.portfolio_items = portfolio_items;
-        }
+    /// Applies the chosen extras on top of package terms. Each extra can be
+    /// picked at most once; express delivery never drops below the minimum.
+    pub fn apply_extras(
+        &self,
+        extra_indices: &[u8],
+        price: u64,
+        delivery_time: u32,
+        revisions: u8,
+    ) -> Result<(u64, u32, u8)> {
+        require!(extra_indices.len() <= Self::MAX_EXTRAS, SolanaGigsError::TooManyExtras);
+
+        let mut price = price;
+        let mut delivery_time = delivery_time as i64;
+        let mut revisions = revisions;
 
-        if let Some(revision_count) = revision_count {
-            require!(revision_count <= Self::MAX_REVISION_COUNT, ServiceError::InvalidRevisionCount);
-            self.revision_count = revision_count;
+        for (i, index) in extra_indices.iter().enumerate() {
+            require!(
+                !extra_indices[..i].contains(index),
+                SolanaGigsError::InvalidExtraIndex
+            );
+            let extra = self.extras
+                .get(*index as usize)
+                .ok_or(SolanaGigsError::InvalidExtraIndex)?;
+
+            price = price.checked_add(extra.price)
+                .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+            delivery_time += extra.delivery_time_delta as i64;
+            revisions = revisions.checked_add(extra.additional_revisions)
+                .ok_or(SolanaGigsError::ArithmeticOverflow)?;
         }
 
-        self.updated_at = clock.unix_timestamp;
+        let delivery_time = delivery_time
+            .clamp(Self::MIN_DELIVERY_TIME as i64, Self::MAX_DELIVERY_TIME as i64) as u32;
 
-        Ok(())
+        Ok((price, delivery_time, revisions))
     }
 
-    pub fn toggle_active(&mut self) -> Result<()> {
-        self.is_active = !self.is_active;
-        self.updated_at = Clock::get()?.unix_timestamp;
+    pub fn record_rating(&mut self, rating: u8) -> Result<()> {
+        let total_score = (self.rating as u64) * (self.total_reviews as u64) + (rating as u64) * 100;
+        self.total_reviews = self.total_reviews.checked_add(1)
+            .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+        self.rating = (total_score / self.total_reviews as u64) as u32;
         Ok(())
     }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct GigPackage {
+    pub name: String,
+    pub description: String,
+    pub price: u64, // in base units of the gig's payment mint
+    pub delivery_time: u32, // in days
+    pub revisions: u32,
+    pub features: Vec<String>,
+}
+
+impl GigPackage {
+    pub const MAX_NAME_LEN: usize = 50;
+    pub const MAX_DESCRIPTION_LEN: usize = 300;
+    pub const MAX_FEATURES: usize = 10;
+    pub const MAX_FEATURE_LEN: usize = 100;
+
+    pub const LEN: usize = 4 + Self::MAX_NAME_LEN + // name
+        4 + Self::MAX_DESCRIPTION_LEN + // description
+        8 + // price
+        4 + // delivery_time
+        4 + // revisions
+        4 + (Self::MAX_FEATURES * (4 + Self::MAX_FEATURE_LEN)); // features
+
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            !self.name.is_empty() && self.name.len() <= Self::MAX_NAME_LEN,
+            SolanaGigsError::InvalidPackage
+        );
+        require!(self.description.len() <= Self::MAX_DESCRIPTION_LEN, SolanaGigsError::InvalidPackage);
+        require!(self.features.len() <= Self::MAX_FEATURES, SolanaGigsError::InvalidPackage);
+        require!(self.delivery_time >= Gig::MIN_DELIVERY_TIME, SolanaGigsError::DeliveryTimeTooShort);
+        require!(self.delivery_time <= Gig::MAX_DELIVERY_TIME, SolanaGigsError::DeliveryTimeTooLong);
+        require!(self.revisions <= Gig::MAX_REVISIONS as u32, SolanaGigsError::InvalidRevisionCount);
+
+        for feature in &self.features {
+            require!(feature.len() <= Self::MAX_FEATURE_LEN, SolanaGigsError::InvalidPackage);
+        }
 
-    pub fn update_rating(&mut self, new_rating: f64, total_orders: u64) -> Result<()> {
-        require!(new_rating >= 0.0 && new_rating <= 5.0, ServiceError::InvalidRating);
-        self.rating = new_rating;
-        self.total_orders = total_orders;
-        self.updated_at = Clock::get()?.unix_timestamp;
         Ok(())
     }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct GigExtra {
+    pub name: String,
+    pub price: u64, // in base units of the gig's payment mint
+    pub delivery_time_delta: i32, // in days, negative for express delivery
+    pub additional_revisions: u8,
+}
+
+impl GigExtra {
+    pub const MAX_NAME_LEN: usize = 50;
+
+    pub const LEN: usize = 4 + Self::MAX_NAME_LEN + // name
+        8 + // price
+        4 + // delivery_time_delta
+        1; // additional_revisions
 
-    pub fn increment_orders(&mut self) -> Result<()> {
-        self.total_orders = self.total_orders.checked_add(1).ok_or(ServiceError::Overflow)?;
-        self.updated_at = Clock::get()?.unix_timestamp;
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            !self.name.is_empty() && self.name.len() <= Self::MAX_NAME_LEN,
+            SolanaGigsError::InvalidExtra
+        );
+        require!(
+            self.delivery_time_delta.unsigned_abs() <= Gig::MAX_DELIVERY_TIME,
+            SolanaGigsError::InvalidDeliveryTime
+        );
+        require!(self.additional_revisions <= Gig::MAX_REVISIONS, SolanaGigsError::InvalidRevisionCount);
         Ok(())
     }
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
 pub enum ServiceCategory {
     GraphicsDesign,
     DigitalMarketing,
@@ -227,46 +281,6 @@ pub enum ServiceCategory {
     Education,
     Gaming,
     Legal,
+    #[default]
     Other,
 }
-
-impl Default for ServiceCategory {
-    fn default() -> Self {
-        ServiceCategory::Other
-    }
-}
-
-#[error_code]
-pub enum ServiceError {
-    #[msg("Title is too long")]
-    TitleTooLong,
-    #[msg("Description is too long")]
-    DescriptionTooLong,
-    #[msg("Invalid price range")]
-    InvalidPrice,
-    #[msg("Invalid delivery time")]
-    InvalidDeliveryTime,
-    #[msg("Too many tags")]
-    TooManyTags,
-    #[msg("Tag is too long")]
-    TagTooLong,
-    #[msg("Too many requirements")]
-    TooManyRequirements,
-    #[msg("Requirement is too long")]
-    RequirementTooLong,
-    #[msg("Too many portfolio items")]
-    TooManyPortfolioItems,
-    #[msg("Portfolio item is too long")]
-    PortfolioItemTooLong,
-    #[msg("Invalid revision count")]
-    InvalidRevisionCount,
-    #[msg("Invalid rating")]
-    InvalidRating,
-    #[msg("Arithmetic overflow")]
-    Overflow,
-    #[msg("Service is not active")]
-    ServiceNotActive,
-    #[msg("Unauthorized access")]
-    Unauthorized,
-}
-```
\ No newline at end of file
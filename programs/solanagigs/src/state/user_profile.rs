@@ -1,6 +1,7 @@
-```rust
 use anchor_lang::prelude::*;
 
+use crate::errors::SolanaGigsError;
+
 #[account]
 pub struct UserProfile {
     pub authority: Pubkey,
@@ -13,14 +14,15 @@ pub struct UserProfile {
     pub total_gigs_completed: u32,
     pub total_gigs_created: u32,
     pub reputation_score: u32,
+    pub reputation_level: ReputationLevel,
     pub is_verified: bool,
     pub verification_level: VerificationLevel,
     pub languages: Vec<String>,
     pub location: String,
     pub joined_at: i64,
     pub last_active: i64,
+    pub updated_at: i64,
     pub portfolio_items: Vec<PortfolioItem>,
-    pub reviews_received: Vec<Review>,
     pub average_rating: u32, // out of 500 (5.00 stars * 100)
     pub total_reviews: u32,
     pub response_time_hours: u32,
@@ -42,10 +44,9 @@ impl UserProfile {
     pub const MAX_LANGUAGES: usize = 10;
     pub const MAX_LANGUAGE_LENGTH: usize = 20;
     pub const MAX_LOCATION_LENGTH: usize = 50;
-    pub const MAX_PORTFOLIO_ITEMS: usize = 10;
-    pub const MAX_REVIEWS: usize = 100;
-    pub const MAX_BADGES: usize = 20;
-    pub const MAX_SOCIAL_LINKS: usize = 10;
+    pub const MAX_PORTFOLIO_ITEMS: usize = 3;
+    pub const MAX_BADGES: usize = 5;
+    pub const MAX_SOCIAL_LINKS: usize = 5;
     pub const MAX_CATEGORIES: usize = 15;
     pub const MAX_CATEGORY_LENGTH: usize = 30;
     pub const MAX_TIMEZONE_LENGTH: usize = 50;
@@ -61,14 +62,15 @@ impl UserProfile {
         4 + // total_gigs_completed
         4 + // total_gigs_created
         4 + // reputation_score
+        1 + // reputation_level
         1 + // is_verified
         1 + // verification_level
         4 + (Self::MAX_LANGUAGES * (4 + Self::MAX_LANGUAGE_LENGTH)) + // languages
         4 + Self::MAX_LOCATION_LENGTH + // location
         8 + // joined_at
         8 + // last_active
+        8 + // updated_at
         4 + (Self::MAX_PORTFOLIO_ITEMS * PortfolioItem::SPACE) + // portfolio_items
-        4 + (Self::MAX_REVIEWS * Review::SPACE) + // reviews_received
         4 + // average_rating
         4 + // total_reviews
         4 + // response_time_hours
@@ -80,43 +82,77 @@ impl UserProfile {
         4 + Self::MAX_TIMEZONE_LENGTH + // timezone
         1; // bump
 
+    pub fn validate(&self) -> Result<()> {
+        require!(self.username.len() <= Self::MAX_USERNAME_LENGTH, SolanaGigsError::UsernameTooLong);
+        require!(self.bio.len() <= Self::MAX_BIO_LENGTH, SolanaGigsError::BioTooLong);
+        require!(self.profile_image_url.len() <= Self::MAX_URL_LENGTH, SolanaGigsError::UrlTooLong);
+        require!(self.skills.len() <= Self::MAX_SKILLS, SolanaGigsError::TooManySkills);
+        require!(self.languages.len() <= Self::MAX_LANGUAGES, SolanaGigsError::TooManyLanguages);
+        require!(self.location.len() <= Self::MAX_LOCATION_LENGTH, SolanaGigsError::LocationTooLong);
+        require!(self.portfolio_items.len() <= Self::MAX_PORTFOLIO_ITEMS, SolanaGigsError::PortfolioLimitExceeded);
+        require!(self.social_links.len() <= Self::MAX_SOCIAL_LINKS, SolanaGigsError::TooManySocialLinks);
+        require!(self.preferred_categories.len() <= Self::MAX_CATEGORIES, SolanaGigsError::TooManyCategories);
+        require!(self.timezone.len() <= Self::MAX_TIMEZONE_LENGTH, SolanaGigsError::TimezoneTooLong);
+
+        for skill in &self.skills {
+            require!(skill.len() <= Self::MAX_SKILL_LENGTH, SolanaGigsError::SkillTooLong);
+        }
+
+        for language in &self.languages {
+            require!(language.len() <= Self::MAX_LANGUAGE_LENGTH, SolanaGigsError::LanguageTooLong);
+        }
+
+        for category in &self.preferred_categories {
+            require!(category.len() <= Self::MAX_CATEGORY_LENGTH, SolanaGigsError::CategoryTooLong);
+        }
+
+        for item in &self.portfolio_items {
+            item.validate()?;
+        }
+
+        for link in &self.social_links {
+            require!(link.url.len() <= SocialLink::MAX_URL_LENGTH, SolanaGigsError::UrlTooLong);
+            require!(link.username.len() <= SocialLink::MAX_USERNAME_LENGTH, SolanaGigsError::UsernameTooLong);
+        }
+
+        Ok(())
+    }
+
     pub fn update_reputation(&mut self, new_rating: u32) -> Result<()> {
         let total_score = (self.average_rating as u64) * (self.total_reviews as u64);
         let new_total_score = total_score + (new_rating as u64);
-        self.total_reviews += 1;
+        self.total_reviews = self.total_reviews.checked_add(1)
+            .ok_or(SolanaGigsError::ArithmeticOverflow)?;
         self.average_rating = (new_total_score / (self.total_reviews as u64)) as u32;
+        self.reputation_level = ReputationLevel::from_reviews(self.total_reviews, self.average_rating);
         Ok(())
     }
 
     pub fn add_earnings(&mut self, amount: u64) -> Result<()> {
         self.total_earnings = self.total_earnings.checked_add(amount)
-            .ok_or(ErrorCode::ArithmeticOverflow)?;
+            .ok_or(SolanaGigsError::ArithmeticOverflow)?;
         Ok(())
     }
 
     pub fn increment_gigs_completed(&mut self) -> Result<()> {
         self.total_gigs_completed = self.total_gigs_completed.checked_add(1)
-            .ok_or(ErrorCode::ArithmeticOverflow)?;
+            .ok_or(SolanaGigsError::ArithmeticOverflow)?;
         Ok(())
     }
 
     pub fn increment_gigs_created(&mut self) -> Result<()> {
         self.total_gigs_created = self.total_gigs_created.checked_add(1)
-            .ok_or(ErrorCode::ArithmeticOverflow)?;
+            .ok_or(SolanaGigsError::ArithmeticOverflow)?;
         Ok(())
     }
 
     pub fn update_completion_rate(&mut self, completed: u32, total: u32) -> Result<()> {
-        if total == 0 {
-            self.completion_rate = 0;
-        } else {
-            self.completion_rate = (completed * 10000) / total; // percentage * 100
-        }
+        self.completion_rate = (completed * 10000).checked_div(total).unwrap_or(0); // percentage * 100
         Ok(())
     }
 
     pub fn add_badge(&mut self, badge: Badge) -> Result<()> {
-        require!(self.badges.len() < Self::MAX_BADGES, ErrorCode::TooManyBadges);
+        require!(self.badges.len() < Self::MAX_BADGES, SolanaGigsError::TooManyBadges);
         self.badges.push(badge);
         Ok(())
     }
@@ -145,6 +181,36 @@ pub enum AvailabilityStatus {
     Offline,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum ReputationLevel {
+    #[default]
+    Newcomer,
+    Rising,
+    Established,
+    TopRated,
+    Elite,
+}
+
+impl ReputationLevel {
+    pub fn from_reviews(total_reviews: u32, average_rating: u32) -> Self {
+        match (total_reviews, average_rating) {
+            (0..=4, _) => ReputationLevel::Newcomer,
+            (5..=19, rating) if rating >= 450 => ReputationLevel::Rising,
+            (5..=19, rating) if rating >= 350 => ReputationLevel::Established,
+            (5..=19, _) => ReputationLevel::Newcomer,
+            (20..=49, rating) if rating >= 480 => ReputationLevel::TopRated,
+            (20..=49, rating) if rating >= 450 => ReputationLevel::Rising,
+            (20..=49, rating) if rating >= 350 => ReputationLevel::Established,
+            (20..=49, _) => ReputationLevel::Newcomer,
+            (50.., rating) if rating >= 490 => ReputationLevel::Elite,
+            (50.., rating) if rating >= 480 => ReputationLevel::TopRated,
+            (50.., rating) if rating >= 450 => ReputationLevel::Rising,
+            (50.., rating) if rating >= 350 => ReputationLevel::Established,
+            (50.., _) => ReputationLevel::Newcomer,
+        }
+    }
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct PortfolioItem {
     pub title: String,
@@ -169,31 +235,46 @@ impl PortfolioItem {
         4 + Self::MAX_URL_LENGTH + // project_url
         4 + (Self::MAX_TAGS * (4 + Self::MAX_TAG_LENGTH)) + // tags
         8; // created_at
+
+    pub fn validate(&self) -> Result<()> {
+        require!(self.title.len() <= Self::MAX_TITLE_LENGTH, SolanaGigsError::InvalidPortfolioItem);
+        require!(self.description.len() <= Self::MAX_DESCRIPTION_LENGTH, SolanaGigsError::InvalidPortfolioItem);
+        require!(self.image_url.len() <= Self::MAX_URL_LENGTH, SolanaGigsError::UrlTooLong);
+        require!(self.project_url.len() <= Self::MAX_URL_LENGTH, SolanaGigsError::UrlTooLong);
+        require!(self.tags.len() <= Self::MAX_TAGS, SolanaGigsError::TooManyTags);
+        for tag in &self.tags {
+            require!(tag.len() <= Self::MAX_TAG_LENGTH, SolanaGigsError::TagTooLong);
+        }
+        Ok(())
+    }
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+#[account]
 pub struct Review {
+    pub order: Pubkey,
+    pub gig: Pubkey,
     pub reviewer: Pubkey,
-    pub reviewer_username: String,
-    pub rating: u32, // out of 500 (5.00 stars * 100)
+    pub reviewee: Pubkey,
+    pub rating: u8, // 1-5 stars
     pub comment: String,
-    pub gig_id: Pubkey,
+    pub is_public: bool,
     pub created_at: i64,
-    pub is_verified_purchase: bool,
+    pub bump: u8,
 }
 
 impl Review {
-    pub const MAX_USERNAME_LENGTH: usize = 32;
     pub const MAX_COMMENT_LENGTH: usize = 500;
 
-    pub const SPACE: usize = 
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // order
+        32 + // gig
         32 + // reviewer
-        4 + Self::MAX_USERNAME_LENGTH + // reviewer_username
-        4 + // rating
+        32 + // reviewee
+        1 + // rating
         4 + Self::MAX_COMMENT_LENGTH + // comment
-        32 + // gig_id
+        1 + // is_public
         8 + // created_at
-        1; // is_verified_purchase
+        1; // bump
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
@@ -303,7 +384,7 @@ impl UserStats {
         1; // bump
 
     pub fn add_favorite_seller(&mut self, seller: Pubkey) -> Result<()> {
-        require!(self.favorite_sellers.len() < Self::MAX_FAVORITE_SELLERS, ErrorCode::TooManyFavorites);
+        require!(self.favorite_sellers.len() < Self::MAX_FAVORITE_SELLERS, SolanaGigsError::TooManyFavorites);
         if !self.favorite_sellers.contains(&seller) {
             self.favorite_sellers.push(seller);
         }
@@ -316,7 +397,7 @@ impl UserStats {
     }
 
     pub fn add_recent_search(&mut self, search_term: String) -> Result<()> {
-        require!(search_term.len() <= Self::MAX_SEARCH_LENGTH, ErrorCode::SearchTermTooLong);
+        require!(search_term.len() <= Self::MAX_SEARCH_LENGTH, SolanaGigsError::SearchTermTooLong);
         
         // Remove if already exists
         self.recent_searches.retain(|x| x != &search_term);
@@ -334,23 +415,23 @@ impl UserStats {
 
     pub fn add_purchase(&mut self, amount: u64) -> Result<()> {
         self.total_spent = self.total_spent.checked_add(amount)
-            .ok_or(ErrorCode::ArithmeticOverflow)?;
+            .ok_or(SolanaGigsError::ArithmeticOverflow)?;
         self.total_orders = self.total_orders.checked_add(1)
-            .ok_or(ErrorCode::ArithmeticOverflow)?;
+            .ok_or(SolanaGigsError::ArithmeticOverflow)?;
         self.last_purchase = Clock::get()?.unix_timestamp;
         Ok(())
     }
 
     pub fn add_loyalty_points(&mut self, points: u64) -> Result<()> {
         self.loyalty_points = self.loyalty_points.checked_add(points)
-            .ok_or(ErrorCode::ArithmeticOverflow)?;
+            .ok_or(SolanaGigsError::ArithmeticOverflow)?;
         Ok(())
     }
 
     pub fn spend_loyalty_points(&mut self, points: u64) -> Result<()> {
-        require!(self.loyalty_points >= points, ErrorCode::InsufficientLoyaltyPoints);
+        require!(self.loyalty_points >= points, SolanaGigsError::InsufficientLoyaltyPoints);
         self.loyalty_points = self.loyalty_points.checked_sub(points)
-            .ok_or(ErrorCode::ArithmeticUnderflow)?;
+            .ok_or(SolanaGigsError::ArithmeticUnderflow)?;
         Ok(())
     }
 }
@@ -361,20 +442,3 @@ pub enum PaymentMethod {
     Usdc,
     Any,
 }
-
-#[error_code]
-pub enum ErrorCode {
-    #[msg("Arithmetic overflow")]
-    ArithmeticOverflow,
-    #[msg("Arithmetic underflow")]
-    ArithmeticUnderflow,
-    #[msg("Too many badges")]
-    TooManyBadges,
-    #[msg("Too many favorites")]
-    TooManyFavorites,
-    #[msg("Search term too long")]
-    SearchTermTooLong,
-    #[msg("Insufficient loyalty points")]
-    InsufficientLoyaltyPoints,
-}
-```
\ No newline at end of file
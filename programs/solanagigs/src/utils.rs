@@ -0,0 +1,475 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke;
+use anchor_lang::system_program;
+use anchor_spl::token_2022::spl_token_2022;
+use anchor_spl::token_2022::spl_token_2022::extension::{
+    transfer_fee::{instruction::harvest_withheld_tokens_to_mint, TransferFeeAmount},
+    BaseStateWithExtensions, StateWithExtensions,
+};
+use anchor_spl::token_interface::{
+    self, CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::errors::SolanaGigsError;
+use crate::state::{
+    Escrow, FeeSchedule, Referral, ReferralSplit, SellerVolume, ServiceCategory, Treasury,
+    UserEarnings, UserProfile,
+};
+
+/// Resolves where a payout lands: the wallet itself for lamport balances,
+/// or the matching token account for SPL balances.
+pub fn payout_account<'info>(
+    is_native: bool,
+    token_account: &Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    wallet: &Option<SystemAccount<'info>>,
+) -> Result<AccountInfo<'info>> {
+    if is_native {
+        wallet
+            .as_ref()
+            .map(|wallet| wallet.to_account_info())
+            .ok_or(error!(SolanaGigsError::InvalidAccountData))
+    } else {
+        token_account
+            .as_ref()
+            .map(|token_account| token_account.to_account_info())
+            .ok_or(error!(SolanaGigsError::TokenAccountNotFound))
+    }
+}
+
+/// Resolves where marketplace fees for a mint land: the treasury PDA itself
+/// for lamport balances, or its vault for SPL balances.
+pub fn treasury_account<'info>(
+    treasury: &Account<'info, Treasury>,
+    treasury_vault: &Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+) -> Result<AccountInfo<'info>> {
+    if treasury.is_native {
+        return Ok(treasury.to_account_info());
+    }
+
+    let treasury_vault = treasury_vault
+        .as_ref()
+        .ok_or(SolanaGigsError::TokenAccountNotFound)?;
+    require_keys_eq!(treasury_vault.key(), treasury.vault, SolanaGigsError::InvalidAccountData);
+    Ok(treasury_vault.to_account_info())
+}
+
+/// Prices a seller payout against the fee schedule and emits the breakdown.
+/// Volume tiers look at the seller's completed volume in the payout's mint
+/// before this payout.
+pub fn charge_fee(
+    fee_schedule: &FeeSchedule,
+    order: Pubkey,
+    category: ServiceCategory,
+    seller_profile: &UserProfile,
+    seller_volume: &SellerVolume,
+    amount: u64,
+) -> Result<u64> {
+    let quote = fee_schedule.quote(
+        category,
+        seller_profile.reputation_level,
+        seller_volume.mint,
+        seller_volume.total_volume,
+        amount,
+    )?;
+
+    emit!(FeeCharged {
+        order,
+        seller: seller_profile.authority,
+        category,
+        base_fee_bps: quote.base_fee_bps,
+        level_fee_bps: quote.level_fee_bps,
+        volume_discount_bps: quote.volume_discount_bps,
+        fee_bps: quote.fee_bps,
+        amount,
+        fee: quote.fee,
+    });
+
+    Ok(quote.fee)
+}
+
+#[event]
+pub struct FeeCharged {
+    pub order: Pubkey,
+    pub seller: Pubkey,
+    pub category: ServiceCategory,
+    pub base_fee_bps: u16,
+    pub level_fee_bps: Option<u16>,
+    pub volume_discount_bps: u16,
+    pub fee_bps: u16,
+    pub amount: u64,
+    pub fee: u64,
+}
+
+/// `transfer_checked` for both the legacy token program and Token-2022.
+/// Token-2022 transfers go through the on-chain helper so that any
+/// transfer-hook accounts passed as remaining accounts reach the hook program.
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_checked<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    from: AccountInfo<'info>,
+    mint: &InterfaceAccount<'info, Mint>,
+    to: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    amount: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    if token_program.key() == spl_token_2022::ID {
+        spl_token_2022::onchain::invoke_transfer_checked(
+            token_program.key,
+            from,
+            mint.to_account_info(),
+            to,
+            authority,
+            remaining_accounts,
+            amount,
+            mint.decimals,
+            signer_seeds,
+        )?;
+        return Ok(());
+    }
+
+    let cpi_accounts = TransferChecked {
+        from,
+        mint: mint.to_account_info(),
+        to,
+        authority,
+    };
+    let cpi_program = token_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+    token_interface::transfer_checked(cpi_ctx, amount, mint.decimals)
+}
+
+/// Moves lamports out of a program-owned account without a system transfer.
+pub fn debit_lamports<'info>(
+    from: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    let from_lamports = from.lamports()
+        .checked_sub(amount)
+        .ok_or(SolanaGigsError::InsufficientFunds)?;
+    let to_lamports = to.lamports()
+        .checked_add(amount)
+        .ok_or(SolanaGigsError::ArithmeticOverflow)?;
+    **from.try_borrow_mut_lamports()? = from_lamports;
+    **to.try_borrow_mut_lamports()? = to_lamports;
+    Ok(())
+}
+
+/// Reads a program account that may not have been created yet. Settlements
+/// take some PDAs unconditionally so callers cannot leave them out; `None`
+/// means the PDA is still empty.
+pub fn load_if_initialized<T: AccountDeserialize + Owner>(info: &AccountInfo) -> Result<Option<T>> {
+    if info.data_is_empty() {
+        return Ok(None);
+    }
+    require_keys_eq!(*info.owner, T::owner(), SolanaGigsError::InvalidAccountData);
+    Ok(Some(T::try_deserialize(&mut &info.try_borrow_data()?[..])?))
+}
+
+/// Writes back an account read with `load_if_initialized`.
+pub fn store<T: AccountSerialize>(info: &AccountInfo, account: &T) -> Result<()> {
+    let mut data = info.try_borrow_mut_data()?;
+    let mut writer: &mut [u8] = &mut data;
+    account.try_serialize(&mut writer)
+}
+
+/// Creates a program-owned PDA the accounts struct cannot `init` itself,
+/// such as one of a variable number passed as remaining accounts. Follows
+/// Anchor's `init`, so an address someone pre-funded is still claimable.
+pub fn create_pda_account<'info>(
+    payer: &AccountInfo<'info>,
+    account: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    require!(account.data_is_empty(), SolanaGigsError::InvalidAccountData);
+
+    let signer = &[signer_seeds];
+    let rent = Rent::get()?.minimum_balance(space);
+    let current_lamports = account.lamports();
+    if current_lamports == 0 {
+        let cpi_accounts = system_program::CreateAccount {
+            from: payer.clone(),
+            to: account.clone(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(system_program.clone(), cpi_accounts, signer);
+        return system_program::create_account(cpi_ctx, rent, space as u64, &crate::ID);
+    }
+
+    if current_lamports < rent {
+        let cpi_accounts = system_program::Transfer {
+            from: payer.clone(),
+            to: account.clone(),
+        };
+        let cpi_ctx = CpiContext::new(system_program.clone(), cpi_accounts);
+        system_program::transfer(cpi_ctx, rent - current_lamports)?;
+    }
+
+    let cpi_accounts = system_program::Allocate {
+        account_to_allocate: account.clone(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(system_program.clone(), cpi_accounts, signer);
+    system_program::allocate(cpi_ctx, space as u64)?;
+
+    let cpi_accounts = system_program::Assign {
+        account_to_assign: account.clone(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(system_program.clone(), cpi_accounts, signer);
+    system_program::assign(cpi_ctx, &crate::ID)
+}
+
+fn token_balance(token_account: &AccountInfo) -> Result<u64> {
+    Ok(TokenAccount::try_deserialize(&mut &token_account.try_borrow_data()?[..])?.amount)
+}
+
+/// Everything needed to pay out of an order's escrow, gathered once per
+/// instruction so each payout only names its destination.
+pub struct EscrowPayer<'a, 'info> {
+    pub escrow: &'a Account<'info, Escrow>,
+    pub vault: &'a Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    pub mint: &'a Option<Box<InterfaceAccount<'info, Mint>>>,
+    pub token_program: &'a Interface<'info, TokenInterface>,
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+}
+
+impl<'a, 'info> EscrowPayer<'a, 'info> {
+    /// Moves `amount` out of the escrow. Lamport escrows are debited directly
+    /// since the escrow PDA is program owned; SPL escrows sign for their vault.
+    pub fn transfer(&self, destination: AccountInfo<'info>, amount: u64) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+
+        if self.escrow.is_native {
+            return debit_lamports(&self.escrow.to_account_info(), &destination, amount);
+        }
+
+        let vault = self.vault.as_ref().ok_or(SolanaGigsError::TokenAccountNotFound)?;
+        let mint = self.mint.as_ref().ok_or(SolanaGigsError::InvalidTokenMint)?;
+        let seeds = &[
+            b"escrow",
+            self.escrow.order.as_ref(),
+            &[self.escrow.bump],
+        ];
+        let signer = &[&seeds[..]];
+
+        transfer_checked(
+            self.token_program,
+            vault.to_account_info(),
+            mint,
+            destination,
+            self.escrow.to_account_info(),
+            self.remaining_accounts,
+            amount,
+            signer,
+        )
+    }
+
+    /// Pays the seller's share. `earnings_info` is always the seller's
+    /// earnings PDA; once the seller has opened it the share is credited
+    /// there, otherwise it goes straight to their wallet or token account.
+    pub fn pay_seller(
+        &self,
+        earnings_info: &AccountInfo<'info>,
+        earnings_vault: &Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+        token_account: &Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+        wallet: &Option<SystemAccount<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        match load_if_initialized::<UserEarnings>(earnings_info)? {
+            Some(mut earnings) => {
+                self.credit_earnings(earnings_info, &mut earnings, earnings_vault, amount)?;
+                store(earnings_info, &earnings)
+            }
+            None => {
+                let destination = payout_account(self.escrow.is_native, token_account, wallet)?;
+                self.transfer(destination, amount)
+            }
+        }
+    }
+
+    /// Pays a referrer their cut of the marketplace fee. `referral` is always
+    /// the user's referral PDA; only users who never registered a referrer
+    /// pay nothing. The split is returned for the event.
+    pub fn pay_referrer(
+        &self,
+        referral_info: &AccountInfo<'info>,
+        token_account: &Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+        wallet: &Option<SystemAccount<'info>>,
+        amount: u64,
+    ) -> Result<Option<ReferralSplit>> {
+        let Some(mut referral) = load_if_initialized::<Referral>(referral_info)? else {
+            return Ok(None);
+        };
+        if amount == 0 {
+            return Ok(None);
+        }
+
+        let destination = payout_account(self.escrow.is_native, token_account, wallet)?;
+        let recipient = match token_account {
+            Some(token_account) if !self.escrow.is_native => token_account.owner,
+            _ => destination.key(),
+        };
+        require_keys_eq!(recipient, referral.referrer, SolanaGigsError::InvalidReferralCode);
+
+        self.transfer(destination, amount)?;
+        referral.record_reward(amount)?;
+        store(referral_info, &referral)?;
+
+        Ok(Some(ReferralSplit {
+            user: referral.user,
+            referrer: referral.referrer,
+            amount,
+        }))
+    }
+
+    /// Pays the seller's share into their earnings account instead of their
+    /// wallet. Only what actually reaches the earnings vault is credited, so
+    /// transfer-fee mints never leave the ledger ahead of the vault.
+    pub fn credit_earnings(
+        &self,
+        earnings_info: &AccountInfo<'info>,
+        earnings: &mut UserEarnings,
+        earnings_vault: &Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+        amount: u64,
+    ) -> Result<()> {
+        require_keys_eq!(earnings.mint, self.escrow.mint, SolanaGigsError::InvalidTokenMint);
+        if amount == 0 {
+            return Ok(());
+        }
+
+        let credited = if self.escrow.is_native {
+            self.transfer(earnings_info.clone(), amount)?;
+            amount
+        } else {
+            let earnings_vault = earnings_vault.as_ref()
+                .ok_or(SolanaGigsError::TokenAccountNotFound)?;
+            require_keys_eq!(earnings_vault.key(), earnings.vault, SolanaGigsError::InvalidAccountData);
+            let vault_info = earnings_vault.to_account_info();
+            let balance_before = token_balance(&vault_info)?;
+            self.transfer(vault_info.clone(), amount)?;
+            token_balance(&vault_info)?.saturating_sub(balance_before)
+        };
+
+        earnings.credit(credited, Clock::get()?.unix_timestamp)
+    }
+
+    /// Drains the vault to exactly zero and closes it once an escrow is fully
+    /// settled. Any balance the escrow books do not account for is swept to
+    /// the mint's treasury vault; the vault rent goes back to the buyer who
+    /// funded it.
+    pub fn close_vault(
+        &self,
+        surplus_destination: &Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+        buyer: Option<AccountInfo<'info>>,
+    ) -> Result<()> {
+        if self.escrow.is_native {
+            return Ok(());
+        }
+
+        let buyer = buyer.ok_or(SolanaGigsError::InvalidAccountData)?;
+        let vault = self.vault.as_ref().ok_or(SolanaGigsError::TokenAccountNotFound)?;
+        let vault_info = vault.to_account_info();
+        let balance = token_balance(&vault_info)?;
+        if balance > 0 {
+            let surplus_destination = surplus_destination
+                .as_ref()
+                .ok_or(SolanaGigsError::TokenAccountNotFound)?;
+            self.transfer(surplus_destination.to_account_info(), balance)?;
+        }
+        self.harvest_withheld_fees(&vault_info)?;
+
+        let seeds = &[
+            b"escrow",
+            self.escrow.order.as_ref(),
+            &[self.escrow.bump],
+        ];
+        let signer = &[&seeds[..]];
+
+        let cpi_accounts = CloseAccount {
+            account: vault_info,
+            destination: buyer,
+            authority: self.escrow.to_account_info(),
+        };
+        let cpi_program = self.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        token_interface::close_account(cpi_ctx)
+    }
+
+    /// Token-2022 refuses to close an account that still holds withheld
+    /// transfer fees, so they are harvested back to the mint first. Harvesting
+    /// is permissionless and only needs the mint writable.
+    fn harvest_withheld_fees(&self, vault_info: &AccountInfo<'info>) -> Result<()> {
+        if self.token_program.key() != spl_token_2022::ID {
+            return Ok(());
+        }
+
+        let withheld = {
+            let data = vault_info.try_borrow_data()?;
+            let vault = StateWithExtensions::<spl_token_2022::state::Account>::unpack(&data)?;
+            vault
+                .get_extension::<TransferFeeAmount>()
+                .map(|fee| u64::from(fee.withheld_amount))
+                .unwrap_or(0)
+        };
+        if withheld == 0 {
+            return Ok(());
+        }
+
+        let mint = self.mint.as_ref().ok_or(SolanaGigsError::InvalidTokenMint)?;
+        let ix = harvest_withheld_tokens_to_mint(
+            &spl_token_2022::ID,
+            &mint.key(),
+            &[&vault_info.key()],
+        )?;
+        invoke(&ix, &[mint.to_account_info(), vault_info.clone()])?;
+
+        Ok(())
+    }
+}
+
+/// Pays out of a seller's earnings account, the counterpart of `EscrowPayer`.
+pub struct EarningsPayer<'a, 'info> {
+    pub earnings: &'a Account<'info, UserEarnings>,
+    pub vault: &'a Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    pub mint: &'a Option<Box<InterfaceAccount<'info, Mint>>>,
+    pub token_program: &'a Interface<'info, TokenInterface>,
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+}
+
+impl<'a, 'info> EarningsPayer<'a, 'info> {
+    pub fn transfer(&self, destination: AccountInfo<'info>, amount: u64) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+
+        if self.earnings.is_native {
+            return debit_lamports(&self.earnings.to_account_info(), &destination, amount);
+        }
+
+        let vault = self.vault.as_ref().ok_or(SolanaGigsError::TokenAccountNotFound)?;
+        require_keys_eq!(vault.key(), self.earnings.vault, SolanaGigsError::InvalidAccountData);
+        let mint = self.mint.as_ref().ok_or(SolanaGigsError::InvalidTokenMint)?;
+        let seeds = &[
+            b"earnings",
+            self.earnings.user.as_ref(),
+            self.earnings.mint.as_ref(),
+            &[self.earnings.bump],
+        ];
+        let signer = &[&seeds[..]];
+
+        transfer_checked(
+            self.token_program,
+            vault.to_account_info(),
+            mint,
+            destination,
+            self.earnings.to_account_info(),
+            self.remaining_accounts,
+            amount,
+            signer,
+        )
+    }
+}
@@ -0,0 +1,199 @@
+mod common;
+
+use anchor_lang::solana_program::{instruction::Instruction, pubkey::Pubkey};
+use anchor_spl::token::spl_token;
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+use common::*;
+use solanagigs::errors::SolanaGigsError;
+use solanagigs::state::{AdminRole, Marketplace};
+
+fn assign_role_ix(authority: Pubkey, role: AdminRole, holder: Pubkey) -> Instruction {
+    instruction(
+        solanagigs::accounts::AssignRole {
+            marketplace: marketplace_pda(),
+            authority,
+        },
+        solanagigs::instruction::AssignRole { role, holder },
+    )
+}
+
+fn pause_ix(pauser: Pubkey, emergency: bool) -> Instruction {
+    instruction(
+        solanagigs::accounts::PauseMarketplace {
+            marketplace: marketplace_pda(),
+            pauser,
+        },
+        solanagigs::instruction::Pause { emergency },
+    )
+}
+
+fn unpause_ix(pauser: Pubkey) -> Instruction {
+    instruction(
+        solanagigs::accounts::PauseMarketplace {
+            marketplace: marketplace_pda(),
+            pauser,
+        },
+        solanagigs::instruction::Unpause {},
+    )
+}
+
+async fn transfer_authority(env: &mut TestEnv, new_authority: &Keypair) {
+    let payer = env.payer();
+    let nominate = instruction(
+        solanagigs::accounts::NominateAuthority {
+            marketplace: marketplace_pda(),
+            authority: payer,
+        },
+        solanagigs::instruction::NominateAuthority {
+            new_authority: Some(new_authority.pubkey()),
+        },
+    );
+    env.process(&[nominate], &[]).await.unwrap();
+
+    let accept = instruction(
+        solanagigs::accounts::AcceptAuthority {
+            marketplace: marketplace_pda(),
+            new_authority: new_authority.pubkey(),
+        },
+        solanagigs::instruction::AcceptAuthority {},
+    );
+    env.process(&[accept], &[new_authority]).await.unwrap();
+}
+
+#[tokio::test]
+async fn only_the_nominee_can_accept_authority() {
+    let mut env = TestEnv::start().await;
+    env.initialize_marketplace(500).await;
+    let nominee = env.new_wallet(SOL).await;
+    let intruder = env.new_wallet(SOL).await;
+
+    let payer = env.payer();
+    let nominate = instruction(
+        solanagigs::accounts::NominateAuthority {
+            marketplace: marketplace_pda(),
+            authority: payer,
+        },
+        solanagigs::instruction::NominateAuthority {
+            new_authority: Some(nominee.pubkey()),
+        },
+    );
+    env.process(&[nominate], &[]).await.unwrap();
+
+    let accept = instruction(
+        solanagigs::accounts::AcceptAuthority {
+            marketplace: marketplace_pda(),
+            new_authority: intruder.pubkey(),
+        },
+        solanagigs::instruction::AcceptAuthority {},
+    );
+    assert_error(env.process(&[accept], &[&intruder]).await, SolanaGigsError::Unauthorized);
+
+    let marketplace: Marketplace = env.get(marketplace_pda()).await;
+    assert_eq!(marketplace.authority, payer);
+    assert_eq!(marketplace.pending_authority, Some(nominee.pubkey()));
+}
+
+#[tokio::test]
+async fn accepting_authority_revokes_the_old_roles() {
+    let mut env = TestEnv::start().await;
+    env.initialize_marketplace(500).await;
+    let payer = env.payer();
+    let pauser = env.new_wallet(SOL).await;
+    let new_authority = env.new_wallet(SOL).await;
+
+    env.process(&[assign_role_ix(payer, AdminRole::Pauser, pauser.pubkey())], &[])
+        .await
+        .unwrap();
+    transfer_authority(&mut env, &new_authority).await;
+
+    let marketplace: Marketplace = env.get(marketplace_pda()).await;
+    assert_eq!(marketplace.authority, new_authority.pubkey());
+    assert_eq!(marketplace.pending_authority, None);
+    for role in [
+        AdminRole::FeeManager,
+        AdminRole::Pauser,
+        AdminRole::DisputeAdmin,
+        AdminRole::CategoryCurator,
+        AdminRole::Verifier,
+    ] {
+        assert_eq!(marketplace.role_holder(role), new_authority.pubkey());
+    }
+
+    assert_error(
+        env.process(&[pause_ix(payer, false)], &[]).await,
+        SolanaGigsError::AdminPrivilegesRequired,
+    );
+    assert_error(
+        env.process(&[pause_ix(pauser.pubkey(), false)], &[&pauser]).await,
+        SolanaGigsError::AdminPrivilegesRequired,
+    );
+    env.process(&[pause_ix(new_authority.pubkey(), false)], &[&new_authority])
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn delegated_roles_can_act_without_the_authority() {
+    let mut env = TestEnv::start().await;
+    env.initialize_marketplace(500).await;
+    let payer = env.payer();
+    let pauser = env.new_wallet(SOL).await;
+
+    env.process(&[assign_role_ix(payer, AdminRole::Pauser, pauser.pubkey())], &[])
+        .await
+        .unwrap();
+
+    assert_error(
+        env.process(&[pause_ix(payer, true)], &[]).await,
+        SolanaGigsError::AdminPrivilegesRequired,
+    );
+    env.process(&[pause_ix(pauser.pubkey(), true)], &[&pauser]).await.unwrap();
+
+    let marketplace: Marketplace = env.get(marketplace_pda()).await;
+    assert!(marketplace.is_paused);
+    assert!(marketplace.is_emergency_stopped);
+}
+
+#[tokio::test]
+async fn pause_stops_new_orders_but_not_settlement() {
+    let mut env = TestEnv::start().await;
+    let mint = env.create_mint(spl_token::ID, None).await;
+    let market = env.market(mint, 500, 1_000_000).await;
+    let payer = env.payer();
+    let order = env.delivered_order(&market).await;
+
+    env.process(&[pause_ix(payer, false)], &[]).await.unwrap();
+    assert_error(
+        env.try_create_order(&market, Vec::new()).await.map(|_| ()),
+        SolanaGigsError::ProgramIsPaused,
+    );
+    env.complete_order(&market, order).await.unwrap();
+
+    env.process(&[unpause_ix(payer)], &[]).await.unwrap();
+    env.create_order(&market).await;
+}
+
+#[tokio::test]
+async fn emergency_stop_freezes_escrowed_funds() {
+    let mut env = TestEnv::start().await;
+    let mint = env.create_mint(spl_token::ID, None).await;
+    let market = env.market(mint, 500, 1_000_000).await;
+    let payer = env.payer();
+    let order = env.delivered_order(&market).await;
+
+    env.process(&[pause_ix(payer, true)], &[]).await.unwrap();
+    assert_error(
+        env.complete_order(&market, order).await,
+        SolanaGigsError::EmergencyStopActivated,
+    );
+    assert_error(
+        env.try_create_order(&market, Vec::new()).await.map(|_| ()),
+        SolanaGigsError::ProgramIsPaused,
+    );
+
+    env.process(&[unpause_ix(payer)], &[]).await.unwrap();
+    env.complete_order(&market, order).await.unwrap();
+    let marketplace: Marketplace = env.get(marketplace_pda()).await;
+    assert!(!marketplace.is_paused && !marketplace.is_emergency_stopped);
+}
@@ -0,0 +1,115 @@
+mod common;
+
+use anchor_lang::solana_program::pubkey::Pubkey;
+use anchor_spl::token::spl_token;
+use solana_program_test::BanksClientError;
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+use common::*;
+use solanagigs::errors::SolanaGigsError;
+use solanagigs::state::{Order, OrderStatus};
+
+fn cancellation_pda(order: &Pubkey) -> Pubkey {
+    pda(&[b"cancellation", order.as_ref()])
+}
+
+async fn propose_cancellation(
+    env: &mut TestEnv,
+    order: Pubkey,
+    proposer: &Keypair,
+    refund_bps: u16,
+) -> Result<(), BanksClientError> {
+    let ix = instruction(
+        solanagigs::accounts::ProposeCancellation {
+            order,
+            proposal: cancellation_pda(&order),
+            proposer: proposer.pubkey(),
+            system_program: anchor_lang::system_program::ID,
+        },
+        solanagigs::instruction::ProposeCancellation {
+            refund_bps,
+            reason: "Scope changed".to_string(),
+        },
+    );
+    env.process(&[ix], &[proposer]).await
+}
+
+async fn accept_cancellation(
+    env: &mut TestEnv,
+    market: &Market,
+    order: Pubkey,
+    proposer: Pubkey,
+    signer: &Keypair,
+) -> Result<(), BanksClientError> {
+    let native = market.is_native();
+    let ix = instruction(
+        solanagigs::accounts::AcceptCancellation {
+            marketplace: marketplace_pda(),
+            fee_schedule: fee_schedule_pda(),
+            gig: market.gig,
+            order,
+            escrow: escrow_pda(&order),
+            proposal: cancellation_pda(&order),
+            vault: (!native).then(|| escrow_vault_pda(&order)),
+            mint: (!native).then_some(market.mint),
+            seller_profile: user_pda(&market.seller.pubkey()),
+            seller_volume: volume_pda(&market.seller.pubkey(), &market.mint),
+            buyer_token_account: market.buyer_token,
+            seller_token_account: market.seller_token,
+            seller_earnings: earnings_pda(&market.seller.pubkey(), &market.mint),
+            earnings_vault: None,
+            treasury: treasury_pda(&market.mint),
+            treasury_vault: (!native).then(|| treasury_vault_pda(&market.mint)),
+            buyer_wallet: Some(market.buyer.pubkey()),
+            seller: native.then(|| market.seller.pubkey()),
+            proposer,
+            signer: signer.pubkey(),
+            token_program: market.token_program,
+        },
+        solanagigs::instruction::AcceptCancellation {},
+    );
+    env.process(&[ix], &[signer]).await
+}
+
+#[tokio::test]
+async fn agreed_cancellation_splits_the_escrow() {
+    let mut env = TestEnv::start().await;
+    let mint = env.create_mint(spl_token::ID, None).await;
+    let market = env.market(mint, 500, 1_000_000).await;
+    let buyer_token = market.buyer_token.unwrap();
+    let funded = env.token_balance(buyer_token).await;
+
+    let order = env.create_order(&market).await;
+    env.accept_order(&market, order).await;
+    propose_cancellation(&mut env, order, &market.seller, 6_000).await.unwrap();
+
+    let seller = market.seller.pubkey();
+    assert_error(
+        accept_cancellation(&mut env, &market, order, seller, &market.seller).await,
+        SolanaGigsError::CannotAcceptOwnProposal,
+    );
+    accept_cancellation(&mut env, &market, order, seller, &market.buyer).await.unwrap();
+
+    // 60% back to the buyer; the fee only applies to the seller's 40%
+    assert_eq!(env.token_balance(buyer_token).await, funded - 400_000);
+    assert_eq!(env.token_balance(market.seller_token.unwrap()).await, 380_000);
+    assert_eq!(env.token_balance(treasury_vault_pda(&mint)).await, 20_000);
+    assert!(env.account(escrow_vault_pda(&order)).await.is_none());
+    assert!(env.account(cancellation_pda(&order)).await.is_none());
+
+    let state: Order = env.get(order).await;
+    assert_eq!(state.status, OrderStatus::Cancelled);
+}
+
+#[tokio::test]
+async fn pending_orders_cannot_be_cancelled_by_proposal() {
+    let mut env = TestEnv::start().await;
+    let mint = env.create_mint(spl_token::ID, None).await;
+    let market = env.market(mint, 500, 1_000_000).await;
+    let order = env.create_order(&market).await;
+
+    assert_error(
+        propose_cancellation(&mut env, order, &market.buyer, 10_000).await,
+        SolanaGigsError::CannotCancelOrder,
+    );
+}
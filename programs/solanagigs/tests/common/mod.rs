@@ -0,0 +1,1076 @@
+#![allow(dead_code)]
+
+use anchor_lang::solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, instruction::Instruction,
+    program_option::COption, program_pack::Pack, pubkey::Pubkey, system_instruction,
+    sysvar::slot_hashes,
+};
+use anchor_lang::{AccountDeserialize, InstructionData, ToAccountMetas};
+use anchor_spl::token::spl_token::{self, native_mint};
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{
+        transfer_fee::{self, TransferFeeAmount, TransferFeeConfig},
+        BaseStateWithExtensions, ExtensionType, StateWithExtensions,
+    },
+};
+use solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account::Account, instruction::AccountMeta, clock::Clock, compute_budget::ComputeBudgetInstruction,
+    signature::Keypair, signer::Signer, transaction::Transaction,
+};
+
+use solanagigs::instructions::{CreateGigParams, MilestoneParams, UpdateMarketplaceParams};
+use solanagigs::state::{ArbiterTier, Dispute, DisputeResolution, GigPackage, JuryCase, JurorStake, ServiceCategory};
+
+pub const SOL: u64 = 1_000_000_000;
+pub const DAY: i64 = 86_400;
+
+fn process_instruction(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    // Anchor ties every account to the lifetime of the slice, which the
+    // native test runtime cannot promise, so the slice is leaked instead.
+    let accounts = Box::leak(Box::new(accounts.to_vec()));
+    solanagigs::entry(program_id, accounts, data)
+}
+
+pub fn pda(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &solanagigs::ID).0
+}
+
+pub fn marketplace_pda() -> Pubkey {
+    pda(&[b"marketplace"])
+}
+
+pub fn fee_schedule_pda() -> Pubkey {
+    pda(&[b"fee_schedule"])
+}
+
+pub fn accepted_mint_pda(mint: &Pubkey) -> Pubkey {
+    pda(&[b"accepted_mint", mint.as_ref()])
+}
+
+pub fn treasury_pda(mint: &Pubkey) -> Pubkey {
+    pda(&[b"treasury", mint.as_ref()])
+}
+
+pub fn treasury_vault_pda(mint: &Pubkey) -> Pubkey {
+    pda(&[b"treasury_vault", mint.as_ref()])
+}
+
+pub fn user_pda(user: &Pubkey) -> Pubkey {
+    pda(&[b"user", user.as_ref()])
+}
+
+pub fn gig_pda(id: u64) -> Pubkey {
+    pda(&[b"gig", id.to_le_bytes().as_ref()])
+}
+
+pub fn order_pda(id: u64) -> Pubkey {
+    pda(&[b"order", id.to_le_bytes().as_ref()])
+}
+
+pub fn escrow_pda(order: &Pubkey) -> Pubkey {
+    pda(&[b"escrow", order.as_ref()])
+}
+
+pub fn milestone_pda(order: &Pubkey, id: u8) -> Pubkey {
+    pda(&[b"milestone", order.as_ref(), &[id]])
+}
+
+pub fn escrow_vault_pda(order: &Pubkey) -> Pubkey {
+    pda(&[b"escrow_vault", order.as_ref()])
+}
+
+pub fn earnings_pda(user: &Pubkey, mint: &Pubkey) -> Pubkey {
+    pda(&[b"earnings", user.as_ref(), mint.as_ref()])
+}
+
+pub fn volume_pda(seller: &Pubkey, mint: &Pubkey) -> Pubkey {
+    pda(&[b"volume", seller.as_ref(), mint.as_ref()])
+}
+
+pub fn earnings_vault_pda(earnings: &Pubkey) -> Pubkey {
+    pda(&[b"earnings_vault", earnings.as_ref()])
+}
+
+pub fn withdrawal_pda(earnings: &Pubkey, id: u64) -> Pubkey {
+    pda(&[b"withdrawal", earnings.as_ref(), id.to_le_bytes().as_ref()])
+}
+
+pub fn referral_pda(user: &Pubkey) -> Pubkey {
+    pda(&[b"referral", user.as_ref()])
+}
+
+pub fn arbiter_pda(index: u32) -> Pubkey {
+    pda(&[b"arbiter", index.to_le_bytes().as_ref()])
+}
+
+pub fn dispute_pda(order: &Pubkey) -> Pubkey {
+    pda(&[b"dispute", order.as_ref()])
+}
+
+pub fn appeal_pda(order: &Pubkey) -> Pubkey {
+    pda(&[b"appeal", dispute_pda(order).as_ref()])
+}
+
+pub fn juror_pda(juror: &Pubkey) -> Pubkey {
+    pda(&[b"juror", juror.as_ref()])
+}
+
+pub fn jury_pda(order: &Pubkey) -> Pubkey {
+    pda(&[b"jury", dispute_pda(order).as_ref()])
+}
+
+pub const JUROR_SALT: [u8; 32] = [7; 32];
+
+pub fn instruction(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: solanagigs::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+/// Pulls the custom error code out of a failed transaction so tests can
+/// compare it against `SolanaGigsError`.
+pub fn error_code(err: BanksClientError) -> u32 {
+    use solana_sdk::{instruction::InstructionError, transaction::TransactionError};
+    match err.unwrap() {
+        TransactionError::InstructionError(_, InstructionError::Custom(code)) => code,
+        other => panic!("expected a custom program error, got {other:?}"),
+    }
+}
+
+pub fn assert_error(result: Result<(), BanksClientError>, expected: solanagigs::errors::SolanaGigsError) {
+    let err = result.expect_err("transaction should have failed");
+    assert_eq!(error_code(err), 6000 + expected as u32);
+}
+
+/// A registered arbiter and the wallet that signs for it.
+pub struct ArbiterKeys {
+    pub authority: Keypair,
+    pub arbiter: Pubkey,
+}
+
+/// Staked jurors in registry order.
+pub struct Jury {
+    pub jurors: Vec<Keypair>,
+}
+
+impl Jury {
+    pub fn juror(&self, key: &Pubkey) -> &Keypair {
+        self.jurors.iter().find(|juror| juror.pubkey() == *key).unwrap()
+    }
+}
+
+/// A marketplace, one payment mint and a seller with a single-package gig.
+pub struct Market {
+    pub mint: Pubkey,
+    pub token_program: Pubkey,
+    pub seller: Keypair,
+    pub buyer: Keypair,
+    pub gig: Pubkey,
+    pub seller_token: Option<Pubkey>,
+    pub buyer_token: Option<Pubkey>,
+}
+
+impl Market {
+    pub fn is_native(&self) -> bool {
+        self.mint == native_mint::ID
+    }
+}
+
+pub struct TestEnv {
+    pub ctx: ProgramTestContext,
+    nonce: u64,
+}
+
+impl TestEnv {
+    pub async fn start() -> Self {
+        let mut program_test = ProgramTest::new("solanagigs", solanagigs::ID, processor!(process_instruction));
+
+        let mut native_mint_data = vec![0; spl_token::state::Mint::LEN];
+        spl_token::state::Mint {
+            mint_authority: COption::None,
+            supply: 0,
+            decimals: 9,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        }
+        .pack_into_slice(&mut native_mint_data);
+        program_test.add_account(
+            native_mint::ID,
+            Account {
+                lamports: SOL,
+                data: native_mint_data,
+                owner: spl_token::ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+
+        Self {
+            ctx: program_test.start_with_context().await,
+            nonce: 0,
+        }
+    }
+
+    pub fn payer(&self) -> Pubkey {
+        self.ctx.payer.pubkey()
+    }
+
+    /// Sends one transaction signed by the payer and `signers`. A unique
+    /// compute price keeps otherwise identical transactions from being
+    /// deduplicated.
+    pub async fn process(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> Result<(), BanksClientError> {
+        self.nonce += 1;
+        let mut all = vec![
+            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+            ComputeBudgetInstruction::set_compute_unit_price(self.nonce),
+        ];
+        all.extend_from_slice(instructions);
+
+        let mut all_signers = vec![&self.ctx.payer];
+        all_signers.extend_from_slice(signers);
+        let tx = Transaction::new_signed_with_payer(
+            &all,
+            Some(&self.ctx.payer.pubkey()),
+            &all_signers,
+            self.ctx.last_blockhash,
+        );
+        self.ctx.banks_client.process_transaction(tx).await
+    }
+
+    pub async fn account(&mut self, address: Pubkey) -> Option<Account> {
+        self.ctx.banks_client.get_account(address).await.unwrap()
+    }
+
+    pub async fn get<T: AccountDeserialize>(&mut self, address: Pubkey) -> T {
+        let account = self.account(address).await.expect("account does not exist");
+        T::try_deserialize(&mut account.data.as_slice()).unwrap()
+    }
+
+    pub async fn lamports(&mut self, address: Pubkey) -> u64 {
+        self.account(address).await.map_or(0, |account| account.lamports)
+    }
+
+    pub async fn now(&mut self) -> i64 {
+        self.ctx.banks_client.get_sysvar::<Clock>().await.unwrap().unix_timestamp
+    }
+
+    pub async fn advance_time(&mut self, seconds: i64) {
+        let mut clock = self.ctx.banks_client.get_sysvar::<Clock>().await.unwrap();
+        clock.unix_timestamp += seconds;
+        self.ctx.set_sysvar(&clock);
+    }
+
+    pub async fn advance_slots(&mut self, slots: u64) {
+        let clock = self.ctx.banks_client.get_sysvar::<Clock>().await.unwrap();
+        let unix_timestamp = clock.unix_timestamp;
+        self.ctx.warp_to_slot(clock.slot + slots).unwrap();
+        // Warping re-derives the clock, so keep any time already advanced
+        let mut clock = self.ctx.banks_client.get_sysvar::<Clock>().await.unwrap();
+        clock.unix_timestamp = clock.unix_timestamp.max(unix_timestamp);
+        self.ctx.set_sysvar(&clock);
+    }
+
+    pub async fn new_wallet(&mut self, lamports: u64) -> Keypair {
+        let wallet = Keypair::new();
+        let payer = self.payer();
+        self.process(&[system_instruction::transfer(&payer, &wallet.pubkey(), lamports)], &[])
+            .await
+            .unwrap();
+        wallet
+    }
+
+    pub async fn new_user(&mut self, username: &str) -> Keypair {
+        let user = self.new_wallet(100 * SOL).await;
+        let ix = instruction(
+            solanagigs::accounts::InitializeUser {
+                user_profile: user_pda(&user.pubkey()),
+                authority: user.pubkey(),
+                system_program: anchor_lang::system_program::ID,
+            },
+            solanagigs::instruction::InitializeUser {
+                username: username.to_string(),
+                bio: String::new(),
+                profile_image_url: String::new(),
+                skills: Vec::new(),
+                hourly_rate: 0,
+            },
+        );
+        self.process(&[ix], &[&user]).await.unwrap();
+        user
+    }
+
+    pub async fn initialize_marketplace(&mut self, default_fee_bps: u16) {
+        let payer = self.payer();
+        let ix = instruction(
+            solanagigs::accounts::InitializeMarketplace {
+                marketplace: marketplace_pda(),
+                fee_schedule: fee_schedule_pda(),
+                treasury: treasury_pda(&native_mint::ID),
+                authority: payer,
+                system_program: anchor_lang::system_program::ID,
+            },
+            solanagigs::instruction::InitializeMarketplace {
+                default_fee_bps,
+                dispute_period: None,
+            },
+        );
+        self.process(&[ix], &[]).await.unwrap();
+    }
+
+    pub async fn update_marketplace(&mut self, params: UpdateMarketplaceParams) -> Result<(), BanksClientError> {
+        let payer = self.payer();
+        let ix = instruction(
+            solanagigs::accounts::UpdateMarketplace {
+                marketplace: marketplace_pda(),
+                admin: payer,
+            },
+            solanagigs::instruction::UpdateMarketplace { params },
+        );
+        self.process(&[ix], &[]).await
+    }
+
+    /// Creates a mint owned by `token_program`, optionally carrying a
+    /// Token-2022 transfer fee.
+    pub async fn create_mint(&mut self, token_program: Pubkey, transfer_fee_bps: Option<u16>) -> Pubkey {
+        let mint = Keypair::new();
+        let payer = self.payer();
+        let extensions = match transfer_fee_bps {
+            Some(_) => vec![ExtensionType::TransferFeeConfig],
+            None => Vec::new(),
+        };
+        let space = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&extensions).unwrap();
+        let rent = self.ctx.banks_client.get_rent().await.unwrap();
+
+        let mut ixs = vec![system_instruction::create_account(
+            &payer,
+            &mint.pubkey(),
+            rent.minimum_balance(space),
+            space as u64,
+            &token_program,
+        )];
+        if let Some(fee_bps) = transfer_fee_bps {
+            ixs.push(
+                transfer_fee::instruction::initialize_transfer_fee_config(
+                    &token_program,
+                    &mint.pubkey(),
+                    None,
+                    None,
+                    fee_bps,
+                    u64::MAX,
+                )
+                .unwrap(),
+            );
+        }
+        ixs.push(
+            spl_token_2022::instruction::initialize_mint2(&token_program, &mint.pubkey(), &payer, None, 6)
+                .unwrap(),
+        );
+        self.process(&ixs, &[&mint]).await.unwrap();
+        mint.pubkey()
+    }
+
+    pub async fn create_token_account(&mut self, mint: Pubkey, owner: Pubkey) -> Pubkey {
+        let mint_account = self.account(mint).await.unwrap();
+        let token_program = mint_account.owner;
+        let mint_state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_account.data).unwrap();
+        let required = ExtensionType::get_required_init_account_extensions(&mint_state.get_extension_types().unwrap());
+        let space = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Account>(&required).unwrap();
+        let rent = self.ctx.banks_client.get_rent().await.unwrap();
+
+        let account = Keypair::new();
+        let payer = self.payer();
+        let ixs = [
+            system_instruction::create_account(
+                &payer,
+                &account.pubkey(),
+                rent.minimum_balance(space),
+                space as u64,
+                &token_program,
+            ),
+            spl_token_2022::instruction::initialize_account3(&token_program, &account.pubkey(), &mint, &owner)
+                .unwrap(),
+        ];
+        self.process(&ixs, &[&account]).await.unwrap();
+        account.pubkey()
+    }
+
+    pub async fn mint_to(&mut self, mint: Pubkey, account: Pubkey, amount: u64) {
+        let token_program = self.account(mint).await.unwrap().owner;
+        let payer = self.payer();
+        let ix = spl_token_2022::instruction::mint_to(&token_program, &mint, &account, &payer, &[], amount).unwrap();
+        self.process(&[ix], &[]).await.unwrap();
+    }
+
+    pub async fn token_balance(&mut self, account: Pubkey) -> u64 {
+        let account = self.account(account).await.expect("token account does not exist");
+        StateWithExtensions::<spl_token_2022::state::Account>::unpack(&account.data)
+            .unwrap()
+            .base
+            .amount
+    }
+
+    pub async fn withheld_in_account(&mut self, account: Pubkey) -> u64 {
+        let account = self.account(account).await.expect("token account does not exist");
+        let state = StateWithExtensions::<spl_token_2022::state::Account>::unpack(&account.data).unwrap();
+        state.get_extension::<TransferFeeAmount>().map_or(0, |fee| u64::from(fee.withheld_amount))
+    }
+
+    pub async fn withheld_in_mint(&mut self, mint: Pubkey) -> u64 {
+        let account = self.account(mint).await.unwrap();
+        let state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&account.data).unwrap();
+        state.get_extension::<TransferFeeConfig>().map_or(0, |fee| u64::from(fee.withheld_amount))
+    }
+
+    pub async fn add_accepted_mint(&mut self, mint: Pubkey, min_price: u64, max_price: u64, withdrawal_threshold: u64) {
+        let payer = self.payer();
+        let is_native = mint == native_mint::ID;
+        let token_program = self.account(mint).await.unwrap().owner;
+        let ix = instruction(
+            solanagigs::accounts::AddAcceptedMint {
+                marketplace: marketplace_pda(),
+                accepted_mint: accepted_mint_pda(&mint),
+                treasury: (!is_native).then(|| treasury_pda(&mint)),
+                treasury_vault: (!is_native).then(|| treasury_vault_pda(&mint)),
+                mint,
+                authority: payer,
+                token_program,
+                system_program: anchor_lang::system_program::ID,
+            },
+            solanagigs::instruction::AddAcceptedMint {
+                min_price,
+                max_price,
+                withdrawal_threshold,
+            },
+        );
+        self.process(&[ix], &[]).await.unwrap();
+    }
+
+    pub async fn update_accepted_mint(
+        &mut self,
+        mint: Pubkey,
+        max_price: Option<u64>,
+        withdrawal_threshold: Option<u64>,
+    ) -> Result<(), BanksClientError> {
+        let payer = self.payer();
+        let ix = instruction(
+            solanagigs::accounts::UpdateAcceptedMint {
+                marketplace: marketplace_pda(),
+                accepted_mint: accepted_mint_pda(&mint),
+                authority: payer,
+            },
+            solanagigs::instruction::UpdateAcceptedMint {
+                min_price: None,
+                max_price,
+                withdrawal_threshold,
+                is_active: None,
+            },
+        );
+        self.process(&[ix], &[]).await
+    }
+
+    pub async fn create_gig(&mut self, seller: &Keypair, mint: Pubkey, category: ServiceCategory, price: u64) -> Pubkey {
+        let marketplace: solanagigs::state::Marketplace = self.get(marketplace_pda()).await;
+        let gig = gig_pda(marketplace.total_gigs);
+        let ix = instruction(
+            solanagigs::accounts::CreateGig {
+                marketplace: marketplace_pda(),
+                gig,
+                seller_profile: user_pda(&seller.pubkey()),
+                payment_mint: mint,
+                accepted_mint: accepted_mint_pda(&mint),
+                seller: seller.pubkey(),
+                system_program: anchor_lang::system_program::ID,
+            },
+            solanagigs::instruction::CreateGig {
+                params: CreateGigParams {
+                    title: "Logo design".to_string(),
+                    description: "A custom logo".to_string(),
+                    category,
+                    subcategory: String::new(),
+                    tags: Vec::new(),
+                    images: Vec::new(),
+                    requirements: String::new(),
+                    price,
+                    delivery_time: 3,
+                    revisions: 1,
+                    packages: vec![GigPackage {
+                        name: "Basic".to_string(),
+                        description: String::new(),
+                        price,
+                        delivery_time: 3,
+                        revisions: 1,
+                        features: Vec::new(),
+                    }],
+                    extras: Vec::new(),
+                },
+            },
+        );
+        self.process(&[ix], &[seller]).await.unwrap();
+        gig
+    }
+
+    /// Sets up a marketplace charging `fee_bps` and a seller's gig priced at
+    /// `price` in `mint`, with a funded buyer ready to order it.
+    pub async fn market(&mut self, mint: Pubkey, fee_bps: u16, price: u64) -> Market {
+        self.initialize_marketplace(fee_bps).await;
+        self.market_for(mint, price).await
+    }
+
+    /// Like `market`, but on a marketplace that already exists.
+    pub async fn market_for(&mut self, mint: Pubkey, price: u64) -> Market {
+        let is_native = mint == native_mint::ID;
+        let token_program = self.account(mint).await.unwrap().owner;
+        if self.account(accepted_mint_pda(&mint)).await.is_none() {
+            self.add_accepted_mint(mint, 1, 1_000 * SOL, 100 * SOL).await;
+        }
+
+        let seller = self.new_user("seller").await;
+        let buyer = self.new_user("buyer").await;
+        let gig = self.create_gig(&seller, mint, ServiceCategory::GraphicsDesign, price).await;
+
+        let (seller_token, buyer_token) = if is_native {
+            (None, None)
+        } else {
+            let seller_token = self.create_token_account(mint, seller.pubkey()).await;
+            let buyer_token = self.create_token_account(mint, buyer.pubkey()).await;
+            self.mint_to(mint, buyer_token, 10 * price).await;
+            (Some(seller_token), Some(buyer_token))
+        };
+
+        Market {
+            mint,
+            token_program,
+            seller,
+            buyer,
+            gig,
+            seller_token,
+            buyer_token,
+        }
+    }
+
+    pub async fn try_create_order(&mut self, market: &Market, extras: Vec<u8>) -> Result<Pubkey, BanksClientError> {
+        self.try_create_order_with(market, 0, extras, Vec::new()).await
+    }
+
+    pub async fn try_create_order_with(
+        &mut self,
+        market: &Market,
+        package_index: u8,
+        extras: Vec<u8>,
+        milestones: Vec<MilestoneParams>,
+    ) -> Result<Pubkey, BanksClientError> {
+        let marketplace: solanagigs::state::Marketplace = self.get(marketplace_pda()).await;
+        let order = order_pda(marketplace.total_orders);
+        let mut ix = instruction(
+            solanagigs::accounts::CreateOrder {
+                marketplace: marketplace_pda(),
+                gig: market.gig,
+                order,
+                escrow: escrow_pda(&order),
+                escrow_vault: (!market.is_native()).then(|| escrow_vault_pda(&order)),
+                mint: market.mint,
+                accepted_mint: accepted_mint_pda(&market.mint),
+                buyer_token_account: market.buyer_token,
+                buyer: market.buyer.pubkey(),
+                token_program: market.token_program,
+                system_program: anchor_lang::system_program::ID,
+                rent: anchor_lang::solana_program::sysvar::rent::ID,
+            },
+            solanagigs::instruction::CreateOrder {
+                package_index,
+                extras,
+                requirements: String::new(),
+                milestones: milestones.clone(),
+            },
+        );
+        for id in 0..milestones.len() as u8 {
+            ix.accounts.push(AccountMeta::new(milestone_pda(&order, id), false));
+        }
+        self.process(&[ix], &[&market.buyer]).await?;
+        Ok(order)
+    }
+
+    pub async fn create_order(&mut self, market: &Market) -> Pubkey {
+        self.try_create_order(market, Vec::new()).await.unwrap()
+    }
+
+    pub async fn accept_order(&mut self, market: &Market, order: Pubkey) {
+        let ix = instruction(
+            solanagigs::accounts::AcceptOrder {
+                order,
+                escrow: escrow_pda(&order),
+                seller_volume: volume_pda(&market.seller.pubkey(), &market.mint),
+                seller: market.seller.pubkey(),
+                system_program: anchor_lang::system_program::ID,
+            },
+            solanagigs::instruction::AcceptOrder {},
+        );
+        self.process(&[ix], &[&market.seller]).await.unwrap();
+    }
+
+    pub async fn submit_delivery(&mut self, market: &Market, order: Pubkey) {
+        let ix = instruction(
+            solanagigs::accounts::SubmitDelivery {
+                marketplace: marketplace_pda(),
+                order,
+                escrow: escrow_pda(&order),
+                seller: market.seller.pubkey(),
+            },
+            solanagigs::instruction::SubmitDelivery {
+                delivery_message: "Done".to_string(),
+                delivery_files: Vec::new(),
+            },
+        );
+        self.process(&[ix], &[&market.seller]).await.unwrap();
+    }
+
+    /// Creates, accepts and delivers an order so it is ready to settle.
+    pub async fn delivered_order(&mut self, market: &Market) -> Pubkey {
+        let order = self.create_order(market).await;
+        self.accept_order(market, order).await;
+        self.submit_delivery(market, order).await;
+        order
+    }
+
+    /// Accounts for the buyer approving a delivery; tests adjust them for
+    /// referrals or earnings before building the instruction.
+    pub fn complete_order_accounts(&self, market: &Market, order: Pubkey) -> solanagigs::accounts::CompleteOrder {
+        let native = market.is_native();
+        solanagigs::accounts::CompleteOrder {
+            marketplace: marketplace_pda(),
+            fee_schedule: fee_schedule_pda(),
+            gig: market.gig,
+            order,
+            escrow: escrow_pda(&order),
+            vault: (!native).then(|| escrow_vault_pda(&order)),
+            mint: (!native).then_some(market.mint),
+            seller_profile: user_pda(&market.seller.pubkey()),
+            seller_volume: volume_pda(&market.seller.pubkey(), &market.mint),
+            seller_token_account: market.seller_token,
+            seller_earnings: earnings_pda(&market.seller.pubkey(), &market.mint),
+            earnings_vault: None,
+            buyer_referral: referral_pda(&market.buyer.pubkey()),
+            buyer_referrer_token_account: None,
+            buyer_referrer: None,
+            seller_referral: referral_pda(&market.seller.pubkey()),
+            seller_referrer_token_account: None,
+            seller_referrer: None,
+            treasury: treasury_pda(&market.mint),
+            treasury_vault: (!native).then(|| treasury_vault_pda(&market.mint)),
+            seller: native.then(|| market.seller.pubkey()),
+            buyer: market.buyer.pubkey(),
+            token_program: market.token_program,
+        }
+    }
+
+    pub async fn complete_order_with(
+        &mut self,
+        market: &Market,
+        accounts: solanagigs::accounts::CompleteOrder,
+    ) -> Result<(), BanksClientError> {
+        let ix = instruction(accounts, solanagigs::instruction::CompleteOrder {});
+        self.process(&[ix], &[&market.buyer]).await
+    }
+
+    pub async fn complete_order(&mut self, market: &Market, order: Pubkey) -> Result<(), BanksClientError> {
+        let accounts = self.complete_order_accounts(market, order);
+        self.complete_order_with(market, accounts).await
+    }
+
+    pub async fn register_referral(&mut self, user: &Keypair, referrer: Pubkey) -> Result<(), BanksClientError> {
+        let ix = instruction(
+            solanagigs::accounts::RegisterReferral {
+                referral: referral_pda(&user.pubkey()),
+                user_profile: user_pda(&user.pubkey()),
+                referrer_profile: user_pda(&referrer),
+                user: user.pubkey(),
+                system_program: anchor_lang::system_program::ID,
+            },
+            solanagigs::instruction::RegisterReferral {},
+        );
+        self.process(&[ix], &[user]).await
+    }
+
+    pub async fn register_arbiter(&mut self, tier: ArbiterTier) -> ArbiterKeys {
+        let authority = self.new_wallet(10 * SOL).await;
+        let marketplace: solanagigs::state::Marketplace = self.get(marketplace_pda()).await;
+        let arbiter = arbiter_pda(marketplace.arbiter_count);
+        let payer = self.payer();
+        let ix = instruction(
+            solanagigs::accounts::RegisterArbiter {
+                marketplace: marketplace_pda(),
+                arbiter,
+                arbiter_authority: authority.pubkey(),
+                dispute_admin: payer,
+                system_program: anchor_lang::system_program::ID,
+            },
+            solanagigs::instruction::RegisterArbiter {
+                categories: Vec::new(),
+                tier,
+            },
+        );
+        self.process(&[ix], &[]).await.unwrap();
+        ArbiterKeys { authority, arbiter }
+    }
+
+    /// The buyer disputes a delivered order.
+    pub async fn open_dispute(&mut self, market: &Market, order: Pubkey) -> Pubkey {
+        let dispute = dispute_pda(&order);
+        let ix = instruction(
+            solanagigs::accounts::DisputeOrder {
+                marketplace: marketplace_pda(),
+                order,
+                escrow: escrow_pda(&order),
+                dispute,
+                dispute_initiator: market.buyer.pubkey(),
+                system_program: anchor_lang::system_program::ID,
+            },
+            solanagigs::instruction::DisputeOrder {
+                reason: "The delivery is incomplete".to_string(),
+                evidence_hash: None,
+                requested_resolution: DisputeResolution::RefundBuyer,
+            },
+        );
+        self.process(&[ix], &[&market.buyer]).await.unwrap();
+        dispute
+    }
+
+    pub async fn assign_arbiter(&mut self, market: &Market, order: Pubkey, arbiter: Pubkey) -> Result<(), BanksClientError> {
+        let ix = instruction(
+            solanagigs::accounts::AssignArbiter {
+                marketplace: marketplace_pda(),
+                gig: market.gig,
+                order,
+                dispute: dispute_pda(&order),
+                arbiter,
+            },
+            solanagigs::instruction::AssignArbiter {},
+        );
+        self.process(&[ix], &[]).await
+    }
+
+    pub async fn resolve_dispute(&mut self, order: Pubkey, arbiter: &ArbiterKeys, refund_bps: u16) -> Result<(), BanksClientError> {
+        let ix = instruction(
+            solanagigs::accounts::ResolveDispute {
+                marketplace: marketplace_pda(),
+                order,
+                dispute: dispute_pda(&order),
+                arbiter: arbiter.arbiter,
+                authority: arbiter.authority.pubkey(),
+            },
+            solanagigs::instruction::ResolveDispute {
+                resolution: DisputeResolution::from_refund_bps(refund_bps),
+                refund_bps,
+                resolution_reason: None,
+            },
+        );
+        self.process(&[ix], &[&arbiter.authority]).await
+    }
+
+    pub async fn appeal_dispute(&mut self, order: Pubkey, appellant: &Keypair) -> Result<(), BanksClientError> {
+        let ix = instruction(
+            solanagigs::accounts::AppealDispute {
+                marketplace: marketplace_pda(),
+                order,
+                dispute: dispute_pda(&order),
+                appeal: appeal_pda(&order),
+                appellant: appellant.pubkey(),
+                system_program: anchor_lang::system_program::ID,
+            },
+            solanagigs::instruction::AppealDispute {
+                reason: "The arbiter ignored the evidence".to_string(),
+            },
+        );
+        self.process(&[ix], &[appellant]).await
+    }
+
+    pub async fn resolve_appeal(
+        &mut self,
+        market: &Market,
+        order: Pubkey,
+        arbiter: &ArbiterKeys,
+        appellant: Pubkey,
+        refund_bps: u16,
+    ) -> Result<(), BanksClientError> {
+        let ix = instruction(
+            solanagigs::accounts::ResolveAppeal {
+                marketplace: marketplace_pda(),
+                gig: market.gig,
+                order,
+                dispute: dispute_pda(&order),
+                appeal: appeal_pda(&order),
+                arbiter: arbiter.arbiter,
+                appellant,
+                treasury: treasury_pda(&native_mint::ID),
+                authority: arbiter.authority.pubkey(),
+            },
+            solanagigs::instruction::ResolveAppeal {
+                resolution: DisputeResolution::from_refund_bps(refund_bps),
+                refund_bps,
+                resolution_reason: None,
+            },
+        );
+        self.process(&[ix], &[&arbiter.authority]).await
+    }
+
+    /// Defaults a dispute, or lapses its appeal when `appellant` is given.
+    pub async fn default_dispute(&mut self, order: Pubkey, arbiter: Option<Pubkey>, appellant: Option<Pubkey>) -> Result<(), BanksClientError> {
+        let ix = instruction(
+            solanagigs::accounts::DefaultDispute {
+                order,
+                dispute: dispute_pda(&order),
+                arbiter,
+                appeal: appellant.map(|_| appeal_pda(&order)),
+                appellant,
+            },
+            solanagigs::instruction::DefaultDispute {},
+        );
+        self.process(&[ix], &[]).await
+    }
+
+    pub fn finalize_dispute_accounts(&self, market: &Market, order: Pubkey) -> solanagigs::accounts::FinalizeDispute {
+        let native = market.is_native();
+        solanagigs::accounts::FinalizeDispute {
+            marketplace: marketplace_pda(),
+            fee_schedule: fee_schedule_pda(),
+            gig: market.gig,
+            order,
+            escrow: escrow_pda(&order),
+            dispute: dispute_pda(&order),
+            jury_case: None,
+            vault: (!native).then(|| escrow_vault_pda(&order)),
+            mint: (!native).then_some(market.mint),
+            seller_profile: user_pda(&market.seller.pubkey()),
+            seller_volume: volume_pda(&market.seller.pubkey(), &market.mint),
+            buyer_token_account: market.buyer_token,
+            seller_token_account: market.seller_token,
+            seller_earnings: earnings_pda(&market.seller.pubkey(), &market.mint),
+            earnings_vault: None,
+            buyer_referral: referral_pda(&market.buyer.pubkey()),
+            buyer_referrer_token_account: None,
+            buyer_referrer: None,
+            seller_referral: referral_pda(&market.seller.pubkey()),
+            seller_referrer_token_account: None,
+            seller_referrer: None,
+            treasury: treasury_pda(&market.mint),
+            treasury_vault: (!native).then(|| treasury_vault_pda(&market.mint)),
+            buyer_wallet: Some(market.buyer.pubkey()),
+            seller: native.then(|| market.seller.pubkey()),
+            caller: self.payer(),
+            token_program: market.token_program,
+        }
+    }
+
+    pub async fn finalize_dispute(&mut self, market: &Market, order: Pubkey) -> Result<(), BanksClientError> {
+        let ix = instruction(
+            self.finalize_dispute_accounts(market, order),
+            solanagigs::instruction::FinalizeDispute {},
+        );
+        self.process(&[ix], &[]).await
+    }
+
+    pub async fn register_jurors(&mut self, count: usize) -> Jury {
+        let mut jurors = Vec::new();
+        for _ in 0..count {
+            let juror = self.new_wallet(10 * SOL).await;
+            let ix = instruction(
+                solanagigs::accounts::RegisterJuror {
+                    marketplace: marketplace_pda(),
+                    juror_stake: juror_pda(&juror.pubkey()),
+                    juror: juror.pubkey(),
+                    system_program: anchor_lang::system_program::ID,
+                },
+                solanagigs::instruction::RegisterJuror {
+                    stake_amount: JurorStake::MIN_STAKE,
+                },
+            );
+            self.process(&[ix], &[&juror]).await.unwrap();
+            jurors.push(juror);
+        }
+        Jury { jurors }
+    }
+
+    /// Draws a panel for the order's dispute, walking every juror in the
+    /// dispute's pool from the draw start. Returns the panel in seat order.
+    pub async fn draw_jury(&mut self, market: &Market, order: Pubkey, jury: &Jury) -> Result<Vec<Pubkey>, BanksClientError> {
+        let dispute: Dispute = self.get(dispute_pda(&order)).await;
+        let slot_hashes = self.account(slot_hashes::ID).await.unwrap();
+        let slot_hash: [u8; 32] = slot_hashes.data[16..48].try_into().unwrap();
+        let pool = dispute.juror_pool;
+        let start = JuryCase::draw_start(&dispute_pda(&order), &slot_hash, pool);
+        let mut ix = instruction(
+            solanagigs::accounts::DrawJury {
+                marketplace: marketplace_pda(),
+                order,
+                dispute: dispute_pda(&order),
+                jury_case: jury_pda(&order),
+                caller: market.buyer.pubkey(),
+                slot_hashes: slot_hashes::ID,
+                system_program: anchor_lang::system_program::ID,
+            },
+            solanagigs::instruction::DrawJury {},
+        );
+        for offset in 0..pool {
+            let juror = &jury.jurors[((start + offset) % pool) as usize];
+            ix.accounts.push(AccountMeta::new(juror_pda(&juror.pubkey()), false));
+        }
+        self.process(&[ix], &[&market.buyer]).await?;
+
+        let jury_case: JuryCase = self.get(jury_pda(&order)).await;
+        Ok(jury_case.jurors.iter().map(|vote| vote.juror).collect())
+    }
+
+    pub async fn commit_vote(&mut self, order: Pubkey, juror: &Keypair, refund_bps: u16) -> Result<(), BanksClientError> {
+        let ix = instruction(
+            solanagigs::accounts::CommitVote {
+                jury_case: jury_pda(&order),
+                juror: juror.pubkey(),
+            },
+            solanagigs::instruction::CommitVote {
+                commitment: JuryCase::commitment(refund_bps, &JUROR_SALT, &juror.pubkey()),
+            },
+        );
+        self.process(&[ix], &[juror]).await
+    }
+
+    pub async fn reveal_vote(&mut self, order: Pubkey, juror: &Keypair, refund_bps: u16) -> Result<(), BanksClientError> {
+        let ix = instruction(
+            solanagigs::accounts::RevealVote {
+                jury_case: jury_pda(&order),
+                juror: juror.pubkey(),
+            },
+            solanagigs::instruction::RevealVote {
+                refund_bps,
+                salt: JUROR_SALT,
+            },
+        );
+        self.process(&[ix], &[juror]).await
+    }
+
+    pub async fn settle_jury(&mut self, order: Pubkey, panel: &[Pubkey]) -> Result<(), BanksClientError> {
+        let mut ix = instruction(
+            solanagigs::accounts::SettleJury {
+                marketplace: marketplace_pda(),
+                order,
+                dispute: dispute_pda(&order),
+                jury_case: jury_pda(&order),
+                sol_treasury: treasury_pda(&native_mint::ID),
+                caller: self.payer(),
+            },
+            solanagigs::instruction::SettleJury {},
+        );
+        for juror in panel {
+            ix.accounts.push(AccountMeta::new(juror_pda(juror), false));
+        }
+        self.process(&[ix], &[]).await
+    }
+
+    /// The order's escrow vault, unless the escrow is native or the vault
+    /// has already been closed.
+    async fn open_escrow_vault(&mut self, order: Pubkey) -> Option<Pubkey> {
+        let vault = escrow_vault_pda(&order);
+        self.account(vault).await.map(|_| vault)
+    }
+
+    pub async fn claim_juror_reward(&mut self, market: &Market, order: Pubkey, juror: &Keypair) -> Result<(), BanksClientError> {
+        let native = market.is_native();
+        let juror_token_account = match native {
+            true => None,
+            false => Some(self.create_token_account(market.mint, juror.pubkey()).await),
+        };
+        let vault = self.open_escrow_vault(order).await;
+        let ix = instruction(
+            solanagigs::accounts::ClaimJurorReward {
+                marketplace: marketplace_pda(),
+                order,
+                escrow: escrow_pda(&order),
+                jury_case: jury_pda(&order),
+                vault,
+                mint: (!native).then_some(market.mint),
+                juror_token_account,
+                treasury: treasury_pda(&market.mint),
+                treasury_vault: (!native).then(|| treasury_vault_pda(&market.mint)),
+                buyer_wallet: Some(market.buyer.pubkey()),
+                juror: juror.pubkey(),
+                token_program: market.token_program,
+            },
+            solanagigs::instruction::ClaimJurorReward {},
+        );
+        self.process(&[ix], &[juror]).await
+    }
+
+    pub async fn sweep_juror_rewards(&mut self, market: &Market, order: Pubkey) -> Result<(), BanksClientError> {
+        let native = market.is_native();
+        let vault = self.open_escrow_vault(order).await;
+        let ix = instruction(
+            solanagigs::accounts::SweepJurorRewards {
+                marketplace: marketplace_pda(),
+                order,
+                escrow: escrow_pda(&order),
+                jury_case: jury_pda(&order),
+                vault,
+                mint: (!native).then_some(market.mint),
+                treasury: treasury_pda(&market.mint),
+                treasury_vault: (!native).then(|| treasury_vault_pda(&market.mint)),
+                sol_treasury: treasury_pda(&native_mint::ID),
+                buyer_wallet: Some(market.buyer.pubkey()),
+                caller: self.payer(),
+                token_program: market.token_program,
+            },
+            solanagigs::instruction::SweepJurorRewards {},
+        );
+        self.process(&[ix], &[]).await
+    }
+
+    /// Opts the market's seller into pull payments.
+    pub async fn open_earnings(&mut self, market: &Market) -> Pubkey {
+        let earnings = earnings_pda(&market.seller.pubkey(), &market.mint);
+        let ix = instruction(
+            solanagigs::accounts::OpenEarnings {
+                earnings,
+                earnings_vault: (!market.is_native()).then(|| earnings_vault_pda(&earnings)),
+                mint: market.mint,
+                seller_profile: user_pda(&market.seller.pubkey()),
+                seller: market.seller.pubkey(),
+                token_program: market.token_program,
+                system_program: anchor_lang::system_program::ID,
+            },
+            solanagigs::instruction::OpenEarnings {},
+        );
+        self.process(&[ix], &[&market.seller]).await.unwrap();
+        earnings
+    }
+
+    /// Withdraws the seller's earnings to their token account or wallet,
+    /// passing a withdrawal request when `request` is set.
+    pub async fn withdraw_earnings(&mut self, market: &Market, amount: u64, request: bool) -> Result<(), BanksClientError> {
+        let native = market.is_native();
+        let earnings = earnings_pda(&market.seller.pubkey(), &market.mint);
+        let state: solanagigs::state::UserEarnings = self.get(earnings).await;
+        let ix = instruction(
+            solanagigs::accounts::WithdrawEarnings {
+                marketplace: marketplace_pda(),
+                earnings,
+                accepted_mint: accepted_mint_pda(&market.mint),
+                earnings_vault: (!native).then(|| earnings_vault_pda(&earnings)),
+                mint: (!native).then_some(market.mint),
+                destination_token_account: market.seller_token,
+                destination_wallet: native.then(|| market.seller.pubkey()),
+                withdrawal_request: request.then(|| withdrawal_pda(&earnings, state.withdrawal_count)),
+                seller: market.seller.pubkey(),
+                token_program: market.token_program,
+                system_program: anchor_lang::system_program::ID,
+            },
+            solanagigs::instruction::WithdrawEarnings { amount },
+        );
+        self.process(&[ix], &[&market.seller]).await
+    }
+}
@@ -0,0 +1,334 @@
+mod common;
+
+use anchor_lang::solana_program::pubkey::Pubkey;
+use anchor_spl::token::{spl_token, spl_token::native_mint};
+use solana_program_test::BanksClientError;
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+use common::*;
+use solanagigs::errors::SolanaGigsError;
+use solanagigs::state::{Arbiter, ArbiterTier, Dispute, DisputeStatus, Marketplace};
+
+async fn submit_evidence(env: &mut TestEnv, order: Pubkey, submitter: &Keypair, tag: u8) -> Result<(), BanksClientError> {
+    let ix = instruction(
+        solanagigs::accounts::SubmitEvidence {
+            order,
+            dispute: dispute_pda(&order),
+            submitter: submitter.pubkey(),
+        },
+        solanagigs::instruction::SubmitEvidence {
+            content_hash: [tag; 32],
+            uri: format!("https://example.com/evidence/{tag}"),
+        },
+    );
+    env.process(&[ix], &[submitter]).await
+}
+
+async fn respond_to_dispute(env: &mut TestEnv, order: Pubkey, respondent: &Keypair) -> Result<(), BanksClientError> {
+    let ix = instruction(
+        solanagigs::accounts::RespondToDispute {
+            order,
+            dispute: dispute_pda(&order),
+            respondent: respondent.pubkey(),
+        },
+        solanagigs::instruction::RespondToDispute {
+            response: "The delivery matches the brief".to_string(),
+        },
+    );
+    env.process(&[ix], &[respondent]).await
+}
+
+/// Runs a delivered order through a full refund that the seller appeals.
+async fn appealed_order(env: &mut TestEnv, market: &Market) -> (Pubkey, ArbiterKeys) {
+    let arbiter = env.register_arbiter(ArbiterTier::Standard).await;
+    let appeals = env.register_arbiter(ArbiterTier::Appeals).await;
+
+    let order = env.delivered_order(market).await;
+    env.open_dispute(market, order).await;
+    env.assign_arbiter(market, order, arbiter.arbiter).await.unwrap();
+    env.resolve_dispute(order, &arbiter, 10_000).await.unwrap();
+    env.appeal_dispute(order, &market.seller).await.unwrap();
+    (order, appeals)
+}
+
+#[tokio::test]
+async fn failed_appeal_forfeits_the_bond_to_the_treasury() {
+    let mut env = TestEnv::start().await;
+    let mint = env.create_mint(spl_token::ID, None).await;
+    let market = env.market(mint, 500, 1_000_000).await;
+    let funded = env.token_balance(market.buyer_token.unwrap()).await;
+    let (order, appeals) = appealed_order(&mut env, &market).await;
+
+    let marketplace: Marketplace = env.get(marketplace_pda()).await;
+    let treasury = treasury_pda(&native_mint::ID);
+    let treasury_before = env.lamports(treasury).await;
+    let authority_before = env.lamports(marketplace.authority).await;
+
+    env.resolve_appeal(&market, order, &appeals, market.seller.pubkey(), 10_000)
+        .await
+        .unwrap();
+
+    assert_eq!(env.lamports(treasury).await, treasury_before + marketplace.appeal_bond);
+    assert!(env.lamports(marketplace.authority).await < authority_before);
+
+    env.finalize_dispute(&market, order).await.unwrap();
+    assert_eq!(env.token_balance(market.buyer_token.unwrap()).await, funded);
+    let dispute: Dispute = env.get(dispute_pda(&order)).await;
+    assert_eq!(dispute.status, DisputeStatus::Resolved);
+}
+
+#[tokio::test]
+async fn successful_appeal_returns_the_bond() {
+    let mut env = TestEnv::start().await;
+    let mint = env.create_mint(spl_token::ID, None).await;
+    let market = env.market(mint, 500, 1_000_000).await;
+    let (order, appeals) = appealed_order(&mut env, &market).await;
+
+    let treasury = treasury_pda(&native_mint::ID);
+    let treasury_before = env.lamports(treasury).await;
+    let seller_before = env.lamports(market.seller.pubkey()).await;
+    let appeal_account = env.lamports(appeal_pda(&order)).await;
+
+    env.resolve_appeal(&market, order, &appeals, market.seller.pubkey(), 0)
+        .await
+        .unwrap();
+
+    assert_eq!(env.lamports(treasury).await, treasury_before);
+    assert_eq!(env.lamports(market.seller.pubkey()).await, seller_before + appeal_account);
+
+    env.finalize_dispute(&market, order).await.unwrap();
+    assert_eq!(env.token_balance(market.seller_token.unwrap()).await, 950_000);
+}
+
+#[tokio::test]
+async fn undecided_appeal_lapses_after_the_deadline() {
+    let mut env = TestEnv::start().await;
+    let mint = env.create_mint(spl_token::ID, None).await;
+    let market = env.market(mint, 500, 1_000_000).await;
+    let funded = env.token_balance(market.buyer_token.unwrap()).await;
+    let (order, _) = appealed_order(&mut env, &market).await;
+    let seller = market.seller.pubkey();
+
+    assert_error(
+        env.default_dispute(order, None, Some(seller)).await,
+        SolanaGigsError::ArbiterDeadlineNotReached,
+    );
+
+    let marketplace: Marketplace = env.get(marketplace_pda()).await;
+    env.advance_time(marketplace.arbiter_period + 1).await;
+    let seller_before = env.lamports(seller).await;
+    let appeal_account = env.lamports(appeal_pda(&order)).await;
+    env.default_dispute(order, None, Some(seller)).await.unwrap();
+
+    // The appellant was never heard, so the bond comes back with the rent
+    assert_eq!(env.lamports(seller).await, seller_before + appeal_account);
+    let dispute: Dispute = env.get(dispute_pda(&order)).await;
+    assert_eq!(dispute.status, DisputeStatus::PendingSettlement);
+
+    env.finalize_dispute(&market, order).await.unwrap();
+    assert_eq!(env.token_balance(market.buyer_token.unwrap()).await, funded);
+}
+
+#[tokio::test]
+async fn arbiters_are_assigned_in_rotation() {
+    let mut env = TestEnv::start().await;
+    let mint = env.create_mint(spl_token::ID, None).await;
+    let market = env.market(mint, 500, 1_000_000).await;
+    let first = env.register_arbiter(ArbiterTier::Standard).await;
+    let second = env.register_arbiter(ArbiterTier::Standard).await;
+
+    let order = env.delivered_order(&market).await;
+    env.open_dispute(&market, order).await;
+    assert_error(
+        env.assign_arbiter(&market, order, second.arbiter).await,
+        SolanaGigsError::ArbiterOutOfRotation,
+    );
+    env.assign_arbiter(&market, order, first.arbiter).await.unwrap();
+    assert_error(
+        env.assign_arbiter(&market, order, first.arbiter).await,
+        SolanaGigsError::ArbiterAlreadyAssigned,
+    );
+
+    let dispute: Dispute = env.get(dispute_pda(&order)).await;
+    assert_eq!(dispute.arbiter, Some(first.arbiter));
+    assert_eq!(dispute.status, DisputeStatus::UnderReview);
+    let arbiter: Arbiter = env.get(first.arbiter).await;
+    assert_eq!(arbiter.active_cases, 1);
+
+    let next = env.delivered_order(&market).await;
+    env.open_dispute(&market, next).await;
+    assert_error(
+        env.assign_arbiter(&market, next, first.arbiter).await,
+        SolanaGigsError::ArbiterOutOfRotation,
+    );
+    env.assign_arbiter(&market, next, second.arbiter).await.unwrap();
+
+    // Only the assigned arbiter can rule on the case
+    assert_error(
+        env.resolve_dispute(next, &first, 0).await,
+        SolanaGigsError::ArbiterNotAssigned,
+    );
+}
+
+#[tokio::test]
+async fn parties_build_the_evidence_record() {
+    let mut env = TestEnv::start().await;
+    let mint = env.create_mint(spl_token::ID, None).await;
+    let market = env.market(mint, 500, 1_000_000).await;
+    let outsider = env.new_wallet(SOL).await;
+    let order = env.delivered_order(&market).await;
+    env.open_dispute(&market, order).await;
+
+    for tag in 1..=Dispute::MAX_EVIDENCE_PER_PARTY as u8 {
+        submit_evidence(&mut env, order, &market.buyer, tag).await.unwrap();
+    }
+    assert_error(
+        submit_evidence(&mut env, order, &market.buyer, 9).await,
+        SolanaGigsError::EvidenceLimitReached,
+    );
+    submit_evidence(&mut env, order, &market.seller, 10).await.unwrap();
+    assert_error(
+        submit_evidence(&mut env, order, &outsider, 11).await,
+        SolanaGigsError::Unauthorized,
+    );
+
+    respond_to_dispute(&mut env, order, &market.seller).await.unwrap();
+    assert_error(
+        respond_to_dispute(&mut env, order, &market.seller).await,
+        SolanaGigsError::DisputeAlreadyAnswered,
+    );
+
+    let dispute: Dispute = env.get(dispute_pda(&order)).await;
+    assert_eq!(dispute.evidence.len(), Dispute::MAX_EVIDENCE_PER_PARTY + 1);
+    assert_eq!(dispute.evidence_count_by(&market.seller.pubkey()), 1);
+    assert!(dispute.seller_response.is_some());
+
+    env.advance_time(Dispute::EVIDENCE_PERIOD + 1).await;
+    assert_error(
+        submit_evidence(&mut env, order, &market.seller, 12).await,
+        SolanaGigsError::EvidenceWindowClosed,
+    );
+}
+
+#[tokio::test]
+async fn silent_respondent_loses_by_default() {
+    let mut env = TestEnv::start().await;
+    let mint = env.create_mint(spl_token::ID, None).await;
+    let market = env.market(mint, 500, 1_000_000).await;
+    let funded = env.token_balance(market.buyer_token.unwrap()).await;
+    let order = env.delivered_order(&market).await;
+    env.open_dispute(&market, order).await;
+
+    assert_error(
+        env.default_dispute(order, None, None).await,
+        SolanaGigsError::ResponseDeadlineNotReached,
+    );
+
+    let marketplace: Marketplace = env.get(marketplace_pda()).await;
+    env.advance_time(marketplace.response_period + 1).await;
+    env.default_dispute(order, None, None).await.unwrap();
+
+    let dispute: Dispute = env.get(dispute_pda(&order)).await;
+    assert_eq!(dispute.status, DisputeStatus::PendingSettlement);
+    assert_eq!(dispute.refund_bps, 10_000);
+
+    env.finalize_dispute(&market, order).await.unwrap();
+    assert_eq!(env.token_balance(market.buyer_token.unwrap()).await, funded);
+}
+
+#[tokio::test]
+async fn engaged_respondent_cannot_be_defaulted() {
+    let mut env = TestEnv::start().await;
+    let mint = env.create_mint(spl_token::ID, None).await;
+    let market = env.market(mint, 500, 1_000_000).await;
+    let order = env.delivered_order(&market).await;
+    env.open_dispute(&market, order).await;
+    submit_evidence(&mut env, order, &market.seller, 1).await.unwrap();
+
+    let marketplace: Marketplace = env.get(marketplace_pda()).await;
+    env.advance_time(marketplace.response_period + 1).await;
+    assert_error(
+        env.default_dispute(order, None, None).await,
+        SolanaGigsError::RespondentEngaged,
+    );
+}
+
+#[tokio::test]
+async fn refund_split_is_exact_in_basis_points() {
+    let mut env = TestEnv::start().await;
+    let mint = env.create_mint(spl_token::ID, None).await;
+    let market = env.market(mint, 500, 999_999).await;
+    let funded = env.token_balance(market.buyer_token.unwrap()).await;
+    let arbiter = env.register_arbiter(ArbiterTier::Standard).await;
+
+    let order = env.delivered_order(&market).await;
+    env.open_dispute(&market, order).await;
+    env.assign_arbiter(&market, order, arbiter.arbiter).await.unwrap();
+    env.resolve_dispute(order, &arbiter, 3_333).await.unwrap();
+    let marketplace: Marketplace = env.get(marketplace_pda()).await;
+    env.advance_time(marketplace.appeal_period + 1).await;
+    env.finalize_dispute(&market, order).await.unwrap();
+
+    // The seller's 66.67% rounds down and the dust goes back to the buyer;
+    // the fee is charged on the seller's share only
+    let refund = 333_300;
+    let fee = 33_334;
+    assert_eq!(env.token_balance(market.buyer_token.unwrap()).await, funded - 999_999 + refund);
+    assert_eq!(env.token_balance(market.seller_token.unwrap()).await, 666_699 - fee);
+    assert_eq!(env.token_balance(treasury_vault_pda(&mint)).await, fee);
+
+    let dispute: Dispute = env.get(dispute_pda(&order)).await;
+    assert_eq!(dispute.refund_bps, 3_333);
+    assert_eq!(dispute.buyer_refund_amount, refund);
+}
+
+#[tokio::test]
+async fn appeals_escalate_to_the_appeals_tier() {
+    let mut env = TestEnv::start().await;
+    let mint = env.create_mint(spl_token::ID, None).await;
+    let market = env.market(mint, 500, 1_000_000).await;
+    let arbiter = env.register_arbiter(ArbiterTier::Standard).await;
+    let peer = env.register_arbiter(ArbiterTier::Standard).await;
+
+    let order = env.delivered_order(&market).await;
+    env.open_dispute(&market, order).await;
+    env.assign_arbiter(&market, order, arbiter.arbiter).await.unwrap();
+    env.resolve_dispute(order, &arbiter, 10_000).await.unwrap();
+
+    // The buyer got a full refund, so only the seller has something to appeal
+    assert_error(
+        env.appeal_dispute(order, &market.buyer).await,
+        SolanaGigsError::CannotAppealResolution,
+    );
+    env.appeal_dispute(order, &market.seller).await.unwrap();
+
+    let seller = market.seller.pubkey();
+    for standard in [&arbiter, &peer] {
+        assert_error(
+            env.resolve_appeal(&market, order, standard, seller, 0).await,
+            SolanaGigsError::ArbiterNotEligible,
+        );
+    }
+    let appeals = env.register_arbiter(ArbiterTier::Appeals).await;
+    env.resolve_appeal(&market, order, &appeals, seller, 0).await.unwrap();
+}
+
+#[tokio::test]
+async fn appeal_window_closes() {
+    let mut env = TestEnv::start().await;
+    let mint = env.create_mint(spl_token::ID, None).await;
+    let market = env.market(mint, 500, 1_000_000).await;
+    let arbiter = env.register_arbiter(ArbiterTier::Standard).await;
+
+    let order = env.delivered_order(&market).await;
+    env.open_dispute(&market, order).await;
+    env.assign_arbiter(&market, order, arbiter.arbiter).await.unwrap();
+    env.resolve_dispute(order, &arbiter, 10_000).await.unwrap();
+
+    let marketplace: Marketplace = env.get(marketplace_pda()).await;
+    env.advance_time(marketplace.appeal_period + 1).await;
+    assert_error(
+        env.appeal_dispute(order, &market.seller).await,
+        SolanaGigsError::AppealWindowClosed,
+    );
+}
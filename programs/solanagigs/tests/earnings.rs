@@ -0,0 +1,80 @@
+mod common;
+
+use anchor_spl::token::spl_token;
+use solana_sdk::signer::Signer;
+
+use common::*;
+use solanagigs::errors::SolanaGigsError;
+use solanagigs::state::UserEarnings;
+
+async fn earnings_market(env: &mut TestEnv, withdrawal_threshold: u64) -> Market {
+    let mint = env.create_mint(spl_token::ID, None).await;
+    let market = env.market(mint, 500, 1_000_000).await;
+    env.update_accepted_mint(mint, None, Some(withdrawal_threshold)).await.unwrap();
+    market
+}
+
+async fn credited_earnings(env: &mut TestEnv, market: &Market) -> UserEarnings {
+    let earnings = env.open_earnings(market).await;
+    let order = env.delivered_order(market).await;
+    let mut accounts = env.complete_order_accounts(market, order);
+    accounts.earnings_vault = Some(earnings_vault_pda(&earnings));
+    env.complete_order_with(market, accounts).await.unwrap();
+    env.get(earnings).await
+}
+
+#[tokio::test]
+async fn settlement_credits_the_earnings_account() {
+    let mut env = TestEnv::start().await;
+    let market = earnings_market(&mut env, 0).await;
+    let earnings = credited_earnings(&mut env, &market).await;
+
+    assert_eq!(earnings.available_balance, 950_000);
+    assert_eq!(env.token_balance(earnings.vault).await, 950_000);
+    assert_eq!(env.token_balance(market.seller_token.unwrap()).await, 0);
+
+    env.withdraw_earnings(&market, 950_000, false).await.unwrap();
+    assert_eq!(env.token_balance(market.seller_token.unwrap()).await, 950_000);
+}
+
+#[tokio::test]
+async fn opened_earnings_cannot_be_bypassed() {
+    let mut env = TestEnv::start().await;
+    let market = earnings_market(&mut env, 0).await;
+    env.open_earnings(&market).await;
+    let order = env.delivered_order(&market).await;
+
+    // Without the earnings vault the payout cannot fall back to the seller's token account
+    assert_error(
+        env.complete_order(&market, order).await,
+        SolanaGigsError::TokenAccountNotFound,
+    );
+
+    let mut accounts = env.complete_order_accounts(&market, order);
+    accounts.seller_earnings = earnings_pda(&market.buyer.pubkey(), &market.mint);
+    let err = env.complete_order_with(&market, accounts).await.unwrap_err();
+    assert_eq!(error_code(err), anchor_lang::error::ErrorCode::ConstraintSeeds as u32);
+}
+
+#[tokio::test]
+async fn threshold_caps_unapproved_withdrawals_per_period() {
+    let mut env = TestEnv::start().await;
+    let market = earnings_market(&mut env, 400_000).await;
+    credited_earnings(&mut env, &market).await;
+
+    env.withdraw_earnings(&market, 300_000, false).await.unwrap();
+    // A second withdrawal under the threshold still counts towards the period
+    assert_error(
+        env.withdraw_earnings(&market, 200_000, false).await,
+        SolanaGigsError::WithdrawalApprovalRequired,
+    );
+    env.withdraw_earnings(&market, 200_000, true).await.unwrap();
+
+    let earnings: UserEarnings = env.get(earnings_pda(&market.seller.pubkey(), &market.mint)).await;
+    assert_eq!(earnings.pending_balance, 200_000);
+    assert_eq!(env.token_balance(market.seller_token.unwrap()).await, 300_000);
+
+    env.advance_time(UserEarnings::WITHDRAWAL_PERIOD).await;
+    env.withdraw_earnings(&market, 300_000, false).await.unwrap();
+    assert_eq!(env.token_balance(market.seller_token.unwrap()).await, 600_000);
+}
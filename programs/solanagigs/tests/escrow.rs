@@ -0,0 +1,262 @@
+mod common;
+
+use anchor_lang::solana_program::{instruction::Instruction, pubkey::Pubkey};
+use anchor_spl::token::spl_token;
+use anchor_spl::token_2022::spl_token_2022;
+use solana_program_test::BanksClientError;
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+use common::*;
+use solanagigs::errors::SolanaGigsError;
+use solanagigs::instructions::UpdateMarketplaceParams;
+use solanagigs::state::{Escrow, Marketplace, Order, OrderStatus, Referral};
+
+fn auto_release_accounts(
+    market: &Market,
+    order: Pubkey,
+    caller: Pubkey,
+    caller_token_account: Option<Pubkey>,
+) -> solanagigs::accounts::AutoRelease {
+    let native = market.is_native();
+    solanagigs::accounts::AutoRelease {
+        marketplace: marketplace_pda(),
+        fee_schedule: fee_schedule_pda(),
+        gig: market.gig,
+        order,
+        escrow: escrow_pda(&order),
+        vault: (!native).then(|| escrow_vault_pda(&order)),
+        mint: (!native).then_some(market.mint),
+        seller_profile: user_pda(&market.seller.pubkey()),
+        seller_volume: volume_pda(&market.seller.pubkey(), &market.mint),
+        seller_token_account: market.seller_token,
+        seller_earnings: earnings_pda(&market.seller.pubkey(), &market.mint),
+        earnings_vault: None,
+        buyer_referral: referral_pda(&market.buyer.pubkey()),
+        buyer_referrer_token_account: None,
+        buyer_referrer: None,
+        seller_referral: referral_pda(&market.seller.pubkey()),
+        seller_referrer_token_account: None,
+        seller_referrer: None,
+        treasury: treasury_pda(&market.mint),
+        treasury_vault: (!native).then(|| treasury_vault_pda(&market.mint)),
+        caller_token_account,
+        buyer_wallet: Some(market.buyer.pubkey()),
+        seller: native.then(|| market.seller.pubkey()),
+        caller,
+        token_program: market.token_program,
+    }
+}
+
+async fn auto_release_with(
+    env: &mut TestEnv,
+    accounts: solanagigs::accounts::AutoRelease,
+    caller: &Keypair,
+) -> Result<(), BanksClientError> {
+    let ix = instruction(accounts, solanagigs::instruction::AutoRelease {});
+    env.process(&[ix], &[caller]).await
+}
+
+async fn auto_release(
+    env: &mut TestEnv,
+    market: &Market,
+    order: Pubkey,
+    caller: &Keypair,
+    caller_token_account: Option<Pubkey>,
+) -> Result<(), BanksClientError> {
+    let accounts = auto_release_accounts(market, order, caller.pubkey(), caller_token_account);
+    auto_release_with(env, accounts, caller).await
+}
+
+fn close_order_ix(market: &Market, order: Pubkey, dispute_initiator: Option<Pubkey>) -> Instruction {
+    instruction(
+        solanagigs::accounts::CloseOrder {
+            marketplace: marketplace_pda(),
+            order,
+            escrow: escrow_pda(&order),
+            dispute: dispute_initiator.map(|_| dispute_pda(&order)),
+            buyer_review: None,
+            seller_review: None,
+            buyer: market.buyer.pubkey(),
+            seller: None,
+            dispute_initiator,
+        },
+        solanagigs::instruction::CloseOrder {},
+    )
+}
+
+#[tokio::test]
+async fn complete_order_pays_seller_and_treasury() {
+    let mut env = TestEnv::start().await;
+    let mint = env.create_mint(spl_token::ID, None).await;
+    let market = env.market(mint, 500, 1_000_000).await;
+
+    let order = env.delivered_order(&market).await;
+    env.complete_order(&market, order).await.unwrap();
+
+    assert_eq!(env.token_balance(market.seller_token.unwrap()).await, 950_000);
+    assert_eq!(env.token_balance(treasury_vault_pda(&mint)).await, 50_000);
+    assert!(env.account(escrow_vault_pda(&order)).await.is_none());
+
+    let order: Order = env.get(order).await;
+    assert_eq!(order.status, OrderStatus::Completed);
+}
+
+#[tokio::test]
+async fn complete_order_in_sol_keeps_lamports_on_escrow() {
+    let mut env = TestEnv::start().await;
+    let market = env.market(spl_token::native_mint::ID, 500, SOL).await;
+
+    let order = env.delivered_order(&market).await;
+    let seller_before = env.lamports(market.seller.pubkey()).await;
+    let treasury_before = env.lamports(treasury_pda(&market.mint)).await;
+    env.complete_order(&market, order).await.unwrap();
+
+    assert_eq!(env.lamports(market.seller.pubkey()).await - seller_before, SOL / 100 * 95);
+    assert_eq!(env.lamports(treasury_pda(&market.mint)).await - treasury_before, SOL / 100 * 5);
+
+    let escrow: Escrow = env.get(escrow_pda(&order)).await;
+    assert_eq!(escrow.remaining_amount(), 0);
+}
+
+#[tokio::test]
+async fn transfer_fee_escrow_is_credited_what_arrived() {
+    let mut env = TestEnv::start().await;
+    let mint = env.create_mint(spl_token_2022::ID, Some(100)).await;
+    let market = env.market(mint, 500, 1_000_000).await;
+
+    let order = env.create_order(&market).await;
+
+    let escrow: Escrow = env.get(escrow_pda(&order)).await;
+    assert_eq!(escrow.amount, 990_000);
+    assert_eq!(env.withheld_in_account(escrow_vault_pda(&order)).await, 10_000);
+}
+
+#[tokio::test]
+async fn transfer_fee_vault_is_harvested_before_closing() {
+    let mut env = TestEnv::start().await;
+    let mint = env.create_mint(spl_token_2022::ID, Some(100)).await;
+    let market = env.market(mint, 500, 1_000_000).await;
+
+    let order = env.delivered_order(&market).await;
+    env.complete_order(&market, order).await.unwrap();
+
+    // The vault held the fee withheld on deposit; it must end up on the mint
+    assert!(env.account(escrow_vault_pda(&order)).await.is_none());
+    assert_eq!(env.withheld_in_mint(mint).await, 10_000);
+
+    let order: Order = env.get(order).await;
+    assert_eq!(order.status, OrderStatus::Completed);
+}
+
+#[tokio::test]
+async fn anyone_can_release_once_the_dispute_window_closes() {
+    let mut env = TestEnv::start().await;
+    let mint = env.create_mint(spl_token::ID, None).await;
+    let market = env.market(mint, 500, 1_000_000).await;
+    env.update_marketplace(UpdateMarketplaceParams {
+        auto_release_bounty: Some(1_000),
+        ..Default::default()
+    })
+    .await
+    .unwrap();
+    let caller = env.new_wallet(SOL).await;
+    let caller_token = env.create_token_account(mint, caller.pubkey()).await;
+
+    let order = env.delivered_order(&market).await;
+    assert_error(
+        auto_release(&mut env, &market, order, &caller, Some(caller_token)).await,
+        SolanaGigsError::AutoReleaseNotAvailable,
+    );
+
+    let marketplace: Marketplace = env.get(marketplace_pda()).await;
+    env.advance_time(marketplace.dispute_period + 1).await;
+    auto_release(&mut env, &market, order, &caller, Some(caller_token)).await.unwrap();
+
+    // A tenth of the 5% fee goes to whoever cranked the release
+    assert_eq!(env.token_balance(market.seller_token.unwrap()).await, 950_000);
+    assert_eq!(env.token_balance(caller_token).await, 5_000);
+    assert_eq!(env.token_balance(treasury_vault_pda(&mint)).await, 45_000);
+    assert!(env.account(escrow_vault_pda(&order)).await.is_none());
+
+    let order: Order = env.get(order).await;
+    assert_eq!(order.status, OrderStatus::Completed);
+}
+
+#[tokio::test]
+async fn auto_release_pays_the_referrers_before_the_bounty() {
+    let mut env = TestEnv::start().await;
+    let mint = env.create_mint(spl_token::ID, None).await;
+    let market = env.market(mint, 500, 1_000_000).await;
+    env.update_marketplace(UpdateMarketplaceParams {
+        auto_release_bounty: Some(1_000),
+        referral_share: Some(2_000),
+        ..Default::default()
+    })
+    .await
+    .unwrap();
+    let referrer = env.new_user("referrer").await;
+    let referrer_token = env.create_token_account(mint, referrer.pubkey()).await;
+    env.register_referral(&market.buyer, referrer.pubkey()).await.unwrap();
+    let caller = env.new_wallet(SOL).await;
+    let caller_token = env.create_token_account(mint, caller.pubkey()).await;
+
+    let order = env.delivered_order(&market).await;
+    let marketplace: Marketplace = env.get(marketplace_pda()).await;
+    env.advance_time(marketplace.dispute_period + 1).await;
+    let mut accounts = auto_release_accounts(&market, order, caller.pubkey(), Some(caller_token));
+    accounts.buyer_referrer_token_account = Some(referrer_token);
+    auto_release_with(&mut env, accounts, &caller).await.unwrap();
+
+    // Of the 50_000 fee, a fifth goes to the referrer and a tenth to the caller
+    assert_eq!(env.token_balance(referrer_token).await, 10_000);
+    assert_eq!(env.token_balance(caller_token).await, 5_000);
+    assert_eq!(env.token_balance(treasury_vault_pda(&mint)).await, 35_000);
+    let referral: Referral = env.get(referral_pda(&market.buyer.pubkey())).await;
+    assert_eq!(referral.total_rewards, 10_000);
+}
+
+#[tokio::test]
+async fn finalized_order_rent_returns_after_retention() {
+    let mut env = TestEnv::start().await;
+    let mint = env.create_mint(spl_token::ID, None).await;
+    let market = env.market(mint, 500, 1_000_000).await;
+    let order = env.delivered_order(&market).await;
+    env.complete_order(&market, order).await.unwrap();
+
+    assert_error(
+        env.process(&[close_order_ix(&market, order, None)], &[]).await,
+        SolanaGigsError::RetentionPeriodActive,
+    );
+
+    let marketplace: Marketplace = env.get(marketplace_pda()).await;
+    env.advance_time(marketplace.retention_period).await;
+    let rent = env.lamports(order).await + env.lamports(escrow_pda(&order)).await;
+    let buyer_before = env.lamports(market.buyer.pubkey()).await;
+    env.process(&[close_order_ix(&market, order, None)], &[]).await.unwrap();
+
+    assert_eq!(env.lamports(market.buyer.pubkey()).await, buyer_before + rent);
+    assert!(env.account(order).await.is_none());
+    assert!(env.account(escrow_pda(&order)).await.is_none());
+}
+
+#[tokio::test]
+async fn disputed_order_closes_with_its_dispute() {
+    let mut env = TestEnv::start().await;
+    let mint = env.create_mint(spl_token::ID, None).await;
+    let market = env.market(mint, 500, 1_000_000).await;
+    let order = env.delivered_order(&market).await;
+    env.open_dispute(&market, order).await;
+    let marketplace: Marketplace = env.get(marketplace_pda()).await;
+    env.advance_time(marketplace.response_period + 1).await;
+    env.default_dispute(order, None, None).await.unwrap();
+    env.finalize_dispute(&market, order).await.unwrap();
+
+    env.advance_time(marketplace.retention_period).await;
+    assert_error(
+        env.process(&[close_order_ix(&market, order, None)], &[]).await,
+        SolanaGigsError::NoDisputeFound,
+    );
+    let buyer = market.buyer.pubkey();
+    env.process(&[close_order_ix(&market, order, Some(buyer))], &[]).await.unwrap();
+    assert!(env.account(dispute_pda(&order)).await.is_none());
+}
@@ -0,0 +1,188 @@
+mod common;
+
+use anchor_spl::token::{spl_token, spl_token::native_mint};
+use solana_program_test::BanksClientError;
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+use common::*;
+use solanagigs::errors::SolanaGigsError;
+use solanagigs::instructions::UpdateFeeScheduleParams;
+use solanagigs::state::{
+    CategoryFee, LevelFee, ReputationLevel, SellerVolume, ServiceCategory, VolumeTier,
+};
+
+async fn update_fee_schedule(
+    env: &mut TestEnv,
+    fee_manager: Option<&Keypair>,
+    params: UpdateFeeScheduleParams,
+) -> Result<(), BanksClientError> {
+    let ix = instruction(
+        solanagigs::accounts::UpdateFeeSchedule {
+            marketplace: marketplace_pda(),
+            fee_schedule: fee_schedule_pda(),
+            fee_manager: fee_manager.map_or(env.payer(), |signer| signer.pubkey()),
+        },
+        solanagigs::instruction::UpdateFeeSchedule { params },
+    );
+    let signers: Vec<&Keypair> = fee_manager.into_iter().collect();
+    env.process(&[ix], &signers).await
+}
+
+/// Completes one order and returns the fee the treasury took for it.
+async fn settle(env: &mut TestEnv, market: &Market) -> u64 {
+    let treasury_vault = treasury_vault_pda(&market.mint);
+    let before = env.token_balance(treasury_vault).await;
+    let order = env.delivered_order(market).await;
+    env.complete_order(market, order).await.unwrap();
+    env.token_balance(treasury_vault).await - before
+}
+
+#[tokio::test]
+async fn category_rate_replaces_the_default() {
+    let mut env = TestEnv::start().await;
+    let mint = env.create_mint(spl_token::ID, None).await;
+    let market = env.market(mint, 500, 1_000_000).await;
+    update_fee_schedule(
+        &mut env,
+        None,
+        UpdateFeeScheduleParams {
+            category_fees: Some(vec![CategoryFee {
+                category: ServiceCategory::GraphicsDesign,
+                fee_bps: 300,
+            }]),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(settle(&mut env, &market).await, 30_000);
+}
+
+#[tokio::test]
+async fn level_rate_only_lowers_the_fee() {
+    let mut env = TestEnv::start().await;
+    let mint = env.create_mint(spl_token::ID, None).await;
+    let market = env.market(mint, 500, 1_000_000).await;
+
+    let newcomer_fee = |fee_bps| UpdateFeeScheduleParams {
+        level_fees: Some(vec![LevelFee {
+            level: ReputationLevel::Newcomer,
+            fee_bps,
+        }]),
+        ..Default::default()
+    };
+    update_fee_schedule(&mut env, None, newcomer_fee(800)).await.unwrap();
+    assert_eq!(settle(&mut env, &market).await, 50_000);
+
+    update_fee_schedule(&mut env, None, newcomer_fee(200)).await.unwrap();
+    assert_eq!(settle(&mut env, &market).await, 20_000);
+}
+
+#[tokio::test]
+async fn volume_discount_applies_once_the_tier_is_reached() {
+    let mut env = TestEnv::start().await;
+    let mint = env.create_mint(spl_token::ID, None).await;
+    let market = env.market(mint, 500, 1_000_000).await;
+    update_fee_schedule(
+        &mut env,
+        None,
+        UpdateFeeScheduleParams {
+            volume_tiers: Some(vec![VolumeTier {
+                mint,
+                min_volume: 900_000,
+                discount_bps: 100,
+            }]),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap();
+
+    // The first payout lifts the seller's volume to 950_000, into the tier
+    assert_eq!(settle(&mut env, &market).await, 50_000);
+    assert_eq!(settle(&mut env, &market).await, 40_000);
+}
+
+#[tokio::test]
+async fn volume_tiers_only_count_sales_in_their_mint() {
+    let mut env = TestEnv::start().await;
+    let mint = env.create_mint(spl_token::ID, None).await;
+    let market = env.market(mint, 500, 1_000_000).await;
+    update_fee_schedule(
+        &mut env,
+        None,
+        UpdateFeeScheduleParams {
+            volume_tiers: Some(vec![VolumeTier {
+                mint,
+                min_volume: 900_000,
+                discount_bps: 100,
+            }]),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap();
+
+    // The same seller sells for SOL, far above 900_000 base units
+    let mut sol_market = env.market_for(native_mint::ID, SOL).await;
+    sol_market.seller = market.seller.insecure_clone();
+    sol_market.gig = env
+        .create_gig(&sol_market.seller, native_mint::ID, ServiceCategory::GraphicsDesign, SOL)
+        .await;
+    let order = env.delivered_order(&sol_market).await;
+    env.complete_order(&sol_market, order).await.unwrap();
+    let sol_volume: SellerVolume = env.get(volume_pda(&market.seller.pubkey(), &native_mint::ID)).await;
+    assert_eq!(sol_volume.total_volume, SOL / 100 * 95);
+
+    // None of it counts towards the tier for the token mint
+    assert_eq!(settle(&mut env, &market).await, 50_000);
+    assert_eq!(settle(&mut env, &market).await, 40_000);
+}
+
+#[tokio::test]
+async fn fee_schedule_is_bounded_and_guarded() {
+    let mut env = TestEnv::start().await;
+    env.initialize_marketplace(500).await;
+    let outsider = env.new_wallet(SOL).await;
+
+    assert_error(
+        update_fee_schedule(
+            &mut env,
+            None,
+            UpdateFeeScheduleParams {
+                default_fee_bps: Some(1_001),
+                ..Default::default()
+            },
+        )
+        .await,
+        SolanaGigsError::InvalidPlatformFee,
+    );
+    assert_error(
+        update_fee_schedule(
+            &mut env,
+            None,
+            UpdateFeeScheduleParams {
+                volume_tiers: Some(vec![
+                    VolumeTier { mint: native_mint::ID, min_volume: 10, discount_bps: 50 },
+                    VolumeTier { mint: native_mint::ID, min_volume: 10, discount_bps: 100 },
+                ]),
+                ..Default::default()
+            },
+        )
+        .await,
+        SolanaGigsError::InvalidFeeStructure,
+    );
+    assert_error(
+        update_fee_schedule(
+            &mut env,
+            Some(&outsider),
+            UpdateFeeScheduleParams {
+                default_fee_bps: Some(0),
+                ..Default::default()
+            },
+        )
+        .await,
+        SolanaGigsError::AdminPrivilegesRequired,
+    );
+}
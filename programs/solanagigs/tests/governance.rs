@@ -0,0 +1,200 @@
+mod common;
+
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+use anchor_lang::{InstructionData, ToAccountMetas};
+use solana_program_test::BanksClientError;
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+use common::*;
+use solanagigs::errors::SolanaGigsError;
+use solanagigs::state::{AdminRole, Marketplace, Proposal, ProposalAccount, ProposalStatus};
+
+fn governance_pda() -> Pubkey {
+    pda(&[b"governance"])
+}
+
+fn governance_signer_pda() -> Pubkey {
+    pda(&[b"governance_signer"])
+}
+
+fn proposal_pda(id: u64) -> Pubkey {
+    pda(&[b"proposal", id.to_le_bytes().as_ref()])
+}
+
+struct Council {
+    signers: Vec<Keypair>,
+}
+
+async fn install_governance(env: &mut TestEnv, members: usize, threshold: u8, min_delay: i64) -> Council {
+    env.initialize_marketplace(500).await;
+    let mut signers = Vec::new();
+    for _ in 0..members {
+        signers.push(env.new_wallet(10 * SOL).await);
+    }
+
+    let payer = env.payer();
+    let ix = instruction(
+        solanagigs::accounts::InitializeGovernance {
+            marketplace: marketplace_pda(),
+            governance: governance_pda(),
+            governance_signer: governance_signer_pda(),
+            authority: payer,
+            system_program: anchor_lang::system_program::ID,
+        },
+        solanagigs::instruction::InitializeGovernance {
+            signers: signers.iter().map(|signer| signer.pubkey()).collect(),
+            threshold,
+            min_delay,
+        },
+    );
+    env.process(&[ix], &[]).await.unwrap();
+    Council { signers }
+}
+
+/// A pause issued by the governance signer, the simplest call to route
+/// through a proposal.
+fn pause_call() -> (Vec<ProposalAccount>, Vec<u8>) {
+    let metas = solanagigs::accounts::PauseMarketplace {
+        marketplace: marketplace_pda(),
+        pauser: governance_signer_pda(),
+    }
+    .to_account_metas(Some(true));
+    let accounts = metas
+        .into_iter()
+        .map(|meta| ProposalAccount {
+            pubkey: meta.pubkey,
+            is_signer: meta.is_signer,
+            is_writable: meta.is_writable,
+        })
+        .collect();
+    (accounts, solanagigs::instruction::Pause { emergency: false }.data())
+}
+
+async fn propose(env: &mut TestEnv, proposer: &Keypair) -> Result<Pubkey, BanksClientError> {
+    let governance: solanagigs::state::Governance = env.get(governance_pda()).await;
+    let proposal = proposal_pda(governance.proposal_count);
+    let (accounts, data) = pause_call();
+    let ix = instruction(
+        solanagigs::accounts::CreateProposal {
+            governance: governance_pda(),
+            proposal,
+            proposer: proposer.pubkey(),
+            system_program: anchor_lang::system_program::ID,
+        },
+        solanagigs::instruction::CreateProposal { accounts, data },
+    );
+    env.process(&[ix], &[proposer]).await?;
+    Ok(proposal)
+}
+
+async fn approve(env: &mut TestEnv, proposal: Pubkey, approver: &Keypair) -> Result<(), BanksClientError> {
+    let ix = instruction(
+        solanagigs::accounts::ApproveProposal {
+            governance: governance_pda(),
+            proposal,
+            approver: approver.pubkey(),
+        },
+        solanagigs::instruction::ApproveProposal {},
+    );
+    env.process(&[ix], &[approver]).await
+}
+
+fn execute_ix(proposal: Pubkey, executor: Pubkey) -> Instruction {
+    let mut ix = instruction(
+        solanagigs::accounts::ExecuteProposal {
+            governance: governance_pda(),
+            proposal,
+            governance_signer: governance_signer_pda(),
+            executor,
+        },
+        solanagigs::instruction::ExecuteProposal {},
+    );
+    // The proposal's own accounts ride along as remaining accounts
+    ix.accounts.push(AccountMeta::new(marketplace_pda(), false));
+    ix.accounts.push(AccountMeta::new_readonly(solanagigs::ID, false));
+    ix
+}
+
+#[tokio::test]
+async fn installing_governance_moves_authority_and_roles() {
+    let mut env = TestEnv::start().await;
+    install_governance(&mut env, 3, 2, DAY).await;
+
+    let marketplace: Marketplace = env.get(marketplace_pda()).await;
+    assert_eq!(marketplace.authority, governance_signer_pda());
+    for role in [
+        AdminRole::FeeManager,
+        AdminRole::Pauser,
+        AdminRole::DisputeAdmin,
+        AdminRole::CategoryCurator,
+        AdminRole::Verifier,
+    ] {
+        assert_eq!(marketplace.role_holder(role), governance_signer_pda());
+    }
+
+    // The key that installed governance can no longer pause on its own
+    let payer = env.payer();
+    let pause = instruction(
+        solanagigs::accounts::PauseMarketplace {
+            marketplace: marketplace_pda(),
+            pauser: payer,
+        },
+        solanagigs::instruction::Pause { emergency: true },
+    );
+    assert_error(env.process(&[pause], &[]).await, SolanaGigsError::AdminPrivilegesRequired);
+}
+
+#[tokio::test]
+async fn proposal_needs_threshold_and_timelock() {
+    let mut env = TestEnv::start().await;
+    let council = install_governance(&mut env, 3, 2, DAY).await;
+
+    let proposal = propose(&mut env, &council.signers[0]).await.unwrap();
+    let executor = env.payer();
+    assert_error(
+        env.process(&[execute_ix(proposal, executor)], &[]).await,
+        SolanaGigsError::InvalidProposalStatus,
+    );
+    assert_error(
+        approve(&mut env, proposal, &council.signers[0]).await,
+        SolanaGigsError::AlreadyApproved,
+    );
+
+    approve(&mut env, proposal, &council.signers[1]).await.unwrap();
+    let approved: Proposal = env.get(proposal).await;
+    assert_eq!(approved.status, ProposalStatus::Approved);
+    assert_error(
+        env.process(&[execute_ix(proposal, executor)], &[]).await,
+        SolanaGigsError::ProposalNotReady,
+    );
+
+    env.advance_time(DAY).await;
+    env.process(&[execute_ix(proposal, executor)], &[]).await.unwrap();
+
+    let marketplace: Marketplace = env.get(marketplace_pda()).await;
+    assert!(marketplace.is_paused);
+    let executed: Proposal = env.get(proposal).await;
+    assert_eq!(executed.status, ProposalStatus::Executed);
+    assert_error(
+        env.process(&[execute_ix(proposal, executor)], &[]).await,
+        SolanaGigsError::InvalidProposalStatus,
+    );
+}
+
+#[tokio::test]
+async fn outsiders_cannot_propose_or_approve() {
+    let mut env = TestEnv::start().await;
+    let council = install_governance(&mut env, 2, 2, 0).await;
+    let outsider = env.new_wallet(10 * SOL).await;
+
+    assert_error(propose(&mut env, &outsider).await.map(|_| ()), SolanaGigsError::AdminPrivilegesRequired);
+
+    let proposal = propose(&mut env, &council.signers[0]).await.unwrap();
+    assert_error(
+        approve(&mut env, proposal, &outsider).await,
+        SolanaGigsError::AdminPrivilegesRequired,
+    );
+}
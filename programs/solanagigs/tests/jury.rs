@@ -0,0 +1,230 @@
+mod common;
+
+use anchor_lang::solana_program::pubkey::Pubkey;
+use anchor_spl::token::{spl_token, spl_token::native_mint};
+use solana_sdk::signer::Signer;
+
+use common::*;
+use solanagigs::errors::SolanaGigsError;
+use solanagigs::state::{Dispute, DisputeStatus, JuryCase, JurorStake, JuryStatus};
+
+/// Disputes a delivered order and has a five-juror panel vote `refund_bps`
+/// unanimously.
+async fn jury_verdict(env: &mut TestEnv, market: &Market, refund_bps: u16) -> (Pubkey, Jury, Vec<Pubkey>) {
+    let jury = env.register_jurors(JuryCase::PANEL_SIZE).await;
+    let order = env.delivered_order(market).await;
+    env.open_dispute(market, order).await;
+    let panel = env.draw_jury(market, order, &jury).await.unwrap();
+
+    for juror in &panel {
+        env.commit_vote(order, jury.juror(juror), refund_bps).await.unwrap();
+    }
+    for juror in &panel {
+        env.reveal_vote(order, jury.juror(juror), refund_bps).await.unwrap();
+    }
+    env.settle_jury(order, &panel).await.unwrap();
+    (order, jury, panel)
+}
+
+async fn finalize_jury_verdict(env: &mut TestEnv, market: &Market, order: Pubkey) {
+    let mut accounts = env.finalize_dispute_accounts(market, order);
+    accounts.jury_case = Some(jury_pda(&order));
+    env.process(&[instruction(accounts, solanagigs::instruction::FinalizeDispute {})], &[])
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn jury_verdict_waits_for_the_appeal_window() {
+    let mut env = TestEnv::start().await;
+    let mint = env.create_mint(spl_token::ID, None).await;
+    let market = env.market(mint, 500, 1_000_000).await;
+    let (order, _, _) = jury_verdict(&mut env, &market, 5_000).await;
+
+    let dispute: Dispute = env.get(dispute_pda(&order)).await;
+    assert_eq!(dispute.status, DisputeStatus::PendingSettlement);
+    assert_eq!(dispute.refund_bps, 5_000);
+    assert!(dispute.appeal_deadline > env.now().await);
+    assert_eq!(env.token_balance(escrow_vault_pda(&order)).await, 1_000_000);
+
+    let mut accounts = env.finalize_dispute_accounts(&market, order);
+    accounts.jury_case = Some(jury_pda(&order));
+    assert_error(
+        env.process(&[instruction(accounts, solanagigs::instruction::FinalizeDispute {})], &[]).await,
+        SolanaGigsError::AppealWindowOpen,
+    );
+
+    env.appeal_dispute(order, &market.buyer).await.unwrap();
+    let dispute: Dispute = env.get(dispute_pda(&order)).await;
+    assert_eq!(dispute.status, DisputeStatus::Appealed);
+}
+
+#[tokio::test]
+async fn finalized_jury_verdict_pays_the_fee_to_the_panel() {
+    let mut env = TestEnv::start().await;
+    let mint = env.create_mint(spl_token::ID, None).await;
+    let market = env.market(mint, 500, 1_000_000).await;
+    let (order, jury, panel) = jury_verdict(&mut env, &market, 0).await;
+    let marketplace: solanagigs::state::Marketplace = env.get(marketplace_pda()).await;
+    env.advance_time(marketplace.appeal_period).await;
+
+    assert_error(
+        env.claim_juror_reward(&market, order, jury.juror(&panel[0])).await,
+        SolanaGigsError::InvalidOrderStatus,
+    );
+    assert_error(
+        env.finalize_dispute(&market, order).await,
+        SolanaGigsError::InvalidResolutionMode,
+    );
+
+    finalize_jury_verdict(&mut env, &market, order).await;
+
+    // The 5% fee stays in the vault for the panel instead of the treasury
+    assert_eq!(env.token_balance(market.seller_token.unwrap()).await, 950_000);
+    assert_eq!(env.token_balance(treasury_vault_pda(&market.mint)).await, 0);
+    assert_eq!(env.token_balance(escrow_vault_pda(&order)).await, 50_000);
+    let jury_case: JuryCase = env.get(jury_pda(&order)).await;
+    assert_eq!(jury_case.status, JuryStatus::Settled);
+    assert_eq!(jury_case.juror_fee, 50_000);
+
+    for juror in &panel {
+        env.claim_juror_reward(&market, order, jury.juror(juror)).await.unwrap();
+    }
+    assert!(env.account(escrow_vault_pda(&order)).await.is_none());
+}
+
+#[tokio::test]
+async fn failed_panel_slashes_stakes_into_the_treasury() {
+    let mut env = TestEnv::start().await;
+    let mint = env.create_mint(spl_token::ID, None).await;
+    let market = env.market(mint, 500, 1_000_000).await;
+    let jury = env.register_jurors(JuryCase::PANEL_SIZE).await;
+    let order = env.delivered_order(&market).await;
+    env.open_dispute(&market, order).await;
+    let panel = env.draw_jury(&market, order, &jury).await.unwrap();
+
+    env.advance_time(JuryCase::COMMIT_PERIOD + JuryCase::REVEAL_PERIOD + 1).await;
+    let treasury = treasury_pda(&native_mint::ID);
+    let treasury_before = env.lamports(treasury).await;
+    env.settle_jury(order, &panel).await.unwrap();
+
+    let slash = JurorStake::MIN_STAKE / 5;
+    assert_eq!(env.lamports(treasury).await, treasury_before + slash * JuryCase::PANEL_SIZE as u64);
+    let dispute: Dispute = env.get(dispute_pda(&order)).await;
+    assert_eq!(dispute.status, DisputeStatus::Open);
+}
+
+#[tokio::test]
+async fn jurors_registered_after_the_dispute_are_not_drawn() {
+    let mut env = TestEnv::start().await;
+    let mint = env.create_mint(spl_token::ID, None).await;
+    let market = env.market(mint, 500, 1_000_000).await;
+    let jury = env.register_jurors(JuryCase::PANEL_SIZE).await;
+    let order = env.delivered_order(&market).await;
+    env.open_dispute(&market, order).await;
+
+    let late = env.register_jurors(JuryCase::PANEL_SIZE).await;
+    let dispute: Dispute = env.get(dispute_pda(&order)).await;
+    assert_eq!(dispute.juror_pool, JuryCase::PANEL_SIZE as u32);
+
+    let panel = env.draw_jury(&market, order, &jury).await.unwrap();
+    for juror in &late.jurors {
+        assert!(!panel.contains(&juror.pubkey()));
+    }
+}
+
+#[tokio::test]
+async fn unclaimed_juror_fee_is_swept_after_the_deadline() {
+    let mut env = TestEnv::start().await;
+    let mint = env.create_mint(spl_token::ID, None).await;
+    let market = env.market(mint, 500, 1_000_000).await;
+    let (order, jury, panel) = jury_verdict(&mut env, &market, 0).await;
+    let marketplace: solanagigs::state::Marketplace = env.get(marketplace_pda()).await;
+    env.advance_time(marketplace.appeal_period).await;
+    finalize_jury_verdict(&mut env, &market, order).await;
+
+    for juror in &panel[..2] {
+        env.claim_juror_reward(&market, order, jury.juror(juror)).await.unwrap();
+    }
+    assert_error(
+        env.sweep_juror_rewards(&market, order).await,
+        SolanaGigsError::ClaimDeadlineNotReached,
+    );
+
+    env.advance_time(JuryCase::CLAIM_PERIOD + 1).await;
+    assert_error(
+        env.claim_juror_reward(&market, order, jury.juror(&panel[2])).await,
+        SolanaGigsError::ClaimDeadlinePassed,
+    );
+    env.sweep_juror_rewards(&market, order).await.unwrap();
+
+    assert_eq!(env.token_balance(treasury_vault_pda(&market.mint)).await, 30_000);
+    assert!(env.account(escrow_vault_pda(&order)).await.is_none());
+    let jury_case: JuryCase = env.get(jury_pda(&order)).await;
+    assert_eq!(jury_case.status, JuryStatus::Expired);
+}
+
+/// Full refund verdict on an SPL escrow where the last panelist never
+/// reveals, so the case holds a slashed stake but no juror fee.
+async fn no_show_refund_verdict(env: &mut TestEnv, market: &Market) -> (Pubkey, Jury, Vec<Pubkey>) {
+    let jury = env.register_jurors(JuryCase::PANEL_SIZE).await;
+    let order = env.delivered_order(market).await;
+    env.open_dispute(market, order).await;
+    let panel = env.draw_jury(market, order, &jury).await.unwrap();
+
+    for juror in &panel {
+        env.commit_vote(order, jury.juror(juror), 10_000).await.unwrap();
+    }
+    for juror in &panel[1..] {
+        env.reveal_vote(order, jury.juror(juror), 10_000).await.unwrap();
+    }
+    env.advance_time(JuryCase::COMMIT_PERIOD + JuryCase::REVEAL_PERIOD + 1).await;
+    env.settle_jury(order, &panel).await.unwrap();
+
+    let marketplace: solanagigs::state::Marketplace = env.get(marketplace_pda()).await;
+    env.advance_time(marketplace.appeal_period).await;
+    finalize_jury_verdict(env, market, order).await;
+    (order, jury, panel)
+}
+
+#[tokio::test]
+async fn full_refund_verdict_still_pays_out_slashed_stakes() {
+    let mut env = TestEnv::start().await;
+    let mint = env.create_mint(spl_token::ID, None).await;
+    let market = env.market(mint, 500, 1_000_000).await;
+    let (order, jury, panel) = no_show_refund_verdict(&mut env, &market).await;
+
+    // Nothing is left for the panel, so finalize already closed the vault
+    assert!(env.account(escrow_vault_pda(&order)).await.is_none());
+    let slash = JurorStake::MIN_STAKE / 5;
+    let jury_case: JuryCase = env.get(jury_pda(&order)).await;
+    assert_eq!(jury_case.juror_fee, 0);
+    assert_eq!(jury_case.slashed_pool, slash);
+
+    for juror in &panel[1..] {
+        env.claim_juror_reward(&market, order, jury.juror(juror)).await.unwrap();
+    }
+    let jury_case: JuryCase = env.get(jury_pda(&order)).await;
+    assert_eq!(jury_case.claims_made, 4);
+    assert_eq!(jury_case.slashed_pool, 0);
+}
+
+#[tokio::test]
+async fn full_refund_verdict_sweeps_unclaimed_slashed_stakes() {
+    let mut env = TestEnv::start().await;
+    let mint = env.create_mint(spl_token::ID, None).await;
+    let market = env.market(mint, 500, 1_000_000).await;
+    let (order, jury, panel) = no_show_refund_verdict(&mut env, &market).await;
+
+    env.claim_juror_reward(&market, order, jury.juror(&panel[1])).await.unwrap();
+    env.advance_time(JuryCase::CLAIM_PERIOD + 1).await;
+    let treasury = treasury_pda(&native_mint::ID);
+    let treasury_before = env.lamports(treasury).await;
+    env.sweep_juror_rewards(&market, order).await.unwrap();
+
+    let slash = JurorStake::MIN_STAKE / 5;
+    assert_eq!(env.lamports(treasury).await, treasury_before + slash - slash / 4);
+    let jury_case: JuryCase = env.get(jury_pda(&order)).await;
+    assert_eq!(jury_case.status, JuryStatus::Expired);
+    assert_eq!(jury_case.slashed_pool, 0);
+}
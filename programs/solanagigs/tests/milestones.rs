@@ -0,0 +1,162 @@
+mod common;
+
+use anchor_lang::solana_program::pubkey::Pubkey;
+use anchor_spl::token::spl_token;
+use solana_program_test::BanksClientError;
+use solana_sdk::signer::Signer;
+
+use common::*;
+use solanagigs::errors::SolanaGigsError;
+use solanagigs::instructions::{MilestoneParams, UpdateMarketplaceParams};
+use solanagigs::state::{MilestoneStatus, Order, OrderMilestone, OrderStatus, Referral};
+
+async fn milestone_order(env: &mut TestEnv, market: &Market, amounts: &[u64]) -> Pubkey {
+    let due_date = env.now().await + 7 * DAY;
+    let milestones = amounts
+        .iter()
+        .map(|&amount| MilestoneParams {
+            title: "Stage".to_string(),
+            description: String::new(),
+            amount,
+            due_date,
+        })
+        .collect();
+    let order = env
+        .try_create_order_with(market, 0, Vec::new(), milestones)
+        .await
+        .unwrap();
+    env.accept_order(market, order).await;
+    order
+}
+
+async fn submit_milestone(env: &mut TestEnv, market: &Market, order: Pubkey, id: u8) -> Result<(), BanksClientError> {
+    let ix = instruction(
+        solanagigs::accounts::SubmitMilestone {
+            order,
+            milestone: milestone_pda(&order, id),
+            seller: market.seller.pubkey(),
+        },
+        solanagigs::instruction::SubmitMilestone {
+            deliverable_url: "https://example.com/stage".to_string(),
+        },
+    );
+    env.process(&[ix], &[&market.seller]).await
+}
+
+fn approve_milestone_accounts(market: &Market, order: Pubkey, id: u8) -> solanagigs::accounts::ApproveMilestone {
+    let native = market.is_native();
+    solanagigs::accounts::ApproveMilestone {
+        marketplace: marketplace_pda(),
+        fee_schedule: fee_schedule_pda(),
+        gig: market.gig,
+        order,
+        milestone: milestone_pda(&order, id),
+        escrow: escrow_pda(&order),
+        vault: (!native).then(|| escrow_vault_pda(&order)),
+        mint: (!native).then_some(market.mint),
+        seller_profile: user_pda(&market.seller.pubkey()),
+        seller_volume: volume_pda(&market.seller.pubkey(), &market.mint),
+        seller_token_account: market.seller_token,
+        seller_earnings: earnings_pda(&market.seller.pubkey(), &market.mint),
+        earnings_vault: None,
+        buyer_referral: referral_pda(&market.buyer.pubkey()),
+        buyer_referrer_token_account: None,
+        buyer_referrer: None,
+        seller_referral: referral_pda(&market.seller.pubkey()),
+        seller_referrer_token_account: None,
+        seller_referrer: None,
+        treasury: treasury_pda(&market.mint),
+        treasury_vault: (!native).then(|| treasury_vault_pda(&market.mint)),
+        seller: native.then(|| market.seller.pubkey()),
+        buyer: market.buyer.pubkey(),
+        token_program: market.token_program,
+    }
+}
+
+async fn approve_milestone_with(
+    env: &mut TestEnv,
+    market: &Market,
+    accounts: solanagigs::accounts::ApproveMilestone,
+) -> Result<(), BanksClientError> {
+    let ix = instruction(accounts, solanagigs::instruction::ApproveMilestone {});
+    env.process(&[ix], &[&market.buyer]).await
+}
+
+async fn approve_milestone(env: &mut TestEnv, market: &Market, order: Pubkey, id: u8) -> Result<(), BanksClientError> {
+    let accounts = approve_milestone_accounts(market, order, id);
+    approve_milestone_with(env, market, accounts).await
+}
+
+#[tokio::test]
+async fn each_approved_milestone_releases_its_share() {
+    let mut env = TestEnv::start().await;
+    let mint = env.create_mint(spl_token::ID, None).await;
+    let market = env.market(mint, 500, 1_000_000).await;
+    let order = milestone_order(&mut env, &market, &[400_000, 600_000]).await;
+    let seller_token = market.seller_token.unwrap();
+
+    submit_milestone(&mut env, &market, order, 0).await.unwrap();
+    approve_milestone(&mut env, &market, order, 0).await.unwrap();
+
+    assert_eq!(env.token_balance(seller_token).await, 380_000);
+    let first: OrderMilestone = env.get(milestone_pda(&order, 0)).await;
+    assert_eq!(first.status, MilestoneStatus::Approved);
+    let state: Order = env.get(order).await;
+    assert_eq!(state.status, OrderStatus::InProgress);
+
+    submit_milestone(&mut env, &market, order, 1).await.unwrap();
+    approve_milestone(&mut env, &market, order, 1).await.unwrap();
+
+    assert_eq!(env.token_balance(seller_token).await, 950_000);
+    assert_eq!(env.token_balance(treasury_vault_pda(&market.mint)).await, 50_000);
+    let state: Order = env.get(order).await;
+    assert_eq!(state.status, OrderStatus::Completed);
+    assert!(env.account(escrow_vault_pda(&order)).await.is_none());
+}
+
+#[tokio::test]
+async fn milestones_are_delivered_in_sequence() {
+    let mut env = TestEnv::start().await;
+    let mint = env.create_mint(spl_token::ID, None).await;
+    let market = env.market(mint, 500, 1_000_000).await;
+    let order = milestone_order(&mut env, &market, &[400_000, 600_000]).await;
+
+    assert_error(
+        submit_milestone(&mut env, &market, order, 1).await,
+        SolanaGigsError::CannotCompleteMilestoneOutOfOrder,
+    );
+    assert_error(
+        approve_milestone(&mut env, &market, order, 0).await,
+        SolanaGigsError::InvalidMilestoneDetails,
+    );
+}
+
+#[tokio::test]
+async fn every_milestone_release_pays_the_referrers() {
+    let mut env = TestEnv::start().await;
+    let mint = env.create_mint(spl_token::ID, None).await;
+    let market = env.market(mint, 500, 1_000_000).await;
+    env.update_marketplace(UpdateMarketplaceParams {
+        referral_share: Some(2_000),
+        ..Default::default()
+    })
+    .await
+    .unwrap();
+    let referrer = env.new_user("referrer").await;
+    let referrer_token = env.create_token_account(mint, referrer.pubkey()).await;
+    env.register_referral(&market.seller, referrer.pubkey()).await.unwrap();
+    let order = milestone_order(&mut env, &market, &[400_000, 600_000]).await;
+
+    for id in 0..2 {
+        submit_milestone(&mut env, &market, order, id).await.unwrap();
+        let mut accounts = approve_milestone_accounts(&market, order, id);
+        accounts.seller_referrer_token_account = Some(referrer_token);
+        approve_milestone_with(&mut env, &market, accounts).await.unwrap();
+    }
+
+    // A fifth of each 5% fee goes to the seller's referrer
+    assert_eq!(env.token_balance(referrer_token).await, 10_000);
+    assert_eq!(env.token_balance(treasury_vault_pda(&mint)).await, 40_000);
+    let referral: Referral = env.get(referral_pda(&market.seller.pubkey())).await;
+    assert_eq!(referral.reward_count, 2);
+}
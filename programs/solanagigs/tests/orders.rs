@@ -0,0 +1,352 @@
+mod common;
+
+use anchor_lang::solana_program::pubkey::Pubkey;
+use anchor_spl::token::spl_token;
+use solana_program_test::BanksClientError;
+use solana_sdk::signer::Signer;
+
+use common::*;
+use solanagigs::errors::SolanaGigsError;
+use solanagigs::instructions::{MilestoneParams, UpdateGigParams};
+use solanagigs::state::{GigExtra, GigPackage, MilestoneStatus, Order, OrderMilestone, OrderRevision, OrderStatus, RevisionStatus};
+
+fn extension_pda(order: &Pubkey, number: u8) -> Pubkey {
+    pda(&[b"extension", order.as_ref(), &[number]])
+}
+
+async fn request_extension(env: &mut TestEnv, market: &Market, order: Pubkey) -> Result<(), BanksClientError> {
+    let state: Order = env.get(order).await;
+    let ix = instruction(
+        solanagigs::accounts::RequestExtension {
+            order,
+            extension: extension_pda(&order, state.extension_count.saturating_add(1)),
+            seller: market.seller.pubkey(),
+            system_program: anchor_lang::system_program::ID,
+        },
+        solanagigs::instruction::RequestExtension {
+            new_deadline: state.delivery_deadline + DAY,
+            price_concession: 0,
+            reason: "Waiting on the brand assets".to_string(),
+        },
+    );
+    env.process(&[ix], &[&market.seller]).await
+}
+
+async fn decline_extension(env: &mut TestEnv, market: &Market, order: Pubkey) {
+    let state: Order = env.get(order).await;
+    let native = market.is_native();
+    let ix = instruction(
+        solanagigs::accounts::RespondExtension {
+            marketplace: marketplace_pda(),
+            order,
+            escrow: escrow_pda(&order),
+            extension: extension_pda(&order, state.extension_count),
+            vault: (!native).then(|| escrow_vault_pda(&order)),
+            mint: (!native).then_some(market.mint),
+            buyer_token_account: market.buyer_token,
+            buyer: market.buyer.pubkey(),
+            token_program: market.token_program,
+        },
+        solanagigs::instruction::RespondExtension { accept: false },
+    );
+    env.process(&[ix], &[&market.buyer]).await.unwrap();
+}
+
+fn revision_pda(order: &Pubkey, number: u8) -> Pubkey {
+    pda(&[b"revision", order.as_ref(), &[number]])
+}
+
+async fn request_revision(env: &mut TestEnv, market: &Market, order: Pubkey) -> Result<(), BanksClientError> {
+    let state: Order = env.get(order).await;
+    let ix = instruction(
+        solanagigs::accounts::RequestRevision {
+            marketplace: marketplace_pda(),
+            order,
+            escrow: escrow_pda(&order),
+            revision: revision_pda(&order, state.revision_count + 1),
+            buyer: market.buyer.pubkey(),
+            system_program: anchor_lang::system_program::ID,
+        },
+        solanagigs::instruction::RequestRevision {
+            reason: "The colours are off".to_string(),
+            description: String::new(),
+            extension_days: None,
+        },
+    );
+    env.process(&[ix], &[&market.buyer]).await
+}
+
+async fn submit_revision(env: &mut TestEnv, market: &Market, order: Pubkey) -> Result<(), BanksClientError> {
+    let state: Order = env.get(order).await;
+    let ix = instruction(
+        solanagigs::accounts::SubmitRevision {
+            marketplace: marketplace_pda(),
+            order,
+            escrow: escrow_pda(&order),
+            revision: revision_pda(&order, state.revision_count),
+            seller: market.seller.pubkey(),
+        },
+        solanagigs::instruction::SubmitRevision {
+            deliverable_url: "https://example.com/v2".to_string(),
+            feedback: None,
+        },
+    );
+    env.process(&[ix], &[&market.seller]).await
+}
+
+/// Gives the market's gig a premium package and two extras.
+async fn tier_gig(env: &mut TestEnv, market: &Market) {
+    let package = |name: &str, price, delivery_time, revisions| GigPackage {
+        name: name.to_string(),
+        description: String::new(),
+        price,
+        delivery_time,
+        revisions,
+        features: Vec::new(),
+    };
+    let extra = |name: &str, price, delivery_time_delta, additional_revisions| GigExtra {
+        name: name.to_string(),
+        price,
+        delivery_time_delta,
+        additional_revisions,
+    };
+    let ix = instruction(
+        solanagigs::accounts::UpdateGig {
+            gig: market.gig,
+            accepted_mint: accepted_mint_pda(&market.mint),
+            seller: market.seller.pubkey(),
+        },
+        solanagigs::instruction::UpdateGig {
+            params: UpdateGigParams {
+                packages: Some(vec![
+                    package("Basic", 1_000_000, 3, 1),
+                    package("Premium", 2_000_000, 5, 3),
+                ]),
+                extras: Some(vec![
+                    extra("Express", 300_000, -2, 0),
+                    extra("Source files", 100_000, 0, 1),
+                ]),
+                ..Default::default()
+            },
+        },
+    );
+    env.process(&[ix], &[&market.seller]).await.unwrap();
+}
+
+fn milestone(amount: u64, due_date: i64) -> MilestoneParams {
+    MilestoneParams {
+        title: "Wireframes".to_string(),
+        description: String::new(),
+        amount,
+        due_date,
+    }
+}
+
+#[tokio::test]
+async fn order_total_is_checked_against_current_mint_bounds() {
+    let mut env = TestEnv::start().await;
+    let mint = env.create_mint(spl_token::ID, None).await;
+    let market = env.market(mint, 500, 1_000_000).await;
+
+    env.update_accepted_mint(mint, Some(500_000), None).await.unwrap();
+    assert_error(
+        env.try_create_order(&market, Vec::new()).await.map(|_| ()),
+        SolanaGigsError::GigPriceTooHigh,
+    );
+
+    env.update_accepted_mint(mint, Some(1_000_000), None).await.unwrap();
+    env.create_order(&market).await;
+}
+
+#[tokio::test]
+async fn deactivated_mint_stops_new_orders() {
+    let mut env = TestEnv::start().await;
+    let mint = env.create_mint(spl_token::ID, None).await;
+    let market = env.market(mint, 500, 1_000_000).await;
+
+    let payer = env.payer();
+    let ix = instruction(
+        solanagigs::accounts::UpdateAcceptedMint {
+            marketplace: marketplace_pda(),
+            accepted_mint: accepted_mint_pda(&mint),
+            authority: payer,
+        },
+        solanagigs::instruction::UpdateAcceptedMint {
+            min_price: None,
+            max_price: None,
+            withdrawal_threshold: None,
+            is_active: Some(false),
+        },
+    );
+    env.process(&[ix], &[]).await.unwrap();
+
+    assert_error(
+        env.try_create_order(&market, Vec::new()).await.map(|_| ()),
+        SolanaGigsError::UnsupportedToken,
+    );
+}
+
+#[tokio::test]
+async fn extension_requests_are_capped() {
+    let mut env = TestEnv::start().await;
+    let mint = env.create_mint(spl_token::ID, None).await;
+    let market = env.market(mint, 500, 1_000_000).await;
+    let order = env.create_order(&market).await;
+    env.accept_order(&market, order).await;
+
+    for _ in 0..Order::MAX_EXTENSIONS {
+        request_extension(&mut env, &market, order).await.unwrap();
+        decline_extension(&mut env, &market, order).await;
+    }
+    assert_error(
+        request_extension(&mut env, &market, order).await,
+        SolanaGigsError::TooManyExtensions,
+    );
+}
+
+#[tokio::test]
+async fn delivery_clears_a_pending_extension() {
+    let mut env = TestEnv::start().await;
+    let mint = env.create_mint(spl_token::ID, None).await;
+    let market = env.market(mint, 500, 1_000_000).await;
+    let order = env.create_order(&market).await;
+    env.accept_order(&market, order).await;
+    request_extension(&mut env, &market, order).await.unwrap();
+    env.submit_delivery(&market, order).await;
+
+    request_revision(&mut env, &market, order).await.unwrap();
+
+    let state: Order = env.get(order).await;
+    assert_eq!(state.status, OrderStatus::InProgress);
+    assert!(!state.has_pending_extension);
+    request_extension(&mut env, &market, order).await.unwrap();
+}
+
+#[tokio::test]
+async fn milestones_are_fixed_when_the_order_is_placed() {
+    let mut env = TestEnv::start().await;
+    let mint = env.create_mint(spl_token::ID, None).await;
+    let market = env.market(mint, 500, 1_000_000).await;
+    let due_date = env.now().await + 7 * DAY;
+
+    let order = env
+        .try_create_order_with(
+            &market,
+            0,
+            Vec::new(),
+            vec![milestone(400_000, due_date), milestone(600_000, due_date + DAY)],
+        )
+        .await
+        .unwrap();
+
+    let state: Order = env.get(order).await;
+    assert_eq!(state.milestone_count, 2);
+    assert_eq!(state.milestones_total, 1_000_000);
+
+    let second: OrderMilestone = env.get(milestone_pda(&order, 1)).await;
+    assert_eq!(second.order, order);
+    assert_eq!(second.milestone_id, 1);
+    assert_eq!(second.amount, 600_000);
+    assert_eq!(second.due_date, due_date + DAY);
+    assert_eq!(second.status, MilestoneStatus::Pending);
+}
+
+#[tokio::test]
+async fn milestones_must_add_up_to_the_order_amount() {
+    let mut env = TestEnv::start().await;
+    let mint = env.create_mint(spl_token::ID, None).await;
+    let market = env.market(mint, 500, 1_000_000).await;
+    let due_date = env.now().await + 7 * DAY;
+
+    assert_error(
+        env.try_create_order_with(
+            &market,
+            0,
+            Vec::new(),
+            vec![milestone(400_000, due_date), milestone(500_000, due_date)],
+        )
+        .await
+        .map(|_| ()),
+        SolanaGigsError::MilestoneTotalMismatch,
+    );
+
+    let too_many = (0..=Order::MAX_MILESTONES).map(|_| milestone(1, due_date)).collect();
+    assert_error(
+        env.try_create_order_with(&market, 0, Vec::new(), too_many)
+            .await
+            .map(|_| ()),
+        SolanaGigsError::InvalidMilestoneCount,
+    );
+}
+
+#[tokio::test]
+async fn revisions_cycle_until_the_limit() {
+    let mut env = TestEnv::start().await;
+    let mint = env.create_mint(spl_token::ID, None).await;
+    let market = env.market(mint, 500, 1_000_000).await;
+    let order = env.delivered_order(&market).await;
+
+    request_revision(&mut env, &market, order).await.unwrap();
+    let state: Order = env.get(order).await;
+    assert_eq!(state.status, OrderStatus::InProgress);
+    assert_eq!(state.revision_count, 1);
+
+    submit_revision(&mut env, &market, order).await.unwrap();
+    let state: Order = env.get(order).await;
+    assert_eq!(state.status, OrderStatus::Delivered);
+    let revision: OrderRevision = env.get(revision_pda(&order, 1)).await;
+    assert_eq!(revision.status, RevisionStatus::Completed);
+
+    // The gig allows a single revision
+    assert_error(
+        request_revision(&mut env, &market, order).await,
+        SolanaGigsError::RevisionLimitExceeded,
+    );
+    env.complete_order(&market, order).await.unwrap();
+}
+
+#[tokio::test]
+async fn chosen_package_sets_the_order_terms() {
+    let mut env = TestEnv::start().await;
+    let mint = env.create_mint(spl_token::ID, None).await;
+    let market = env.market(mint, 500, 1_000_000).await;
+    tier_gig(&mut env, &market).await;
+
+    let placed_at = env.now().await;
+    let order = env.try_create_order_with(&market, 1, Vec::new(), Vec::new()).await.unwrap();
+
+    let state: Order = env.get(order).await;
+    assert_eq!(state.package_index, 1);
+    assert_eq!(state.amount, 2_000_000);
+    assert_eq!(state.max_revisions, 3);
+    assert_eq!(state.delivery_deadline, placed_at + 5 * DAY);
+    assert_eq!(env.token_balance(escrow_vault_pda(&order)).await, 2_000_000);
+
+    assert_error(
+        env.try_create_order_with(&market, 2, Vec::new(), Vec::new()).await.map(|_| ()),
+        SolanaGigsError::InvalidPackageIndex,
+    );
+}
+
+#[tokio::test]
+async fn extras_are_charged_on_top_of_the_package() {
+    let mut env = TestEnv::start().await;
+    let mint = env.create_mint(spl_token::ID, None).await;
+    let market = env.market(mint, 500, 1_000_000).await;
+    tier_gig(&mut env, &market).await;
+
+    let placed_at = env.now().await;
+    let order = env.try_create_order_with(&market, 1, vec![0, 1], Vec::new()).await.unwrap();
+
+    let state: Order = env.get(order).await;
+    assert_eq!(state.extras, vec![0, 1]);
+    assert_eq!(state.amount, 2_400_000);
+    assert_eq!(state.max_revisions, 4);
+    assert_eq!(state.delivery_deadline, placed_at + 3 * DAY);
+    assert_eq!(env.token_balance(escrow_vault_pda(&order)).await, 2_400_000);
+
+    assert_error(
+        env.try_create_order_with(&market, 0, vec![1, 1], Vec::new()).await.map(|_| ()),
+        SolanaGigsError::InvalidExtraIndex,
+    );
+}
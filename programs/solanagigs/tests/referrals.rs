@@ -0,0 +1,87 @@
+mod common;
+
+use anchor_lang::error::ErrorCode;
+use anchor_spl::token::spl_token;
+use solana_sdk::signer::Signer;
+
+use common::*;
+use solanagigs::errors::SolanaGigsError;
+use solanagigs::instructions::UpdateMarketplaceParams;
+use solanagigs::state::Referral;
+
+async fn referral_market(env: &mut TestEnv, referral_share: u16) -> Market {
+    let mint = env.create_mint(spl_token::ID, None).await;
+    let market = env.market(mint, 500, 1_000_000).await;
+    env.update_marketplace(UpdateMarketplaceParams {
+        referral_share: Some(referral_share),
+        ..Default::default()
+    })
+    .await
+    .unwrap();
+    market
+}
+
+#[tokio::test]
+async fn referrer_is_paid_out_of_the_fee() {
+    let mut env = TestEnv::start().await;
+    let market = referral_market(&mut env, 2_000).await;
+    let referrer = env.new_user("referrer").await;
+    let referrer_token = env.create_token_account(market.mint, referrer.pubkey()).await;
+    env.register_referral(&market.buyer, referrer.pubkey()).await.unwrap();
+
+    let order = env.delivered_order(&market).await;
+    let mut accounts = env.complete_order_accounts(&market, order);
+    accounts.buyer_referrer_token_account = Some(referrer_token);
+    env.complete_order_with(&market, accounts).await.unwrap();
+
+    // 5% fee of 1_000_000, a fifth of which goes to the buyer's referrer
+    assert_eq!(env.token_balance(market.seller_token.unwrap()).await, 950_000);
+    assert_eq!(env.token_balance(referrer_token).await, 10_000);
+    assert_eq!(env.token_balance(treasury_vault_pda(&market.mint)).await, 40_000);
+
+    let referral: Referral = env.get(referral_pda(&market.buyer.pubkey())).await;
+    assert_eq!(referral.total_rewards, 10_000);
+    assert_eq!(referral.reward_count, 1);
+}
+
+#[tokio::test]
+async fn referral_account_cannot_be_swapped_out() {
+    let mut env = TestEnv::start().await;
+    let market = referral_market(&mut env, 2_000).await;
+    let referrer = env.new_user("referrer").await;
+    env.register_referral(&market.buyer, referrer.pubkey()).await.unwrap();
+
+    let order = env.delivered_order(&market).await;
+    let mut accounts = env.complete_order_accounts(&market, order);
+    accounts.buyer_referral = referral_pda(&referrer.pubkey());
+    let err = env.complete_order_with(&market, accounts).await.unwrap_err();
+    assert_eq!(error_code(err), ErrorCode::ConstraintSeeds as u32);
+}
+
+#[tokio::test]
+async fn registered_referrer_must_receive_the_reward() {
+    let mut env = TestEnv::start().await;
+    let market = referral_market(&mut env, 2_000).await;
+    let referrer = env.new_user("referrer").await;
+    env.register_referral(&market.buyer, referrer.pubkey()).await.unwrap();
+
+    let order = env.delivered_order(&market).await;
+    let mut accounts = env.complete_order_accounts(&market, order);
+    accounts.buyer_referrer_token_account = market.buyer_token;
+    assert_error(
+        env.complete_order_with(&market, accounts).await,
+        SolanaGigsError::InvalidReferralCode,
+    );
+}
+
+#[tokio::test]
+async fn users_cannot_refer_themselves() {
+    let mut env = TestEnv::start().await;
+    env.initialize_marketplace(500).await;
+    let user = env.new_user("user").await;
+
+    assert_error(
+        env.register_referral(&user, user.pubkey()).await,
+        SolanaGigsError::CannotReferYourself,
+    );
+}
@@ -0,0 +1,99 @@
+mod common;
+
+use anchor_lang::error::ErrorCode;
+use anchor_lang::solana_program::{instruction::Instruction, pubkey::Pubkey};
+use anchor_spl::token::{spl_token, spl_token::native_mint};
+use solana_sdk::signer::Signer;
+
+use common::*;
+use solanagigs::errors::SolanaGigsError;
+use solanagigs::state::Treasury;
+
+fn withdraw_ix(
+    market: &Market,
+    authority: Pubkey,
+    destination: Pubkey,
+    amount: u64,
+) -> Instruction {
+    let native = market.is_native();
+    instruction(
+        solanagigs::accounts::WithdrawTreasury {
+            marketplace: marketplace_pda(),
+            treasury: treasury_pda(&market.mint),
+            treasury_vault: (!native).then(|| treasury_vault_pda(&market.mint)),
+            mint: (!native).then_some(market.mint),
+            destination_token_account: (!native).then_some(destination),
+            destination_wallet: native.then_some(destination),
+            authority,
+            token_program: market.token_program,
+        },
+        solanagigs::instruction::WithdrawTreasury { amount },
+    )
+}
+
+#[tokio::test]
+async fn authority_withdraws_collected_fees() {
+    let mut env = TestEnv::start().await;
+    let mint = env.create_mint(spl_token::ID, None).await;
+    let market = env.market(mint, 500, 1_000_000).await;
+    let order = env.delivered_order(&market).await;
+    env.complete_order(&market, order).await.unwrap();
+
+    let authority = env.payer();
+    let destination = env.create_token_account(mint, authority).await;
+    assert_error(
+        env.process(&[withdraw_ix(&market, authority, destination, 50_001)], &[]).await,
+        SolanaGigsError::TreasuryInsufficientFunds,
+    );
+    env.process(&[withdraw_ix(&market, authority, destination, 30_000)], &[])
+        .await
+        .unwrap();
+
+    assert_eq!(env.token_balance(destination).await, 30_000);
+    assert_eq!(env.token_balance(treasury_vault_pda(&mint)).await, 20_000);
+    let treasury: Treasury = env.get(treasury_pda(&mint)).await;
+    assert_eq!(treasury.total_withdrawn, 30_000);
+    assert_eq!(treasury.withdrawal_count, 1);
+}
+
+#[tokio::test]
+async fn only_the_authority_can_withdraw() {
+    let mut env = TestEnv::start().await;
+    let mint = env.create_mint(spl_token::ID, None).await;
+    let market = env.market(mint, 500, 1_000_000).await;
+    let order = env.delivered_order(&market).await;
+    env.complete_order(&market, order).await.unwrap();
+
+    let intruder = env.new_wallet(SOL).await;
+    let destination = env.create_token_account(mint, intruder.pubkey()).await;
+    let err = env
+        .process(&[withdraw_ix(&market, intruder.pubkey(), destination, 1)], &[&intruder])
+        .await
+        .unwrap_err();
+    assert_eq!(error_code(err), ErrorCode::ConstraintHasOne as u32);
+}
+
+#[tokio::test]
+async fn sol_treasury_keeps_its_rent() {
+    let mut env = TestEnv::start().await;
+    let market = env.market(native_mint::ID, 500, SOL).await;
+    let order = env.delivered_order(&market).await;
+    env.complete_order(&market, order).await.unwrap();
+
+    let treasury = treasury_pda(&native_mint::ID);
+    let fees = SOL / 100 * 5;
+    let rent = env.lamports(treasury).await - fees;
+    let authority = env.payer();
+    let destination = env.new_wallet(SOL).await.pubkey();
+
+    assert_error(
+        env.process(&[withdraw_ix(&market, authority, destination, fees + 1)], &[]).await,
+        SolanaGigsError::TreasuryInsufficientFunds,
+    );
+    env.process(&[withdraw_ix(&market, authority, destination, fees)], &[])
+        .await
+        .unwrap();
+
+    assert_eq!(env.lamports(treasury).await, rent);
+    assert_eq!(env.lamports(destination).await, SOL + fees);
+}